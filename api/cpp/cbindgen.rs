@@ -267,6 +267,7 @@ fn gen_corelib(
         "Rotate",
         "Opacity",
         "Layer",
+        "TimerItem",
     ];
 
     config.export.include = [
@@ -512,9 +513,17 @@ fn gen_corelib(
             "slint_windowrc_set_component",
             "slint_windowrc_show_popup",
             "slint_windowrc_close_popup",
+            "slint_windowrc_show_child_window",
+            "slint_windowrc_is_child_window_shown",
+            "slint_windowrc_close_child_window",
             "slint_windowrc_set_rendering_notifier",
             "slint_windowrc_request_redraw",
             "slint_windowrc_on_close_requested",
+            "slint_windowrc_on_window_state_changed",
+            "slint_windowrc_is_minimized",
+            "slint_windowrc_set_minimized",
+            "slint_windowrc_is_maximized",
+            "slint_windowrc_set_maximized",
             "slint_windowrc_position",
             "slint_windowrc_set_logical_position",
             "slint_windowrc_set_physical_position",
@@ -648,7 +657,7 @@ fn gen_corelib(
     );
     config.export.body.insert(
         "EasingCurve".to_owned(),
-        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} {}".into()
+        "    constexpr EasingCurve(EasingCurve::Tag tag = Tag::Linear, float a = 0, float b = 0, float c = 1, float d = 1) : tag(tag), cubic_bezier{{a,b,c,d}} { if (tag == Tag::Spring) { spring = {{a,b,c}}; } }".into()
     );
     config.export.body.insert(
         "LayoutInfo".to_owned(),
@@ -667,6 +676,11 @@ fn gen_corelib(
         .body
         .insert("Flickable".to_owned(), "    inline Flickable(); inline ~Flickable();".into());
     config.export.pre_body.insert("FlickableDataBox".to_owned(), "struct FlickableData;".into());
+    config
+        .export
+        .body
+        .insert("TimerItem".to_owned(), "    inline TimerItem(); inline ~TimerItem();".into());
+    config.export.pre_body.insert("TimerItemDataBox".to_owned(), "struct TimerItemData;".into());
 
     cbindgen::Builder::new()
         .with_config(config)
@@ -16,6 +16,7 @@
 #include "slint_window.h"
 
 #include <vector>
+#include <string>
 #include <memory>
 #include <algorithm>
 #include <chrono>
@@ -203,6 +204,8 @@ inline bool operator==(const EasingCurve &a, const EasingCurve &b)
         return false;
     } else if (a.tag == EasingCurve::Tag::CubicBezier) {
         return std::equal(a.cubic_bezier._0, a.cubic_bezier._0 + 4, b.cubic_bezier._0);
+    } else if (a.tag == EasingCurve::Tag::Spring) {
+        return std::equal(a.spring._0, a.spring._0 + 3, b.spring._0);
     }
     return true;
 }
@@ -545,6 +548,131 @@ public:
     }
 };
 
+namespace private_api {
+
+template<typename M>
+auto model_sum(const std::shared_ptr<M> &model)
+{
+    using T = std::decay_t<decltype(*model->row_data_tracked(0))>;
+    T result {};
+    for (long int i = 0; i < model_length(model); ++i) {
+        result += access_array_index(model, i);
+    }
+    return result;
+}
+
+template<typename M, typename V>
+long int model_index_of(const std::shared_ptr<M> &model, const V &value)
+{
+    for (long int i = 0; i < model_length(model); ++i) {
+        if (access_array_index(model, i) == value) {
+            return i;
+        }
+    }
+    return -1;
+}
+
+template<typename M>
+auto model_slice(const std::shared_ptr<M> &model, long int start, long int end)
+{
+    using T = std::decay_t<decltype(*model->row_data_tracked(0))>;
+    auto len = model_length(model);
+    start = std::clamp(start, 0l, len);
+    end = std::clamp(end, start, len);
+    std::vector<T> result;
+    for (auto i = start; i < end; ++i) {
+        result.push_back(access_array_index(model, i));
+    }
+    return std::shared_ptr<Model<T>>(std::make_shared<VectorModel<T>>(std::move(result)));
+}
+
+template<typename M, typename F>
+auto model_map(const std::shared_ptr<M> &model, F f)
+{
+    using T = std::decay_t<decltype(f(*model->row_data_tracked(0)))>;
+    std::vector<T> result;
+    for (long int i = 0; i < model_length(model); ++i) {
+        result.push_back(f(access_array_index(model, i)));
+    }
+    return std::shared_ptr<Model<T>>(std::make_shared<VectorModel<T>>(std::move(result)));
+}
+
+template<typename M, typename F>
+auto model_filter(const std::shared_ptr<M> &model, F f)
+{
+    using T = std::decay_t<decltype(*model->row_data_tracked(0))>;
+    std::vector<T> result;
+    for (long int i = 0; i < model_length(model); ++i) {
+        auto value = access_array_index(model, i);
+        if (f(value)) {
+            result.push_back(std::move(value));
+        }
+    }
+    return std::shared_ptr<Model<T>>(std::make_shared<VectorModel<T>>(std::move(result)));
+}
+
+template<typename M>
+SharedString model_join(const std::shared_ptr<M> &model, std::string_view separator)
+{
+    std::string result;
+    for (long int i = 0; i < model_length(model); ++i) {
+        if (i > 0) {
+            result += separator;
+        }
+        result += std::string_view(access_array_index(model, i));
+    }
+    return SharedString(result);
+}
+
+inline bool string_contains(std::string_view string, std::string_view pattern)
+{
+    return string.find(pattern) != std::string_view::npos;
+}
+
+inline std::shared_ptr<Model<SharedString>> string_split(const SharedString &string,
+                                                         std::string_view separator)
+{
+    std::vector<SharedString> result;
+    if (separator.empty()) {
+        result.push_back(string);
+    } else {
+        std::string_view rest(string);
+        for (auto pos = rest.find(separator); pos != std::string_view::npos;
+             pos = rest.find(separator)) {
+            result.push_back(SharedString(rest.substr(0, pos)));
+            rest.remove_prefix(pos + separator.size());
+        }
+        result.push_back(SharedString(rest));
+    }
+    return std::make_shared<VectorModel<SharedString>>(std::move(result));
+}
+
+inline SharedString string_replace(const SharedString &string, std::string_view from,
+                                   std::string_view to)
+{
+    if (from.empty()) {
+        return string;
+    }
+    std::string result;
+    std::string_view rest(string);
+    for (auto pos = rest.find(from); pos != std::string_view::npos; pos = rest.find(from)) {
+        result += rest.substr(0, pos);
+        result += to;
+        rest.remove_prefix(pos + from.size());
+    }
+    result += rest;
+    return SharedString(result);
+}
+
+inline long int string_character_count(std::string_view string)
+{
+    // Count the bytes that are not UTF-8 continuation bytes
+    return std::count_if(string.begin(), string.end(),
+                         [](char c) { return (static_cast<unsigned char>(c) & 0xc0) != 0x80; });
+}
+
+} // namespace private_api
+
 template<typename ModelData>
 class FilterModel;
 
@@ -1145,14 +1273,19 @@ public:
     void ensure_updated_listview(const Parent *parent,
                                  const private_api::Property<float> *viewport_width,
                                  const private_api::Property<float> *viewport_height,
-                                 [[maybe_unused]] const private_api::Property<float> *viewport_y,
-                                 float listview_width, [[maybe_unused]] float listview_height) const
+                                 const private_api::Property<float> *viewport_y,
+                                 float listview_width, float listview_height,
+                                 int scroll_to_row) const
     {
         // TODO: the rust code in model.rs try to only allocate as many items as visible items
         ensure_updated(parent);
 
-        float h = compute_layout_listview(viewport_width, listview_width);
+        float row_y = 0;
+        float h = compute_layout_listview(viewport_width, listview_width, scroll_to_row, &row_y);
         viewport_height->set(h);
+        if (scroll_to_row >= 0) {
+            viewport_y->set(-std::max(0.f, std::min(row_y, h - listview_height)));
+        }
     }
 
     uint64_t visit(TraversalOrder order, private_api::ItemVisitorRefMut visitor) const
@@ -1188,16 +1321,22 @@ public:
         return private_api::IndexRange { 0, inner->data.size() };
     }
 
+    /// Returns the height of all the rows, and stores the y position of the given row in row_y
     float compute_layout_listview(const private_api::Property<float> *viewport_width,
-                                  float listview_width) const
+                                  float listview_width, int row, float *row_y) const
     {
         float offset = 0;
         viewport_width->set(listview_width);
         if (!inner)
             return offset;
+        int i = 0;
         for (auto &x : inner->data) {
+            if (i++ == row)
+                *row_y = offset;
             (*x.ptr)->listview_layout(&offset, viewport_width);
         }
+        if (row >= i)
+            *row_y = offset;
         return offset;
     }
 
@@ -1214,6 +1353,16 @@ public:
     }
 };
 
+inline float easing_curve(const cbindgen_private::EasingCurve &curve, float value)
+{
+    return cbindgen_private::slint_easing_curve(&curve, value);
+}
+
+inline void assertion_failed(const SharedString &message)
+{
+    cbindgen_private::slint_assertion_failed(&message);
+}
+
 inline SharedString translate(const SharedString &original, const SharedString &context,
                               const SharedString &domain,
                               cbindgen_private::Slice<SharedString> arguments, int n,
@@ -1224,6 +1373,41 @@ inline SharedString translate(const SharedString &original, const SharedString &
     return result;
 }
 
+inline SharedString format_number(double value, int decimals)
+{
+    SharedString result;
+    cbindgen_private::slint_format_number(&result, value, decimals);
+    return result;
+}
+
+inline SharedString format_percent(double value, int decimals)
+{
+    SharedString result;
+    cbindgen_private::slint_format_percent(&result, value, decimals);
+    return result;
+}
+
+inline SharedString format_byte_size(double bytes, int decimals)
+{
+    SharedString result;
+    cbindgen_private::slint_format_byte_size(&result, bytes, decimals);
+    return result;
+}
+
+inline SharedString format_duration(std::int64_t milliseconds)
+{
+    SharedString result;
+    cbindgen_private::slint_format_duration(&result, milliseconds);
+    return result;
+}
+
+inline SharedString format_date(int year, int month, int day)
+{
+    SharedString result;
+    cbindgen_private::slint_format_date(&result, year, month, day);
+    return result;
+}
+
 } // namespace private_api
 
 #if !defined(DOXYGEN)
@@ -1236,6 +1420,15 @@ cbindgen_private::Flickable::~Flickable()
     slint_flickable_data_free(&data);
 }
 
+cbindgen_private::TimerItem::TimerItem()
+{
+    slint_timer_item_data_init(&data);
+}
+cbindgen_private::TimerItem::~TimerItem()
+{
+    slint_timer_item_data_free(&data);
+}
+
 cbindgen_private::NativeStyleMetrics::NativeStyleMetrics(void *)
 {
     slint_native_style_metrics_init(this);
@@ -1299,6 +1492,18 @@ inline void quit_event_loop()
     cbindgen_private::slint_quit_event_loop();
 }
 
+/// Sets the locale that is used to format numbers, byte sizes, durations and dates with the
+/// functions of the `Format` namespace in `.slint` files.
+///
+/// The locale is a language tag such as `"en-US"`, `"de"` or `"fr_CH.UTF-8"`. By default, it is
+/// taken from the `LC_ALL`, `LC_NUMERIC` or `LANG` environment variables, or is `"en"`.
+inline void set_locale(std::string_view locale)
+{
+    private_api::assert_main_thread();
+    SharedString s(locale);
+    cbindgen_private::slint_set_locale(&s);
+}
+
 /// Adds the specified functor to an internal queue, notifies the event loop to wake up.
 /// Once woken up, any queued up functors will be invoked.
 /// This function is thread-safe and can be called from any thread, including the one
@@ -100,6 +100,13 @@ struct Property
                 nullptr, nullptr);
     }
 
+    /// \private
+    /// Names the current binding, to report binding loops in debug builds
+    void set_binding_debug_name(const char *name) const
+    {
+        cbindgen_private::slint_property_set_binding_debug_name(&inner, name);
+    }
+
     inline void set_animated_value(const T &value,
                                    const cbindgen_private::PropertyAnimation &animation_data) const;
     template<typename F>
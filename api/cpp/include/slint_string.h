@@ -132,6 +132,32 @@ struct SharedString
                 == 0;
     }
 
+    /// \return a copy of this string where all the characters are converted to uppercase, as
+    /// defined by the Unicode Derived Core Property Uppercase.
+    SharedString to_uppercase() const
+    {
+        SharedString result;
+        cbindgen_private::slint_shared_string_to_uppercase(&result, this);
+        return result;
+    }
+
+    /// \return a copy of this string where all the characters are converted to lowercase, as
+    /// defined by the Unicode Derived Core Property Lowercase.
+    SharedString to_lowercase() const
+    {
+        SharedString result;
+        cbindgen_private::slint_shared_string_to_lowercase(&result, this);
+        return result;
+    }
+
+    /// \return a copy of this string with leading and trailing whitespace removed.
+    SharedString trim() const
+    {
+        SharedString result;
+        cbindgen_private::slint_shared_string_trim(&result, this);
+        return result;
+    }
+
     /// Creates a new SharedString from the given number \a n. The string representation of the
     /// number uses a minimal formatting scheme: If \a n has no fractional part, the number will be
     /// formatted as an integer.
@@ -105,15 +105,32 @@ public:
 
     template<typename Component, typename Parent>
     void show_popup(const Parent *parent_component, cbindgen_private::Point p, bool close_on_click,
-                    cbindgen_private::ItemRc parent_item) const
+                    bool modal, cbindgen_private::ItemRc parent_item) const
     {
         auto popup = Component::create(parent_component).into_dyn();
-        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_on_click,
+        cbindgen_private::slint_windowrc_show_popup(&inner, &popup, p, close_on_click, modal,
                                                     &parent_item);
     }
 
     void close_popup() const { cbindgen_private::slint_windowrc_close_popup(&inner); }
 
+    template<typename Component, typename Parent>
+    void show_child_window(uintptr_t index, const Parent *parent_component,
+                           cbindgen_private::ItemRc parent_item) const
+    {
+        if (cbindgen_private::slint_windowrc_is_child_window_shown(&inner, index, &parent_item)) {
+            return;
+        }
+        auto child_window = Component::create(parent_component).into_dyn();
+        cbindgen_private::slint_windowrc_show_child_window(&inner, index, &child_window,
+                                                           &parent_item);
+    }
+
+    void close_child_window(uintptr_t index, cbindgen_private::ItemRc parent_item) const
+    {
+        cbindgen_private::slint_windowrc_close_child_window(&inner, index, &parent_item);
+    }
+
     template<std::invocable<RenderingState, GraphicsAPI> F>
     std::optional<SetRenderingNotifierError> set_rendering_notifier(F callback) const
     {
@@ -143,6 +160,20 @@ public:
                 new F(std::move(callback)));
     }
 
+    template<std::invocable F>
+    void on_window_state_changed(F callback) const
+    {
+        auto actual_cb = [](void *data) { (*reinterpret_cast<F *>(data))(); };
+        cbindgen_private::slint_windowrc_on_window_state_changed(
+                &inner, actual_cb, [](void *user_data) { delete reinterpret_cast<F *>(user_data); },
+                new F(std::move(callback)));
+    }
+
+    bool is_minimized() const { return slint_windowrc_is_minimized(&inner); }
+    void set_minimized(bool minimized) const { slint_windowrc_set_minimized(&inner, minimized); }
+    bool is_maximized() const { return slint_windowrc_is_maximized(&inner); }
+    void set_maximized(bool maximized) const { slint_windowrc_set_maximized(&inner, maximized); }
+
     void request_redraw() const { cbindgen_private::slint_windowrc_request_redraw(&inner); }
 
     slint::PhysicalPosition position() const
@@ -289,6 +320,29 @@ public:
         return inner.on_close_requested(std::forward<F>(callback));
     }
 
+    /// This function allows registering a callback that's invoked when the windowing system
+    /// minimized, maximized or restored the window. Use is_minimized() and is_maximized() to query
+    /// the new state.
+    template<std::invocable F>
+    void on_window_state_changed(F &&callback) const
+    {
+        return inner.on_window_state_changed(std::forward<F>(callback));
+    }
+
+    /// Returns true if the window is minimized.
+    ///
+    /// This is the value of the `minimized` property of the root `Window` element.
+    bool is_minimized() const { return inner.is_minimized(); }
+    /// Minimizes the window, or restores it from the minimized state.
+    void set_minimized(bool minimized) { inner.set_minimized(minimized); }
+
+    /// Returns true if the window is maximized.
+    ///
+    /// This is the value of the `maximized` property of the root `Window` element.
+    bool is_maximized() const { return inner.is_maximized(); }
+    /// Maximizes the window, or restores it from the maximized state.
+    void set_maximized(bool maximized) { inner.set_maximized(maximized); }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
     void request_redraw() const { inner.request_redraw(); }
 
@@ -475,6 +529,21 @@ public:
         cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
     }
 
+    /// The window was minimized, maximized or restored by the windowing system.
+    ///
+    /// The backend should dispatch this event when the state of the window changes, so that the
+    /// `minimized` and `maximized` properties of the root Window element are kept up-to-date.
+    void dispatch_window_state_changed_event(bool minimized, bool maximized)
+    {
+        private_api::assert_main_thread();
+        using slint::cbindgen_private::WindowEvent;
+        WindowEvent event { .window_state_changed = WindowEvent::WindowStateChanged_Body {
+                                    .tag = WindowEvent::Tag::WindowStateChanged,
+                                    .minimized = minimized,
+                                    .maximized = maximized } };
+        cbindgen_private::slint_windowrc_dispatch_event(&inner.handle(), &event);
+    }
+
     /// The user requested to close the window.
     ///
     /// The backend should send this event when the user tries to close the window,for example by
@@ -0,0 +1,419 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Cache of the previous compilation, stored in `OUT_DIR` next to the generated code.
+//!
+//! The cache records a hash of the content of every file that was read by the previous
+//! compilation (the .slint files, including the imported ones, and the embedded resources),
+//! as well as a fingerprint of the configuration and of the compiler. When none of them changed,
+//! the generated code is still valid and the compilation can be skipped entirely.
+//!
+//! Otherwise, the documents are compiled again, but each document has its own key: the hash of
+//! that document and of the documents it depends on. When the code of each component is generated
+//! in its own module, the modules of a document whose key didn't change are not generated again.
+//! A document depends on the documents it imports, but also on the documents that import it,
+//! because the code generated for a component is optimized for the way it is used.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// The first line of the cache file. A cache written by another version is never reused.
+const CACHE_HEADER: &str = concat!("slint-build cache ", env!("CARGO_PKG_VERSION"));
+
+/// Environment variables read by the compiler that change the generated code. Some of them are
+/// only the default of a `CompilerConfiguration` setting, but that setting is hashed after the
+/// `CompilerConfiguration` was created from them, so they are all hashed.
+const ENV_VARS: &[&str] = &[
+    "SLINT_FONT_SIZES",
+    "SLINT_ASSET_SECTION",
+    "SLINT_STYLE",
+    "SLINT_EMBED_TEXTURES",
+    "SLINT_EMBED_RESOURCES",
+    "DEP_MCU_BOARD_SUPPORT_MCU_EMBED_TEXTURES",
+    "SLINT_INLINING",
+    "SLINT_SCALE_FACTOR",
+    "SLINT_ENABLE_EXPERIMENTAL_FEATURES",
+];
+
+pub struct BuildCache {
+    fingerprint: u64,
+    files: Vec<(PathBuf, u64)>,
+    /// The generated files
+    outputs: Vec<PathBuf>,
+    /// The generated modules, with the key of the document that defines their component
+    modules: Vec<(PathBuf, u64)>,
+    /// The warnings of the compilation, to be reported again when the cache is used
+    pub warnings: Vec<String>,
+}
+
+impl BuildCache {
+    /// Create the cache for a compilation that read `files` and generated `outputs` and `modules`.
+    ///
+    /// Returns None if one of the files cannot be read or its path cannot be stored.
+    pub fn new(
+        fingerprint: u64,
+        files: &[PathBuf],
+        outputs: Vec<PathBuf>,
+        modules: Vec<(PathBuf, u64)>,
+        warnings: Vec<String>,
+    ) -> Option<Self> {
+        let files = files
+            .iter()
+            .map(|file| Some((file.clone(), hash_file(file).filter(|_| is_storable(file))?)))
+            .collect::<Option<Vec<_>>>()?;
+        if !outputs.iter().chain(modules.iter().map(|(module, _)| module)).all(|p| is_storable(p)) {
+            return None;
+        }
+        Some(Self { fingerprint, files, outputs, modules, warnings })
+    }
+
+    /// Load the cache written by a previous build, if any
+    pub fn load(cache_path: &Path) -> Option<Self> {
+        let content = std::fs::read_to_string(cache_path).ok()?;
+        let mut lines = content.lines();
+        if lines.next()? != CACHE_HEADER {
+            return None;
+        }
+        let fingerprint =
+            u64::from_str_radix(lines.next()?.strip_prefix("fingerprint ")?, 16).ok()?;
+        let mut files = Vec::new();
+        let mut outputs = Vec::new();
+        let mut modules = Vec::new();
+        let mut warnings = Vec::new();
+        for line in lines {
+            if let Some(file) = line.strip_prefix("file ") {
+                let (hash, path) = file.split_once(' ')?;
+                files.push((PathBuf::from(path), u64::from_str_radix(hash, 16).ok()?));
+            } else if let Some(output) = line.strip_prefix("output ") {
+                outputs.push(PathBuf::from(output));
+            } else if let Some(module) = line.strip_prefix("module ") {
+                let (key, path) = module.split_once(' ')?;
+                modules.push((PathBuf::from(path), u64::from_str_radix(key, 16).ok()?));
+            } else if let Some(warning) = line.strip_prefix("warning ") {
+                warnings.push(warning.into());
+            } else {
+                return None;
+            }
+        }
+        Some(Self { fingerprint, files, outputs, modules, warnings })
+    }
+
+    pub fn save(&self, cache_path: &Path) -> std::io::Result<()> {
+        let mut content = format!("{CACHE_HEADER}\nfingerprint {:x}\n", self.fingerprint);
+        for (path, hash) in &self.files {
+            content += &format!("file {hash:x} {}\n", path.display());
+        }
+        for path in &self.outputs {
+            content += &format!("output {}\n", path.display());
+        }
+        for (path, key) in &self.modules {
+            content += &format!("module {key:x} {}\n", path.display());
+        }
+        for warning in &self.warnings {
+            content += &format!("warning {warning}\n");
+        }
+        std::fs::write(cache_path, content)
+    }
+
+    /// Returns true if the configuration and all the files are the same as when the cache
+    /// was written, and all the generated files are still there.
+    pub fn is_up_to_date(&self, fingerprint: u64) -> bool {
+        self.fingerprint == fingerprint
+            && !self.outputs.is_empty()
+            && self.outputs.iter().all(|output| output.exists())
+            && self.modules.iter().all(|(module, _)| module.exists())
+            && self.files.iter().all(|(path, hash)| hash_file(path) == Some(*hash))
+    }
+
+    /// Returns true if the module was generated for a document with the same key, and is
+    /// still there.
+    pub fn is_module_up_to_date(&self, module: &Path, key: u64) -> bool {
+        self.modules.iter().any(|(path, k)| path == module && *k == key) && module.exists()
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &Path> {
+        self.files.iter().map(|(path, _)| path.as_path())
+    }
+}
+
+/// The keys of the documents of a compilation
+pub struct DocumentKeys(HashMap<PathBuf, u64>);
+
+impl DocumentKeys {
+    /// Compute the key of each of the `documents`, from the hash of the content of that document
+    /// and of the documents it depends on according to `imports`.
+    ///
+    /// `layout` is the hash of what the generated code of all the documents share, such as the
+    /// names of the modules and the ids of the embedded resources.
+    pub fn new(
+        fingerprint: u64,
+        layout: u64,
+        documents: &[PathBuf],
+        imports: &HashMap<PathBuf, Vec<PathBuf>>,
+    ) -> Self {
+        let mut imported_by = HashMap::<&Path, Vec<&Path>>::new();
+        for (document, imported) in imports {
+            for imported in imported {
+                imported_by.entry(imported.as_path()).or_default().push(document.as_path());
+            }
+        }
+        let imports = imports
+            .iter()
+            .map(|(document, imported)| {
+                (document.as_path(), imported.iter().map(|p| p.as_path()).collect::<Vec<_>>())
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut hashes = HashMap::new();
+        let keys = documents
+            .iter()
+            .map(|document| {
+                let mut dependencies = reachable(document.as_path(), &imports);
+                dependencies.extend(reachable(document.as_path(), &imported_by));
+                dependencies.sort();
+                dependencies.dedup();
+
+                let mut hasher = StableHasher::new();
+                hasher.write_field(&fingerprint.to_le_bytes());
+                hasher.write_field(&layout.to_le_bytes());
+                for dependency in dependencies {
+                    // The builtin documents cannot be read, but they are part of the compiler,
+                    // which is in the fingerprint
+                    let hash = hashes.entry(dependency).or_insert_with(|| hash_file(dependency));
+                    hasher.write_field(dependency.to_string_lossy().as_bytes());
+                    hasher.write_field(&hash.unwrap_or_default().to_le_bytes());
+                }
+                (document.clone(), hasher.finish())
+            })
+            .collect();
+        Self(keys)
+    }
+
+    pub fn get(&self, document: &Path) -> Option<u64> {
+        self.0.get(document).copied()
+    }
+}
+
+/// The document and all the documents reachable from it through `edges`
+fn reachable<'a>(document: &'a Path, edges: &HashMap<&'a Path, Vec<&'a Path>>) -> Vec<&'a Path> {
+    let mut result = vec![document];
+    let mut i = 0;
+    while i < result.len() {
+        for &next in edges.get(result[i]).into_iter().flatten() {
+            if !result.contains(&next) {
+                result.push(next);
+            }
+        }
+        i += 1;
+    }
+    result
+}
+
+/// The values of the environment variables that change the generated code, to be passed to
+/// [`fingerprint`]
+pub fn env_values() -> Vec<(&'static str, Option<String>)> {
+    ENV_VARS
+        .iter()
+        .map(|var| (*var, std::env::var_os(var).map(|value| value.to_string_lossy().into())))
+        .collect()
+}
+
+/// Compute a hash of everything that may change the generated code, other than the content of
+/// the files and the environment variables, whose values are passed in `env_values`
+pub fn fingerprint(
+    path: &Path,
+    config: &i_slint_compiler::CompilerConfiguration,
+    env_values: &[(&str, Option<String>)],
+) -> u64 {
+    let mut library_paths = config.library_paths.iter().collect::<Vec<_>>();
+    library_paths.sort();
+
+    let mut hasher = StableHasher::new();
+    hasher.write_field(CACHE_HEADER.as_bytes());
+    hasher.write_field(format!("{:?}", compiler_build_id()).as_bytes());
+    hasher.write_field(path.to_string_lossy().as_bytes());
+    for value in [
+        format!("{:?}", config.embed_resources),
+        format!("{:?}", config.include_paths),
+        format!("{:?}", library_paths),
+        format!("{:?}", config.style),
+        format!("{:?}", config.inline_all_elements),
+        format!("{:?}", config.scale_factor),
+        format!("{:?}", config.accessibility),
+        format!("{:?}", config.enable_component_containers),
+        format!("{:?}", config.translation_domain),
+        format!("{:?}", config.warn_unused),
+        format!("{:?}", config.split_modules),
+        format!("{:?}", env_values),
+    ] {
+        hasher.write_field(value.as_bytes());
+    }
+    hasher.finish()
+}
+
+/// Compute a hash of what the code generated for all the documents shares: the names of the
+/// modules, and the paths of the embedded resources in the order of their ids.
+pub fn layout<'a>(names: impl IntoIterator<Item = &'a str>) -> u64 {
+    let mut hasher = StableHasher::new();
+    for name in names {
+        hasher.write_field(name.as_bytes());
+    }
+    hasher.finish()
+}
+
+/// Identifies the build of the compiler, which may change without a new version number (for
+/// example with a git or path dependency, or with a patched crate).
+///
+/// The compiler is linked in the build script, so this is the hash of the build script itself.
+fn compiler_build_id() -> Option<u64> {
+    hash_file(&std::env::current_exe().ok()?)
+}
+
+/// The cache is a text file with one path per line
+fn is_storable(path: &Path) -> bool {
+    path.to_str().map_or(false, |p| !p.contains('\n'))
+}
+
+fn hash_file(path: &Path) -> Option<u64> {
+    let mut hasher = StableHasher::new();
+    hasher.write(&std::fs::read(path).ok()?);
+    Some(hasher.finish())
+}
+
+/// The 64-bit FNV-1a hash. Unlike `DefaultHasher`, it computes the same hashes with every
+/// version of Rust, so the hashes stored in the cache stay comparable.
+struct StableHasher(u64);
+
+impl StableHasher {
+    fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    /// Write the length before the bytes, so that consecutive fields cannot be confused
+    fn write_field(&mut self, bytes: &[u8]) {
+        self.write(&(bytes.len() as u64).to_le_bytes());
+        self.write(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+#[test]
+fn cache_round_trip() {
+    let dir = std::env::temp_dir().join(format!("slint-build-cache-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    let slint_file = dir.join("app window.slint");
+    let output_file = dir.join("app.rs");
+    let module_file = dir.join("app_component_App.rs");
+    let cache_file = dir.join("app.slint-cache");
+    std::fs::write(&slint_file, "export component App {}").unwrap();
+    std::fs::write(&output_file, "// generated").unwrap();
+    std::fs::write(&module_file, "// generated").unwrap();
+
+    let cache = BuildCache::new(
+        42,
+        &[slint_file.clone()],
+        vec![output_file.clone()],
+        vec![(module_file.clone(), 7)],
+        vec!["a warning".into()],
+    )
+    .unwrap();
+    cache.save(&cache_file).unwrap();
+
+    let loaded = BuildCache::load(&cache_file).unwrap();
+    assert_eq!(loaded.files().collect::<Vec<_>>(), vec![slint_file.as_path()]);
+    assert_eq!(loaded.warnings, vec!["a warning".to_string()]);
+    assert!(loaded.is_up_to_date(42));
+    assert!(!loaded.is_up_to_date(43));
+    assert!(loaded.is_module_up_to_date(&module_file, 7));
+    assert!(!loaded.is_module_up_to_date(&module_file, 8));
+    assert!(!loaded.is_module_up_to_date(&output_file, 7));
+
+    // A module generated by the previous build was removed
+    std::fs::remove_file(&module_file).unwrap();
+    assert!(!loaded.is_up_to_date(42));
+    std::fs::write(&module_file, "// generated").unwrap();
+    assert!(loaded.is_up_to_date(42));
+
+    std::fs::write(&slint_file, "export component App inherits Window {}").unwrap();
+    assert!(!loaded.is_up_to_date(42));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn fingerprint_depends_on_environment() {
+    let mut config = i_slint_compiler::CompilerConfiguration::new(
+        i_slint_compiler::generator::OutputFormat::Rust,
+    );
+    let path = Path::new("app.slint");
+    let env = [("SLINT_ENABLE_EXPERIMENTAL_FEATURES", None)];
+    let before = fingerprint(path, &config, &env);
+    assert_eq!(before, fingerprint(path, &config, &env));
+    config.split_modules = true;
+    assert_ne!(before, fingerprint(path, &config, &env));
+    config.split_modules = false;
+    let after =
+        fingerprint(path, &config, &[("SLINT_ENABLE_EXPERIMENTAL_FEATURES", Some("1".into()))]);
+    assert_ne!(before, after);
+}
+
+#[test]
+fn stable_hash() {
+    // The FNV-1a test vectors
+    let hash = |bytes: &[u8]| {
+        let mut hasher = StableHasher::new();
+        hasher.write(bytes);
+        hasher.finish()
+    };
+    assert_eq!(hash(b""), 0xcbf29ce484222325);
+    assert_eq!(hash(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(hash(b"foobar"), 0x85944171f73967e8);
+}
+
+#[test]
+fn document_keys_depend_on_the_related_documents() {
+    let dir = std::env::temp_dir().join(format!("slint-build-keys-test-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    // main imports a and c, a imports b
+    let [main, a, b, c] = ["main", "a", "b", "c"].map(|name| dir.join(format!("{name}.slint")));
+    for file in [&main, &a, &b, &c] {
+        std::fs::write(file, "component X {}").unwrap();
+    }
+    let documents = [main.clone(), a.clone(), b.clone(), c.clone()];
+    let imports =
+        HashMap::from([(main.clone(), vec![a.clone(), c.clone()]), (a.clone(), vec![b.clone()])]);
+
+    let before = DocumentKeys::new(1, 2, &documents, &imports);
+    assert_eq!(before.get(&dir.join("unknown.slint")), None);
+    let keys = documents.iter().map(|doc| before.get(doc).unwrap()).collect::<Vec<_>>();
+    assert_eq!(keys.len(), 4);
+    assert_ne!(keys[1], keys[2], "the keys of documents depend on their path");
+
+    std::fs::write(&b, "component Y {}").unwrap();
+    let after = DocumentKeys::new(1, 2, &documents, &imports);
+    // b changed, and main and a depend on it
+    assert_ne!(before.get(&main), after.get(&main));
+    assert_ne!(before.get(&a), after.get(&a));
+    assert_ne!(before.get(&b), after.get(&b));
+    // c is neither imported by b, nor imports it
+    assert_eq!(before.get(&c), after.get(&c));
+
+    std::fs::write(&main, "component Z {}").unwrap();
+    let after_main = DocumentKeys::new(1, 2, &documents, &imports);
+    // The documents imported by main depend on the way main uses them
+    assert_ne!(after.get(&c), after_main.get(&c));
+    // Everything depends on the configuration and on the layout of the generated code
+    assert_ne!(after_main.get(&c), DocumentKeys::new(1, 3, &documents, &imports).get(&c));
+    assert_ne!(after_main.get(&c), DocumentKeys::new(0, 2, &documents, &imports).get(&c));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
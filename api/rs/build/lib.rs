@@ -52,11 +52,13 @@ fn main() {
 
 use std::collections::HashMap;
 use std::env;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::path::Path;
 
 use i_slint_compiler::diagnostics::BuildDiagnostics;
 
+mod cache;
+
 /// The structure for configuring aspects of the compilation of `.slint` markup files to Rust.
 pub struct CompilerConfiguration {
     config: i_slint_compiler::CompilerConfiguration,
@@ -99,7 +101,7 @@ pub fn new() -> Self {
     pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.include_paths = include_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that sets the library paths used for looking up
@@ -132,7 +134,7 @@ pub fn with_include_paths(self, include_paths: Vec<std::path::PathBuf>) -> Self
     pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBuf>) -> Self {
         let mut config = self.config;
         config.library_paths = library_paths;
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Create a new configuration that selects the style to be used for widgets.
@@ -140,7 +142,7 @@ pub fn with_library_paths(self, library_paths: HashMap<String, std::path::PathBu
     pub fn with_style(self, style: String) -> Self {
         let mut config = self.config;
         config.style = Some(style);
-        Self { config }
+        Self { config, ..self }
     }
 
     /// Selects how the resources such as images and font are processed.
@@ -160,7 +162,33 @@ pub fn embed_resources(self, kind: EmbedResourcesKind) -> Self {
                 i_slint_compiler::EmbedResourcesKind::EmbedTextures
             }
         };
-        Self { config }
+        Self { config, ..self }
+    }
+
+    /// Create a new configuration that enables or disables the warnings for private properties,
+    /// callbacks, functions, element ids, imports and components that are never used.
+    ///
+    /// Items whose name starts with an underscore are never reported.
+    #[must_use]
+    pub fn with_unused_warnings(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.warn_unused = enable;
+        Self { config, ..self }
+    }
+
+    /// Create a new configuration that generates the code of each global and of each component
+    /// in its own module and file, instead of a single file.
+    ///
+    /// The code generated for a component only depends on the components and globals it uses,
+    /// and the files are only written when their code changes. So when one component changes,
+    /// the files of the components that don't use it are left untouched, and the incremental
+    /// compilation of rustc reuses what it compiled for them. The generated code is still
+    /// included with `slint::include_modules!()`.
+    #[must_use]
+    pub fn with_split_modules(self, enable: bool) -> Self {
+        let mut config = self.config;
+        config.split_modules = enable;
+        Self { config, ..self }
     }
 }
 
@@ -302,6 +330,13 @@ fn format_code(code: &str) -> String {
 ///
 /// Please check out the documentation of the `slint` crate for more information
 /// about how to use the generated code.
+///
+/// The hashes of all the files read by the compilation are kept in a cache in `OUT_DIR`.
+/// When the build script runs again and none of these files nor the configuration changed,
+/// the code generated by the previous run is reused without compiling again. Otherwise, all
+/// the files are compiled again, and with [`CompilerConfiguration::with_split_modules`], only the
+/// modules of the documents that depend on a changed document are written again. The generated
+/// files are only written when their content changes.
 pub fn compile(path: impl AsRef<std::path::Path>) -> Result<(), CompileError> {
     compile_with_config(path, CompilerConfiguration::default())
 }
@@ -322,6 +357,28 @@ pub fn compile_with_config(
     let path = Path::new(&env::var_os("CARGO_MANIFEST_DIR").ok_or(CompileError::NotRunViaCargo)?)
         .join(path.as_ref());
 
+    let output_file_path = Path::new(&env::var_os("OUT_DIR").ok_or(CompileError::NotRunViaCargo)?)
+        .join(
+            path.file_stem()
+                .map(Path::new)
+                .unwrap_or_else(|| Path::new("slint_out"))
+                .with_extension("rs"),
+        );
+    let cache_path = output_file_path.with_extension("slint-cache");
+
+    let mut compiler_config = config.config;
+    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
+
+    // Nothing to do if none of the files used by the previous build changed
+    let fingerprint = cache::fingerprint(&path, &compiler_config, &cache::env_values());
+    let previous_cache = cache::BuildCache::load(&cache_path);
+    if let Some(cache) = &previous_cache {
+        if cache.is_up_to_date(fingerprint) {
+            print_cargo_instructions(cache.files(), &cache.warnings, &output_file_path);
+            return Ok(());
+        }
+    }
+
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse_file(&path, &mut diag);
 
@@ -331,11 +388,9 @@ pub fn compile_with_config(
         return Err(CompileError::CompileError(vec));
     }
 
-    let mut compiler_config = config.config;
-    compiler_config.translation_domain = std::env::var("CARGO_PKG_NAME").ok();
-
     let syntax_node = syntax_node.expect("diags contained no compilation errors");
 
+    let split_modules = compiler_config.split_modules;
     // 'spin_on' is ok here because the compiler in single threaded and does not block if there is no blocking future
     let (doc, diag) =
         spin_on::spin_on(i_slint_compiler::compile_syntax_node(syntax_node, diag, compiler_config));
@@ -346,39 +401,116 @@ pub fn compile_with_config(
         return Err(CompileError::CompileError(vec));
     }
 
-    let output_file_path = Path::new(&env::var_os("OUT_DIR").ok_or(CompileError::NotRunViaCargo)?)
-        .join(
-            path.file_stem()
-                .map(Path::new)
-                .unwrap_or_else(|| Path::new("slint_out"))
-                .with_extension("rs"),
+    let mut files =
+        diag.all_loaded_files.iter().filter(|x| x.is_absolute()).cloned().collect::<Vec<_>>();
+    files.push(path.clone());
+    let mut resources = doc
+        .root_component
+        .embedded_file_resources
+        .borrow()
+        .iter()
+        .map(|(resource_path, resource)| (resource.id, resource_path.clone()))
+        .collect::<Vec<_>>();
+    resources.sort();
+    files.extend(
+        resources
+            .iter()
+            .filter(|(_, resource)| !resource.starts_with("builtin:"))
+            .map(|(_, resource)| std::path::PathBuf::from(resource)),
+    );
+    files.sort();
+    files.dedup();
+
+    let mut outputs = vec![output_file_path.clone()];
+    let mut modules = Vec::new();
+    let generated = if split_modules {
+        let mut generated_modules = Vec::new();
+        let generated =
+            i_slint_compiler::generator::rust::generate_with_modules(&doc, |name, source, code| {
+                let module_path = output_file_path.with_file_name(format!(
+                    "{}_{name}.rs",
+                    output_file_path.file_stem().unwrap().to_string_lossy()
+                ));
+                let path = module_path.display().to_string();
+                generated_modules.push((module_path, source.map(Path::to_path_buf), code));
+                path
+            });
+
+        // The modules of a document are only written again when the key of that document
+        // changed, which includes the documents it depends on
+        let layout = cache::layout(
+            generated_modules
+                .iter()
+                .map(|(module_path, ..)| module_path.to_str().unwrap_or_default())
+                .chain(resources.iter().map(|(_, resource)| resource.as_str())),
         );
-
-    let file = std::fs::File::create(&output_file_path).map_err(CompileError::SaveError)?;
-    let mut code_formatter = CodeFormatter::new(BufWriter::new(file));
-    let generated = i_slint_compiler::generator::rust::generate(&doc);
-
-    for x in &diag.all_loaded_files {
-        if x.is_absolute() {
-            println!("cargo:rerun-if-changed={}", x.display());
+        let keys = cache::DocumentKeys::new(fingerprint, layout, &files, &diag.all_loaded_imports);
+        for (module_path, source, code) in generated_modules {
+            let Some(key) = source.and_then(|source| keys.get(&source)) else {
+                write_generated_code(&module_path, code).map_err(CompileError::SaveError)?;
+                outputs.push(module_path);
+                continue;
+            };
+            if !previous_cache
+                .as_ref()
+                .map_or(false, |cache| cache.is_module_up_to_date(&module_path, key))
+            {
+                write_generated_code(&module_path, code).map_err(CompileError::SaveError)?;
+            }
+            modules.push((module_path, key));
+        }
+        generated
+    } else {
+        i_slint_compiler::generator::rust::generate(&doc)
+    };
+    write_generated_code(&output_file_path, generated).map_err(CompileError::SaveError)?;
+
+    let warnings = diag
+        .diagnostics_as_string()
+        .lines()
+        .filter(|w| !w.is_empty())
+        .map(|w| w.strip_prefix("warning: ").unwrap_or(w).to_string())
+        .collect::<Vec<_>>();
+
+    print_cargo_instructions(files.iter().map(|f| f.as_path()), &warnings, &output_file_path);
+
+    // The cache is only an optimization: a failure to write it must not fail the build
+    match cache::BuildCache::new(fingerprint, &files, outputs, modules, warnings) {
+        Some(cache) => {
+            let _ = cache.save(&cache_path);
+        }
+        None => {
+            let _ = std::fs::remove_file(&cache_path);
         }
     }
 
-    // print warnings
-    diag.diagnostics_as_string().lines().for_each(|w| {
-        if !w.is_empty() {
-            println!("cargo:warning={}", w.strip_prefix("warning: ").unwrap_or(w))
-        }
-    });
+    Ok(())
+}
 
-    write!(code_formatter, "{}", generated).map_err(CompileError::SaveError)?;
-    println!("cargo:rerun-if-changed={}", path.display());
+/// Format the code and write it to the file, only if it changed, so that the crate doesn't need
+/// to be rebuilt
+fn write_generated_code(path: &Path, generated: impl std::fmt::Display) -> std::io::Result<()> {
+    let mut code = Vec::new();
+    write!(CodeFormatter::new(&mut code), "{}", generated)?;
+    if std::fs::read(path).map_or(true, |previous| previous != code) {
+        std::fs::write(path, code)?;
+    }
+    Ok(())
+}
 
-    for resource in doc.root_component.embedded_file_resources.borrow().keys() {
-        if !resource.starts_with("builtin:") {
-            println!("cargo:rerun-if-changed={}", resource);
-        }
+fn print_cargo_instructions<'a>(
+    files: impl Iterator<Item = &'a Path>,
+    warnings: &[String],
+    output_file_path: &Path,
+) {
+    for file in files {
+        println!("cargo:rerun-if-changed={}", file.display());
+    }
+
+    for warning in warnings {
+        println!("cargo:warning={}", warning);
     }
+
     println!("cargo:rerun-if-env-changed=SLINT_STYLE");
     println!("cargo:rerun-if-env-changed=SLINT_FONT_SIZES");
     println!("cargo:rerun-if-env-changed=SLINT_SCALE_FACTOR");
@@ -386,8 +518,6 @@ pub fn compile_with_config(
     println!("cargo:rerun-if-env-changed=SLINT_EMBED_RESOURCES");
 
     println!("cargo:rustc-env=SLINT_INCLUDE_GENERATED={}", output_file_path.display());
-
-    Ok(())
 }
 
 /// This function is for use the application's build script, in order to print any device specific
@@ -181,8 +181,11 @@ pub mod re_exports {
     #[allow(unused_imports)]
     pub use i_slint_backend_selector::native_widgets::*;
     pub use i_slint_core::accessibility::AccessibleStringProperty;
-    pub use i_slint_core::animations::{animation_tick, EasingCurve};
+    pub use i_slint_core::animations::{animation_tick, current_tick, easing_curve, EasingCurve};
     pub use i_slint_core::callbacks::Callback;
+    pub use i_slint_core::formatting::{
+        format_byte_size, format_date, format_duration, format_number, format_percent,
+    };
     pub use i_slint_core::graphics::*;
     pub use i_slint_core::input::{
         key_codes::Key, FocusEvent, InputEventResult, KeyEvent, KeyEventResult, KeyboardModifiers,
@@ -204,6 +207,7 @@ pub mod re_exports {
     pub use i_slint_core::model::*;
     pub use i_slint_core::properties::{set_state_binding, Property, PropertyTracker, StateInfo};
     pub use i_slint_core::slice::Slice;
+    pub use i_slint_core::tests::assertion_failed;
     pub use i_slint_core::window::{
         InputMethodRequest, WindowAdapter, WindowAdapterRc, WindowInner,
     };
@@ -26,7 +26,7 @@
 use i_slint_core::{ImageInner, Property, SharedString};
 use items::{ImageFit, TextHorizontalAlignment, TextVerticalAlignment};
 
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::pin::Pin;
 use std::ptr::NonNull;
 use std::rc::{Rc, Weak};
@@ -216,6 +216,12 @@ struct SlintWidget : QWidget {
                 rust!(Slint_updateWindowActivation [rust_window: &QtWindow as "void*", active: bool as "bool"] {
                     rust_window.window.dispatch_event(WindowEvent::WindowActiveChanged(active));
                 });
+            } else if (event->type() == QEvent::WindowStateChange) {
+                bool minimized = isMinimized();
+                bool maximized = isMaximized();
+                rust!(Slint_updateWindowState [rust_window: &QtWindow as "void*", minimized: bool as "bool", maximized: bool as "bool"] {
+                    rust_window.window.dispatch_event(WindowEvent::WindowStateChanged { minimized, maximized });
+                });
             } else if (event->type() == QEvent::PaletteChange || event->type() == QEvent::StyleChange) {
                 bool dark_color_scheme = qApp->palette().color(QPalette::Window).valueF() < 0.5;
                 rust!(Slint_updateWindowDarkColorScheme [rust_window: &QtWindow as "void*", dark_color_scheme: bool as "bool"] {
@@ -1402,6 +1408,9 @@ pub struct QtWindow {
     tree_structure_changed: RefCell<bool>,
 
     dark_color_scheme: OnceCell<Pin<Box<Property<bool>>>>,
+
+    /// The last minimized and maximized state that was requested by the properties of the window
+    requested_window_state: Cell<(bool, bool)>,
 }
 
 impl QtWindow {
@@ -1426,6 +1435,7 @@ pub fn new() -> Rc<Self> {
                 cache: Default::default(),
                 tree_structure_changed: RefCell::new(false),
                 dark_color_scheme: Default::default(),
+                requested_window_state: Default::default(),
             }
         });
         let widget_ptr = rc.widget_ptr();
@@ -1657,9 +1667,17 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
         };
 
         let fullscreen: bool = properties.fullscreen();
+        let minimized: bool = properties.is_minimized();
+        let maximized: bool = properties.is_maximized();
+        // Only change the state when the requested one changes, so that a state changed by the
+        // windowing system isn't reverted when the properties keep their binding.
+        let (last_minimized, last_maximized) =
+            self.requested_window_state.replace((minimized, maximized));
+        let update_minimized = minimized != last_minimized;
+        let update_maximized = maximized != last_maximized;
 
         cpp! {unsafe [widget_ptr as "QWidget*",  title as "QString", size as "QSize", background as "QBrush", no_frame as "bool", always_on_top as "bool",
-                      fullscreen as "bool"] {
+                      fullscreen as "bool", minimized as "bool", maximized as "bool", update_minimized as "bool", update_maximized as "bool"] {
             if (size != widget_ptr->size()) {
                 widget_ptr->resize(size.expandedTo({1, 1}));
             }
@@ -1670,10 +1688,15 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
                 // Depending on the request, we either set or clear the fullscreen bits.
                 // See also: https://doc.qt.io/qt-6/qt.html#WindowState-enum
                 const auto state = widget_ptr->windowState();
-                if (fullscreen) {
-                    widget_ptr->setWindowState(state | Qt::WindowFullScreen);
-                } else {
-                    widget_ptr->setWindowState(state & ~Qt::WindowFullScreen);
+                auto new_state = fullscreen ? state | Qt::WindowFullScreen : state & ~Qt::WindowFullScreen;
+                if (update_minimized) {
+                    new_state = minimized ? new_state | Qt::WindowMinimized : new_state & ~Qt::WindowMinimized;
+                }
+                if (update_maximized) {
+                    new_state = maximized ? new_state | Qt::WindowMaximized : new_state & ~Qt::WindowMaximized;
+                }
+                if (new_state != state) {
+                    widget_ptr->setWindowState(new_state);
                 }
             }
 
@@ -1697,17 +1720,23 @@ fn update_window_properties(&self, properties: i_slint_core::window::WindowPrope
 
         let constraints = properties.layout_constraints();
 
-        let min_size: qttypes::QSize = constraints.min.map_or_else(
-            || qttypes::QSize { width: 0, height: 0 }, // (0x0) means unset min size for QWidget
-            into_qsize,
-        );
+        let (min_size, max_size) = if properties.is_resizable() {
+            let min_size: qttypes::QSize = constraints.min.map_or_else(
+                || qttypes::QSize { width: 0, height: 0 }, // (0x0) means unset min size for QWidget
+                into_qsize,
+            );
 
-        let widget_size_max: u32 = 16_777_215;
+            let widget_size_max: u32 = 16_777_215;
 
-        let max_size: qttypes::QSize = constraints.max.map_or_else(
-            || qttypes::QSize { width: widget_size_max, height: widget_size_max },
-            into_qsize,
-        );
+            let max_size: qttypes::QSize = constraints.max.map_or_else(
+                || qttypes::QSize { width: widget_size_max, height: widget_size_max },
+                into_qsize,
+            );
+            (min_size, max_size)
+        } else {
+            // A QWidget can't be resized by the user if its minimum and maximum sizes are the same
+            (size, size)
+        };
 
         cpp! {unsafe [widget_ptr as "QWidget*",  min_size as "QSize", max_size as "QSize"] {
             widget_ptr->setMinimumSize(min_size);
@@ -1756,6 +1785,17 @@ fn create_popup(&self, geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>> {
         Some(popup_window as _)
     }
 
+    fn create_child_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        let child_window = QtWindow::new();
+        let child_ptr = child_window.widget_ptr();
+        let widget_ptr = self.widget_ptr();
+        cpp! {unsafe [widget_ptr as "QWidget*", child_ptr as "QWidget*"] {
+            // A dialog is kept above its parent by the window manager
+            child_ptr->setParent(widget_ptr, Qt::Dialog);
+        }};
+        Some(child_window as _)
+    }
+
     fn set_mouse_cursor(&self, cursor: MouseCursor) {
         let widget_ptr = self.widget_ptr();
         //unidirectional resize cursors are replaced with bidirectional ones
@@ -39,12 +39,7 @@ impl i_slint_core::platform::Platform for TestingBackend {
     fn create_window_adapter(
         &self,
     ) -> Result<Rc<dyn WindowAdapter>, i_slint_core::platform::PlatformError> {
-        Ok(Rc::new_cyclic(|self_weak| TestingWindow {
-            window: i_slint_core::api::Window::new(self_weak.clone() as _),
-            size: Default::default(),
-            ime_requests: Default::default(),
-            mouse_cursor: Default::default(),
-        }))
+        Ok(TestingWindow::new())
     }
 
     fn duration_since_start(&self) -> core::time::Duration {
@@ -94,6 +89,24 @@ pub struct TestingWindow {
     size: Cell<PhysicalSize>,
     pub ime_requests: RefCell<Vec<InputMethodRequest>>,
     pub mouse_cursor: Cell<i_slint_core::items::MouseCursor>,
+    /// When true, the popups are shown in their own window, like with the backends that create
+    /// top-level windows for popups. Otherwise, they are shown in this window.
+    pub popups_as_windows: Cell<bool>,
+    /// The window of the last popup shown in its own window
+    pub popup_window: RefCell<std::rc::Weak<TestingWindow>>,
+}
+
+impl TestingWindow {
+    fn new() -> Rc<Self> {
+        Rc::new_cyclic(|self_weak| TestingWindow {
+            window: i_slint_core::api::Window::new(self_weak.clone() as _),
+            size: Default::default(),
+            ime_requests: Default::default(),
+            mouse_cursor: Default::default(),
+            popups_as_windows: Default::default(),
+            popup_window: Default::default(),
+        })
+    }
 }
 
 impl WindowAdapterInternal for TestingWindow {
@@ -101,6 +114,15 @@ fn as_any(&self) -> &dyn std::any::Any {
         self
     }
 
+    fn create_popup(&self, _geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>> {
+        if !self.popups_as_windows.get() {
+            return None;
+        }
+        let popup = TestingWindow::new();
+        *self.popup_window.borrow_mut() = Rc::downgrade(&popup);
+        Some(popup)
+    }
+
     fn input_method_request(&self, request: i_slint_core::window::InputMethodRequest) {
         self.ime_requests.borrow_mut().push(request)
     }
@@ -253,6 +253,7 @@ fn process_window_event(&mut self, window: Rc<WinitWindowAdapter>, event: Window
         match event {
             WindowEvent::RedrawRequested => self.loop_error = window.draw().err(),
             WindowEvent::Resized(size) => {
+                window.window_state_event();
                 self.loop_error = window.resize_event(size).err();
             }
             WindowEvent::CloseRequested => {
@@ -416,6 +417,7 @@ macro_rules! winit_key_to_char {
             }
             WindowEvent::Occluded(x) => {
                 window.renderer.occluded(x);
+                window.window_state_event();
             }
             _ => {}
         }
@@ -121,6 +121,10 @@ pub struct WinitWindowAdapter {
     constraints: Cell<corelib::window::LayoutConstraints>,
     shown: Cell<bool>,
     window_level: Cell<winit::window::WindowLevel>,
+    /// The last minimized and maximized state that was requested by the properties of the window
+    minimized: Cell<bool>,
+    maximized: Cell<bool>,
+    resizable: Cell<bool>,
 
     pub(crate) renderer: Box<dyn WinitCompatibleRenderer>,
     /// We cache the size because winit_window.inner_size() can return different value between calls (eg, on X11)
@@ -154,6 +158,9 @@ pub(crate) fn new(
             constraints: Default::default(),
             shown: Default::default(),
             window_level: Default::default(),
+            minimized: Default::default(),
+            maximized: Default::default(),
+            resizable: Cell::new(true),
             winit_window: winit_window.clone(),
             size: Default::default(),
             has_explicit_size: Default::default(),
@@ -299,6 +306,16 @@ pub fn resize_event(&self, size: winit::dpi::PhysicalSize<u32>) -> Result<(), Pl
         Ok(())
     }
 
+    /// Forwards the minimized and maximized state of the window to the Window, which ignores it
+    /// if it didn't change.
+    pub fn window_state_event(&self) {
+        let winit_window = self.winit_window();
+        // Not all platforms can report the minimized state
+        let minimized = winit_window.is_minimized().unwrap_or_else(|| self.minimized.get());
+        let maximized = winit_window.is_maximized();
+        self.window().dispatch_event(WindowEvent::WindowStateChanged { minimized, maximized });
+    }
+
     pub fn set_dark_color_scheme(&self, dark_mode: bool) {
         self.dark_color_scheme
             .get_or_init(|| Box::pin(Property::new(false)))
@@ -499,6 +516,17 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
         }
 
         self.with_window_handle(&mut |winit_window| {
+            // Only change the state when the requested one changes, so that a state changed by the
+            // windowing system isn't reverted when the properties keep their binding.
+            let minimized = properties.is_minimized();
+            if self.minimized.replace(minimized) != minimized {
+                winit_window.set_minimized(minimized);
+            }
+            let maximized = properties.is_maximized();
+            if self.maximized.replace(maximized) != maximized {
+                winit_window.set_maximized(maximized);
+            }
+
             if properties.fullscreen() {
                 if winit_window.fullscreen().is_none() {
                     winit_window.set_fullscreen(Some(winit::window::Fullscreen::Borderless(None)));
@@ -517,11 +545,13 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
             }
 
             let new_constraints = properties.layout_constraints();
-            if new_constraints == self.constraints.get() {
+            let resizable = properties.is_resizable();
+            if new_constraints == self.constraints.get() && resizable == self.resizable.get() {
                 return;
             }
 
             self.constraints.set(new_constraints);
+            self.resizable.set(resizable);
 
             // Use our scale factor instead of winit's logical size to take a scale factor override into account.
             let sf = self.window().scale_factor();
@@ -530,7 +560,8 @@ fn update_window_properties(&self, properties: corelib::window::WindowProperties
                 winit::dpi::LogicalSize::new(s.width, s.height).to_physical(sf as f64)
             };
 
-            let resizable = window_is_resizable(new_constraints.min, new_constraints.max);
+            let resizable =
+                resizable && window_is_resizable(new_constraints.min, new_constraints.max);
             // we must call set_resizable before setting the min and max size otherwise setting the min and max size don't work on X11
             winit_window.set_resizable(resizable);
             let winit_min_inner = new_constraints.min.map(into_size);
@@ -569,6 +600,17 @@ fn internal(&self, _: corelib::InternalToken) -> Option<&dyn WindowAdapterIntern
 }
 
 impl WindowAdapterInternal for WinitWindowAdapter {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn create_child_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        use winit::raw_window_handle::HasWindowHandle;
+        let parent_window = self.winit_window.window_handle().ok()?.as_raw();
+        // Safety: the parent window outlives the child window, as the child window is closed with it.
+        let builder =
+            unsafe { Self::window_builder().ok()?.with_parent_window(Some(parent_window)) };
+        let window_adapter = crate::try_create_window_with_fallback_renderer(builder)?;
+        Some(window_adapter as _)
+    }
+
     fn set_mouse_cursor(&self, cursor: MouseCursor) {
         let winit_cursor = match cursor {
             MouseCursor::Default => winit::window::CursorIcon::Default,
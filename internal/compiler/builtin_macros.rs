@@ -27,6 +27,9 @@ pub fn lower_macro(
         BuiltinMacroFunction::Clamp => clamp_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Mod => mod_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Debug => debug_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Assert => assert_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::AssertEq => assert_eq_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::Keyframes => keyframes_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::CubicBezier => {
             let mut has_error = None;
             let expected_argument_type_error =
@@ -38,19 +41,10 @@ pub fn lower_macro(
                     has_error.get_or_insert((n.clone(), "Not enough arguments"));
                     0.
                 }
-                Some((Expression::NumberLiteral(val, Unit::None), _)) => val as f32,
-                // handle negative numbers
-                Some((Expression::UnaryOp { sub, op: '-' }, n)) => match *sub {
-                    Expression::NumberLiteral(val, Unit::None) => (-1.0 * val) as f32,
-                    _ => {
-                        has_error.get_or_insert((n, expected_argument_type_error));
-                        0.
-                    }
-                },
-                Some((_, n)) => {
+                Some((expr, n)) => number_literal(&expr).unwrap_or_else(|| {
                     has_error.get_or_insert((n, expected_argument_type_error));
                     0.
-                }
+                }),
             };
             let expr = Expression::EasingCurve(EasingCurve::CubicBezier(a(), a(), a(), a()));
             if let Some((_, n)) = sub_expr.next() {
@@ -62,7 +56,21 @@ pub fn lower_macro(
 
             expr
         }
+        BuiltinMacroFunction::Spring => spring_macro(n, sub_expr.collect(), diag),
         BuiltinMacroFunction::Rgb => rgb_macro(n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArraySum
+        | BuiltinMacroFunction::ArrayContains
+        | BuiltinMacroFunction::ArrayIndexOf
+        | BuiltinMacroFunction::ArraySlice
+        | BuiltinMacroFunction::ArrayJoin => array_macro(mac, n, sub_expr.collect(), diag),
+        BuiltinMacroFunction::ArrayMap | BuiltinMacroFunction::ArrayFilter => {
+            // Calls with a lambda are resolved in `Expression::from_function_call_node`
+            diag.push_error(
+                "The function expects one lambda argument, such as `item => item.name`".into(),
+                &n,
+            );
+            Expression::Invalid
+        }
     }
 }
 
@@ -223,6 +231,318 @@ fn rgb_macro(
     }
 }
 
+/// Returns the value of a number literal without unit, possibly negated
+fn number_literal(expr: &Expression) -> Option<f32> {
+    match expr {
+        Expression::NumberLiteral(val, Unit::None) => Some(*val as f32),
+        // handle negative numbers
+        Expression::UnaryOp { sub, op: '-' } => match **sub {
+            Expression::NumberLiteral(val, Unit::None) => Some((-1.0 * val) as f32),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn spring_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 3 {
+        diag.push_error(
+            format!(
+                "`spring` needs three values: the `stiffness`, the `damping` and the `mass`, but {} are provided",
+                args.len()
+            ),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut values = [0.; 3];
+    for (i, ((expr, n), name)) in args.iter().zip(["stiffness", "damping", "mass"]).enumerate() {
+        let Some(value) = number_literal(expr) else {
+            diag.push_error("Arguments to spring must be number literal".into(), n);
+            return Expression::Invalid;
+        };
+        let valid = if name == "damping" { value >= 0. } else { value > 0. };
+        if !valid {
+            let requirement = if name == "damping" { "positive or zero" } else { "positive" };
+            diag.push_error(format!("The {name} of a spring must be {requirement}"), n);
+            return Expression::Invalid;
+        }
+        values[i] = value;
+    }
+    let [stiffness, damping, mass] = values;
+    Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass))
+}
+
+/// `keyframes(progress, [v0, v1, ...])` or `keyframes(progress, [v0, v1, ...], [s0, s1, ...])`
+///
+/// The values are evenly distributed between 0 and 1 unless the stops are given.
+/// This is lowered to
+/// `t < s0 ? v0 : t < s1 ? mix(v0, v1, (t - s0) / (s1 - s0)) : ... : v{n-1}`
+fn keyframes_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() != 2 && args.len() != 3 {
+        diag.push_error(
+            format!("The function expects 2 or 3 arguments, but {} are provided", args.len()),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut args = args.into_iter();
+    let (progress, progress_node) = args.next().unwrap();
+    let progress = progress.maybe_convert_to(Type::Float32, &progress_node, diag);
+
+    fn array_literal(
+        (expr, n): (Expression, Option<NodeOrToken>),
+        what: &str,
+        diag: &mut BuildDiagnostics,
+    ) -> Option<(Type, Vec<Expression>, Option<NodeOrToken>)> {
+        match expr {
+            Expression::Array { element_ty, values } => Some((element_ty, values, n)),
+            Expression::Invalid => None,
+            _ => {
+                diag.push_error(format!("The {what} of keyframes must be an array literal"), &n);
+                None
+            }
+        }
+    }
+    let Some((element_ty, values, values_node)) =
+        array_literal(args.next().unwrap(), "values", diag)
+    else {
+        return Expression::Invalid;
+    };
+    if values.is_empty() {
+        diag.push_error("keyframes needs at least one value".into(), &values_node);
+        return Expression::Invalid;
+    }
+    let stops = match args.next() {
+        Some(stops) => {
+            let Some((_, stops, stops_node)) = array_literal(stops, "stops", diag) else {
+                return Expression::Invalid;
+            };
+            if stops.len() != values.len() {
+                diag.push_error(
+                    format!(
+                        "The number of stops ({}) must match the number of values ({})",
+                        stops.len(),
+                        values.len()
+                    ),
+                    &stops_node,
+                );
+                return Expression::Invalid;
+            }
+            stops
+                .into_iter()
+                .map(|s| s.maybe_convert_to(Type::Float32, &stops_node, diag))
+                .collect()
+        }
+        None => (0..values.len())
+            .map(|i| {
+                let pos = if values.len() > 1 { i as f64 / (values.len() - 1) as f64 } else { 0. };
+                Expression::NumberLiteral(pos, Unit::None)
+            })
+            .collect::<Vec<_>>(),
+    };
+
+    let ty = match element_ty {
+        Type::Float32 | Type::Int32 | Type::Percent => Type::Float32,
+        Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Duration
+        | Type::Angle
+        | Type::Rem
+        | Type::Color => element_ty,
+        Type::Invalid => return Expression::Invalid,
+        _ => {
+            diag.push_error(
+                format!("Cannot interpolate values of type '{element_ty}' in keyframes"),
+                &values_node,
+            );
+            return Expression::Invalid;
+        }
+    };
+    let values: Vec<_> =
+        values.into_iter().map(|v| v.maybe_convert_to(ty.clone(), &values_node, diag)).collect();
+
+    let local_progress =
+        format!("keyframes_progress{}", COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed));
+    let t = || Expression::ReadLocalVariable { name: local_progress.clone(), ty: Type::Float32 };
+    let before = |stop: &Expression| Expression::BinaryExpression {
+        lhs: Box::new(t()),
+        rhs: Box::new(stop.clone()),
+        op: '<',
+    };
+
+    let mut result = values.last().unwrap().clone();
+    for i in (0..values.len()).rev() {
+        let segment = if i == 0 {
+            values[0].clone()
+        } else {
+            // factor between 0 and 1 within the segment [stops[i-1], stops[i]]
+            let factor = Expression::BinaryExpression {
+                lhs: Box::new(Expression::BinaryExpression {
+                    lhs: Box::new(t()),
+                    rhs: Box::new(stops[i - 1].clone()),
+                    op: '-',
+                }),
+                rhs: Box::new(Expression::BinaryExpression {
+                    lhs: Box::new(stops[i].clone()),
+                    rhs: Box::new(stops[i - 1].clone()),
+                    op: '-',
+                }),
+                op: '/',
+            };
+            interpolate(values[i - 1].clone(), values[i].clone(), factor)
+        };
+        result = Expression::Condition {
+            condition: Box::new(before(&stops[i])),
+            true_expr: Box::new(segment),
+            false_expr: Box::new(result),
+        };
+    }
+
+    Expression::CodeBlock(vec![
+        Expression::StoreLocalVariable { name: local_progress, value: Box::new(progress) },
+        result,
+    ])
+}
+
+/// Linear interpolation between `from` and `to` (which must have the same type)
+fn interpolate(from: Expression, to: Expression, factor: Expression) -> Expression {
+    if from.ty() == Type::Color {
+        // `to.mix(from, factor)` gives `factor` weight to `to`
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::ColorMix,
+                None,
+            )),
+            arguments: vec![to, from, factor],
+            source_location: None,
+        }
+    } else {
+        Expression::BinaryExpression {
+            lhs: Box::new(from.clone()),
+            rhs: Box::new(Expression::BinaryExpression {
+                lhs: Box::new(Expression::BinaryExpression {
+                    lhs: Box::new(to),
+                    rhs: Box::new(from),
+                    op: '-',
+                }),
+                rhs: Box::new(factor),
+                op: '*',
+            }),
+            op: '+',
+        }
+    }
+}
+
+/// The array member functions. The first argument is the array itself.
+fn array_macro(
+    mac: BuiltinMacroFunction,
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    let mut args = args.into_iter();
+    let Some((array, array_node)) = args.next() else {
+        return Expression::Invalid;
+    };
+    let element_ty = match array.ty() {
+        Type::Array(ty) => *ty,
+        Type::Invalid => return Expression::Invalid,
+        _ => {
+            diag.push_error("Expected an array".into(), &array_node);
+            return Expression::Invalid;
+        }
+    };
+    let args: Vec<_> = args.collect();
+    let (function, expected_args) = match mac {
+        BuiltinMacroFunction::ArraySum => (BuiltinFunction::ArraySum, 0..=0),
+        BuiltinMacroFunction::ArrayContains => (BuiltinFunction::ArrayContains, 1..=1),
+        BuiltinMacroFunction::ArrayIndexOf => (BuiltinFunction::ArrayIndexOf, 1..=1),
+        BuiltinMacroFunction::ArraySlice => (BuiltinFunction::ArraySlice, 1..=2),
+        BuiltinMacroFunction::ArrayJoin => (BuiltinFunction::ArrayJoin, 1..=1),
+        _ => unreachable!(),
+    };
+    if !expected_args.contains(&args.len()) {
+        let expected = if expected_args.start() == expected_args.end() {
+            expected_args.start().to_string()
+        } else {
+            format!("{} or {}", expected_args.start(), expected_args.end())
+        };
+        diag.push_error(
+            format!("The function expects {expected} arguments, but {} are provided", args.len()),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut arguments = vec![array];
+    match mac {
+        BuiltinMacroFunction::ArraySum => {
+            if !matches!(
+                element_ty,
+                Type::Int32
+                    | Type::Float32
+                    | Type::PhysicalLength
+                    | Type::LogicalLength
+                    | Type::Rem
+                    | Type::Duration
+                    | Type::Angle
+                    | Type::Percent
+            ) {
+                diag.push_error(
+                    format!("Cannot compute the sum of an array of '{element_ty}'"),
+                    &array_node,
+                );
+                return Expression::Invalid;
+            }
+        }
+        BuiltinMacroFunction::ArrayContains | BuiltinMacroFunction::ArrayIndexOf => {
+            if matches!(element_ty, Type::Invalid | Type::Void) {
+                diag.push_error(
+                    "Cannot search in an array without element type".into(),
+                    &array_node,
+                );
+                return Expression::Invalid;
+            }
+            arguments.extend(
+                args.into_iter().map(|(e, n)| e.maybe_convert_to(element_ty.clone(), &n, diag)),
+            );
+        }
+        BuiltinMacroFunction::ArraySlice => {
+            arguments
+                .extend(args.into_iter().map(|(e, n)| e.maybe_convert_to(Type::Int32, &n, diag)));
+            if arguments.len() < 3 {
+                arguments.push(Expression::NumberLiteral(i32::MAX as f64, Unit::None));
+            }
+        }
+        BuiltinMacroFunction::ArrayJoin => {
+            if element_ty != Type::String {
+                diag.push_error(
+                    format!("Can only join an array of 'string', not an array of '{element_ty}'"),
+                    &array_node,
+                );
+                return Expression::Invalid;
+            }
+            arguments
+                .extend(args.into_iter().map(|(e, n)| e.maybe_convert_to(Type::String, &n, diag)));
+        }
+        _ => unreachable!(),
+    }
+    let source_location = node.map(|n| n.to_source_location());
+    Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(function, source_location.clone())),
+        arguments,
+        source_location,
+    }
+}
+
 fn debug_macro(
     node: Option<NodeOrToken>,
     args: Vec<(Expression, Option<NodeOrToken>)>,
@@ -255,6 +575,140 @@ fn debug_macro(
     }
 }
 
+fn assert_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.is_empty() || args.len() > 2 {
+        diag.push_error(
+            format!("The function expects 1 or 2 arguments, but {} are provided", args.len()),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut args = args.into_iter();
+    let (condition, condition_node) = args.next().unwrap();
+    let message = match args.next() {
+        Some((message, message_node)) => {
+            message.maybe_convert_to(Type::String, &message_node, diag)
+        }
+        None => Expression::StringLiteral(format!(
+            "assertion `{}` failed",
+            source_text(&condition_node)
+        )),
+    };
+    let condition = condition.maybe_convert_to(Type::Bool, &condition_node, diag);
+    assertion(&node, condition, message)
+}
+
+fn assert_eq_macro(
+    node: Option<NodeOrToken>,
+    args: Vec<(Expression, Option<NodeOrToken>)>,
+    diag: &mut BuildDiagnostics,
+) -> Expression {
+    if args.len() < 2 || args.len() > 3 {
+        diag.push_error(
+            format!("The function expects 2 or 3 arguments, but {} are provided", args.len()),
+            &node,
+        );
+        return Expression::Invalid;
+    }
+    let mut args = args.into_iter();
+    let (left, left_node) = args.next().unwrap();
+    let (right, right_node) = args.next().unwrap();
+    let message = match args.next() {
+        Some((message, message_node)) => {
+            message.maybe_convert_to(Type::String, &message_node, diag)
+        }
+        None => Expression::StringLiteral(format!(
+            "assertion `{} == {}` failed",
+            source_text(&left_node),
+            source_text(&right_node)
+        )),
+    };
+
+    let ty = Expression::common_target_type_for_type_list([left.ty(), right.ty()].into_iter());
+    let counter = COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let left_var = format!("assert_left{counter}");
+    let right_var = format!("assert_right{counter}");
+    let read = |name: &str| Expression::ReadLocalVariable { name: name.into(), ty: ty.clone() };
+
+    let condition = Expression::BinaryExpression {
+        lhs: Box::new(read(&left_var)),
+        rhs: Box::new(read(&right_var)),
+        op: '=',
+    };
+    let concat = |lhs: Expression, rhs: Expression| Expression::BinaryExpression {
+        lhs: Box::new(lhs),
+        op: '+',
+        rhs: Box::new(rhs),
+    };
+    let message = concat(
+        concat(
+            concat(message, Expression::StringLiteral("\n  left: ".into())),
+            to_debug_string(read(&left_var), left_node.clone(), diag),
+        ),
+        concat(
+            Expression::StringLiteral("\n right: ".into()),
+            to_debug_string(read(&right_var), right_node.clone(), diag),
+        ),
+    );
+    Expression::CodeBlock(vec![
+        Expression::StoreLocalVariable {
+            name: left_var,
+            value: Box::new(left.maybe_convert_to(ty.clone(), &left_node, diag)),
+        },
+        Expression::StoreLocalVariable {
+            name: right_var,
+            value: Box::new(right.maybe_convert_to(ty, &right_node, diag)),
+        },
+        assertion(&node, condition, message),
+    ])
+}
+
+/// Return an expression that calls `AssertionFailed` with the source location of `node`
+/// followed by the message, if the condition is false
+fn assertion(node: &Option<NodeOrToken>, condition: Expression, message: Expression) -> Expression {
+    let source_location = node.as_ref().map(|node| node.to_source_location());
+    let location = match &source_location {
+        Some(sl) if sl.span.is_valid() => sl.source_file.as_ref().map(|sf| {
+            let (line, column) = sf.line_column(sl.span.offset);
+            format!("{}:{line}:{column}: ", sf.path().display())
+        }),
+        _ => None,
+    };
+    let message = match location {
+        Some(location) => Expression::BinaryExpression {
+            lhs: Box::new(Expression::StringLiteral(location)),
+            op: '+',
+            rhs: Box::new(message),
+        },
+        None => message,
+    };
+    Expression::Condition {
+        condition: Box::new(condition),
+        true_expr: Box::new(Expression::CodeBlock(vec![])),
+        false_expr: Box::new(Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::AssertionFailed,
+                source_location.clone(),
+            )),
+            arguments: vec![message],
+            source_location,
+        }),
+    }
+}
+
+/// The source code of the node, to be shown in assertion messages
+fn source_text(node: &Option<NodeOrToken>) -> String {
+    match node {
+        Some(NodeOrToken::Node(node)) => node.text().to_string().trim().to_string(),
+        Some(NodeOrToken::Token(token)) => token.text().trim().to_string(),
+        None => String::new(),
+    }
+}
+
 fn to_debug_string(
     expr: Expression,
     node: Option<NodeOrToken>,
@@ -180,6 +180,7 @@ pub fn load_from_path(path: &Path) -> Result<String, Diagnostic> {
             span: Default::default(),
         },
         level: DiagnosticLevel::Error,
+        related: Vec::new(),
     })?;
 
     if path.extension().map_or(false, |e| e == "rs") {
@@ -190,6 +191,7 @@ pub fn load_from_path(path: &Path) -> Result<String, Diagnostic> {
                 span: Default::default(),
             },
             level: DiagnosticLevel::Error,
+            related: Vec::new(),
         });
     }
 
@@ -252,6 +254,8 @@ pub struct Diagnostic {
     message: String,
     span: SourceLocation,
     level: DiagnosticLevel,
+    /// Other locations that help understanding this diagnostic, each with a message
+    related: Vec<(String, SourceLocation)>,
 }
 
 //NOTE! Diagnostic is re-exported in the public API of the interpreter
@@ -285,6 +289,20 @@ pub fn line_column(&self) -> (usize, usize) {
     pub fn source_file(&self) -> Option<&Path> {
         self.span.source_file().map(|sf| sf.path())
     }
+
+    /// Return the other locations related to this diagnostic, with a message explaining each of them.
+    ///
+    /// Each item is the message, the path of the source file and the line and column
+    /// (starting at 1, or (0, 0) if unknown)
+    pub fn related_locations(&self) -> impl Iterator<Item = (&str, Option<&Path>, (usize, usize))> {
+        self.related.iter().map(|(message, location)| {
+            let line_column = match &location.source_file {
+                Some(sf) if location.span.is_valid() => sf.line_column(location.span.offset),
+                _ => (0, 0),
+            };
+            (message.as_str(), location.source_file().map(|sf| sf.path()), line_column)
+        })
+    }
 }
 
 impl std::fmt::Display for Diagnostic {
@@ -307,6 +325,9 @@ pub struct BuildDiagnostics {
     /// FIXME: this doesn't really belong in the diagnostics, it should be somehow returned in another way
     /// (maybe in a compilation state that include the diagnostics?)
     pub all_loaded_files: Vec<PathBuf>,
+
+    /// For each loaded file, including the main file, the .slint files it imports.
+    pub all_loaded_imports: std::collections::HashMap<PathBuf, Vec<PathBuf>>,
 }
 
 impl IntoIterator for BuildDiagnostics {
@@ -329,7 +350,7 @@ pub fn push_diagnostic_with_span(
             "Error message should not end with a period: ({:?})",
             message
         );
-        self.inner.push(Diagnostic { message, span, level });
+        self.inner.push(Diagnostic { message, span, level, related: Vec::new() });
     }
     pub fn push_error_with_span(&mut self, message: String, span: SourceLocation) {
         self.push_diagnostic_with_span(message, span, DiagnosticLevel::Error)
@@ -343,6 +364,16 @@ pub fn push_warning_with_span(&mut self, message: String, span: SourceLocation)
     pub fn push_warning(&mut self, message: String, source: &dyn Spanned) {
         self.push_warning_with_span(message, source.to_source_location());
     }
+    /// Push an error with other related locations, each with a message explaining it
+    pub fn push_error_with_related_locations(
+        &mut self,
+        message: String,
+        source: &dyn Spanned,
+        related: Vec<(String, SourceLocation)>,
+    ) {
+        self.push_error(message, source);
+        self.inner.last_mut().unwrap().related = related;
+    }
     pub fn push_compiler_error(&mut self, error: Diagnostic) {
         self.inner.push(error);
     }
@@ -394,7 +425,7 @@ fn call_diagnostics<Output>(
             .inner
             .into_iter()
             .filter_map(|d| {
-                let spans = if !d.span.span.is_valid() {
+                let mut spans = if !d.span.span.is_valid() {
                     vec![]
                 } else if let Some(sf) = &d.span.source_file {
                     if let Some(ref mut handle_no_source) = handle_no_source {
@@ -421,6 +452,30 @@ fn call_diagnostics<Output>(
                 } else {
                     vec![]
                 };
+                if !spans.is_empty() {
+                    for (label, location) in &d.related {
+                        let Some(sf) = location
+                            .source_file
+                            .as_ref()
+                            .filter(|sf| location.span.is_valid() && sf.source.is_some())
+                        else {
+                            continue;
+                        };
+                        let path: String = sf.path.to_string_lossy().into();
+                        let file = codemap_files.entry(path).or_insert_with(|| {
+                            codemap.add_file(
+                                sf.path.to_string_lossy().into(),
+                                sf.source.clone().unwrap_or_default(),
+                            )
+                        });
+                        let offset = location.span.offset as u64;
+                        spans.push(codemap_diagnostic::SpanLabel {
+                            span: file.span.subspan(offset, offset),
+                            style: codemap_diagnostic::SpanStyle::Secondary,
+                            label: Some(label.clone()),
+                        });
+                    }
+                }
                 Some(codemap_diagnostic::Diagnostic {
                     level: d.level.into(),
                     message: d.message,
@@ -22,7 +22,13 @@ pub enum BuiltinFunction {
     GetWindowScaleFactor,
     GetWindowDefaultFontSize,
     AnimationTick,
+    /// Like AnimationTick, but doesn't request the next animation frame
+    CurrentTick,
+    /// Map a value between 0 and 1 through an easing curve
+    ApplyEasingCurve,
     Debug,
+    /// Report the failure of an `assert()`, with the message as argument
+    AssertionFailed,
     Mod,
     Round,
     Ceil,
@@ -40,6 +46,8 @@ pub enum BuiltinFunction {
     SetFocusItem,
     ShowPopupWindow,
     ClosePopupWindow,
+    ShowChildWindow,
+    CloseChildWindow,
     SetSelectionOffsets,
     /// A function that belongs to an item (such as TextInput's select-all function).
     ItemMemberFunction(String),
@@ -47,6 +55,18 @@ pub enum BuiltinFunction {
     StringToFloat,
     /// the "42".is_float()
     StringIsFloat,
+    StringToUppercase,
+    StringToLowercase,
+    StringTrim,
+    StringContains,
+    StringSplit,
+    StringReplace,
+    StringCharacterCount,
+    FormatNumber,
+    FormatPercent,
+    FormatByteSize,
+    FormatDuration,
+    FormatDate,
     ColorBrighter,
     ColorDarker,
     ColorTransparentize,
@@ -54,6 +74,13 @@ pub enum BuiltinFunction {
     ColorWithAlpha,
     ImageSize,
     ArrayLength,
+    /// The sum of all the elements. The return type is the type of the array elements
+    ArraySum,
+    ArrayContains,
+    ArrayIndexOf,
+    /// `array.slice(start, end)`. The return type is the type of the array
+    ArraySlice,
+    ArrayJoin,
     Rgb,
     DarkColorScheme,
     TextInputFocused,
@@ -82,11 +109,37 @@ pub enum BuiltinMacroFunction {
     /// Add the right conversion operations so that the return type is the same as the argument type
     Mod,
     CubicBezier,
+    /// `spring(stiffness, damping, mass)`: the arguments must be number literals
+    Spring,
     /// The argument can be r,g,b,a or r,g,b and they can be percentages or integer.
     /// transform the argument so it is always rgb(r, g, b, a) with r, g, b between 0 and 255.
     Rgb,
     /// transform `debug(a, b, c)` into debug `a + " " + b + " " + c`
     Debug,
+    /// `assert(condition)` or `assert(condition, message)`: report a failure with the
+    /// source location if the condition is false
+    Assert,
+    /// `assert-eq(left, right)` or `assert-eq(left, right, message)`: like `assert`, but
+    /// also reports both values
+    AssertEq,
+    /// `keyframes(progress, [values...], [stops...])`: transform into a series of conditional
+    /// expressions interpolating linearly between two consecutive values
+    Keyframes,
+    /// `array.sum()`: check that the array contains numbers
+    ArraySum,
+    /// `array.contains(value)`: convert the value to the type of the array elements
+    ArrayContains,
+    /// `array.index-of(value)`: convert the value to the type of the array elements
+    ArrayIndexOf,
+    /// `array.slice(start)` or `array.slice(start, end)`
+    ArraySlice,
+    /// `array.join(separator)`: check that the array contains strings
+    ArrayJoin,
+    /// `array.map(item => expression)`: the argument is a lambda, resolved with the element
+    /// type of the array into an [`Expression::ArrayMap`]
+    ArrayMap,
+    /// `array.filter(item => condition)`
+    ArrayFilter,
 }
 
 impl BuiltinFunction {
@@ -99,10 +152,14 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::GetWindowDefaultFontSize => {
                 Type::Function { return_type: Box::new(Type::LogicalLength), args: vec![] }
             }
-            BuiltinFunction::AnimationTick => {
+            BuiltinFunction::AnimationTick | BuiltinFunction::CurrentTick => {
                 Type::Function { return_type: Type::Duration.into(), args: vec![] }
             }
-            BuiltinFunction::Debug => {
+            BuiltinFunction::ApplyEasingCurve => Type::Function {
+                return_type: Box::new(Type::Float32),
+                args: vec![Type::Easing, Type::Float32],
+            },
+            BuiltinFunction::Debug | BuiltinFunction::AssertionFailed => {
                 Type::Function { return_type: Box::new(Type::Void), args: vec![Type::String] }
             }
             BuiltinFunction::Mod => Type::Function {
@@ -129,12 +186,13 @@ pub fn ty(&self) -> Type {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference],
             },
-            BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => {
-                Type::Function {
-                    return_type: Box::new(Type::Void),
-                    args: vec![Type::ElementReference],
-                }
-            }
+            BuiltinFunction::ShowPopupWindow
+            | BuiltinFunction::ClosePopupWindow
+            | BuiltinFunction::ShowChildWindow
+            | BuiltinFunction::CloseChildWindow => Type::Function {
+                return_type: Box::new(Type::Void),
+                args: vec![Type::ElementReference],
+            },
             BuiltinFunction::SetSelectionOffsets => Type::Function {
                 return_type: Box::new(Type::Void),
                 args: vec![Type::ElementReference, Type::Int32, Type::Int32],
@@ -149,6 +207,39 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::StringIsFloat => {
                 Type::Function { return_type: Box::new(Type::Bool), args: vec![Type::String] }
             }
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::String] }
+            }
+            BuiltinFunction::StringContains => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::StringSplit => Type::Function {
+                return_type: Box::new(Type::Array(Box::new(Type::String))),
+                args: vec![Type::String, Type::String],
+            },
+            BuiltinFunction::StringReplace => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::String, Type::String, Type::String],
+            },
+            BuiltinFunction::StringCharacterCount => {
+                Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::String] }
+            }
+            BuiltinFunction::FormatNumber
+            | BuiltinFunction::FormatPercent
+            | BuiltinFunction::FormatByteSize => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Float32, Type::Int32],
+            },
+            BuiltinFunction::FormatDuration => {
+                Type::Function { return_type: Box::new(Type::String), args: vec![Type::Duration] }
+            }
+            BuiltinFunction::FormatDate => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Int32, Type::Int32, Type::Int32],
+            },
             BuiltinFunction::ImplicitLayoutInfo(_) => Type::Function {
                 return_type: Box::new(crate::layout::layout_info_type()),
                 args: vec![Type::ElementReference],
@@ -189,6 +280,27 @@ pub fn ty(&self) -> Type {
             BuiltinFunction::ArrayLength => {
                 Type::Function { return_type: Box::new(Type::Int32), args: vec![Type::Model] }
             }
+            // The actual return type depends on the array, see `Expression::ty`
+            BuiltinFunction::ArraySum => {
+                Type::Function { return_type: Box::new(Type::Invalid), args: vec![Type::Model] }
+            }
+            BuiltinFunction::ArraySlice => Type::Function {
+                return_type: Box::new(Type::Model),
+                args: vec![Type::Model, Type::Int32, Type::Int32],
+            },
+            // The second argument has the type of the array elements
+            BuiltinFunction::ArrayContains => Type::Function {
+                return_type: Box::new(Type::Bool),
+                args: vec![Type::Model, Type::Invalid],
+            },
+            BuiltinFunction::ArrayIndexOf => Type::Function {
+                return_type: Box::new(Type::Int32),
+                args: vec![Type::Model, Type::Invalid],
+            },
+            BuiltinFunction::ArrayJoin => Type::Function {
+                return_type: Box::new(Type::String),
+                args: vec![Type::Model, Type::String],
+            },
             BuiltinFunction::Rgb => Type::Function {
                 return_type: Box::new(Type::Color),
                 args: vec![Type::Int32, Type::Int32, Type::Int32, Type::Float32],
@@ -234,9 +346,12 @@ fn is_const(&self) -> bool {
             BuiltinFunction::GetWindowScaleFactor => false,
             BuiltinFunction::GetWindowDefaultFontSize => false,
             BuiltinFunction::AnimationTick => false,
+            BuiltinFunction::CurrentTick => false,
+            BuiltinFunction::ApplyEasingCurve => true,
             BuiltinFunction::DarkColorScheme => false,
             // Even if it is not pure, we optimize it away anyway
             BuiltinFunction::Debug => true,
+            BuiltinFunction::AssertionFailed => false,
             BuiltinFunction::Mod
             | BuiltinFunction::Round
             | BuiltinFunction::Ceil
@@ -253,9 +368,23 @@ fn is_const(&self) -> bool {
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
             BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
+            BuiltinFunction::ShowChildWindow | BuiltinFunction::CloseChildWindow => false,
             BuiltinFunction::SetSelectionOffsets => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringCharacterCount => true,
+            // The result depends on the current locale
+            BuiltinFunction::FormatNumber
+            | BuiltinFunction::FormatPercent
+            | BuiltinFunction::FormatByteSize
+            | BuiltinFunction::FormatDuration
+            | BuiltinFunction::FormatDate => false,
             BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
             | BuiltinFunction::ColorTransparentize
@@ -270,6 +399,11 @@ fn is_const(&self) -> bool {
             #[cfg(target_arch = "wasm32")]
             BuiltinFunction::ImageSize => false,
             BuiltinFunction::ArrayLength => true,
+            BuiltinFunction::ArraySum
+            | BuiltinFunction::ArrayContains
+            | BuiltinFunction::ArrayIndexOf
+            | BuiltinFunction::ArraySlice
+            | BuiltinFunction::ArrayJoin => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::SetTextInputFocused => false,
             BuiltinFunction::TextInputFocused => false,
@@ -288,9 +422,11 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::GetWindowScaleFactor => true,
             BuiltinFunction::GetWindowDefaultFontSize => true,
             BuiltinFunction::AnimationTick => true,
+            BuiltinFunction::CurrentTick => true,
+            BuiltinFunction::ApplyEasingCurve => true,
             BuiltinFunction::DarkColorScheme => true,
             // Even if it has technically side effect, we still consider it as pure for our purpose
-            BuiltinFunction::Debug => true,
+            BuiltinFunction::Debug | BuiltinFunction::AssertionFailed => true,
             BuiltinFunction::Mod
             | BuiltinFunction::Round
             | BuiltinFunction::Ceil
@@ -307,9 +443,22 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ATan => true,
             BuiltinFunction::SetFocusItem => false,
             BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => false,
+            BuiltinFunction::ShowChildWindow | BuiltinFunction::CloseChildWindow => false,
             BuiltinFunction::SetSelectionOffsets => false,
             BuiltinFunction::ItemMemberFunction(..) => false,
             BuiltinFunction::StringToFloat | BuiltinFunction::StringIsFloat => true,
+            BuiltinFunction::StringToUppercase
+            | BuiltinFunction::StringToLowercase
+            | BuiltinFunction::StringTrim
+            | BuiltinFunction::StringContains
+            | BuiltinFunction::StringSplit
+            | BuiltinFunction::StringReplace
+            | BuiltinFunction::StringCharacterCount => true,
+            BuiltinFunction::FormatNumber
+            | BuiltinFunction::FormatPercent
+            | BuiltinFunction::FormatByteSize
+            | BuiltinFunction::FormatDuration
+            | BuiltinFunction::FormatDate => true,
             BuiltinFunction::ColorBrighter
             | BuiltinFunction::ColorDarker
             | BuiltinFunction::ColorTransparentize
@@ -317,6 +466,11 @@ pub fn is_pure(&self) -> bool {
             | BuiltinFunction::ColorWithAlpha => true,
             BuiltinFunction::ImageSize => true,
             BuiltinFunction::ArrayLength => true,
+            BuiltinFunction::ArraySum
+            | BuiltinFunction::ArrayContains
+            | BuiltinFunction::ArrayIndexOf
+            | BuiltinFunction::ArraySlice
+            | BuiltinFunction::ArrayJoin => true,
             BuiltinFunction::Rgb => true,
             BuiltinFunction::ImplicitLayoutInfo(_) => true,
             BuiltinFunction::ItemAbsolutePosition => true,
@@ -328,6 +482,22 @@ pub fn is_pure(&self) -> bool {
             BuiltinFunction::Translate => true,
         }
     }
+
+    /// Returns true if the return type of this function depends on the type of the array
+    /// passed as first argument, in which case [`Self::array_return_type`] must be used
+    pub fn depends_on_array_type(&self) -> bool {
+        matches!(self, BuiltinFunction::ArraySum | BuiltinFunction::ArraySlice)
+    }
+
+    /// The return type of a function for which [`Self::depends_on_array_type`] is true,
+    /// given the type of the array
+    pub fn array_return_type(&self, array_ty: Type) -> Type {
+        match (self, array_ty) {
+            (BuiltinFunction::ArraySum, Type::Array(elem)) => *elem,
+            (BuiltinFunction::ArraySlice, ty @ Type::Array(_)) => ty,
+            _ => Type::Invalid,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
@@ -634,6 +804,19 @@ pub enum Expression {
         lhs: Box<Expression>,
         rhs: Box<Expression>,
     },
+
+    /// `array.map(variable => expression)` or `array.filter(variable => expression)`.
+    /// `expression` is evaluated for each element of the array, which is stored in the local
+    /// variable `variable`.
+    ArrayMap {
+        /// This expression should have [`Type::Array`] type
+        array: Box<Expression>,
+        variable: String,
+        expression: Box<Expression>,
+        /// When true, `expression` is a condition and the result contains the elements for
+        /// which it is true. Otherwise, the result contains the values of `expression`.
+        filter: bool,
+    },
 }
 
 impl Expression {
@@ -673,10 +856,15 @@ pub fn ty(&self) -> Type {
             },
             Expression::Cast { to, .. } => to.clone(),
             Expression::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty()),
-            Expression::FunctionCall { function, .. } => match function.ty() {
-                Type::Function { return_type, .. } => *return_type,
-                Type::Callback { return_type, .. } => return_type.map_or(Type::Void, |x| *x),
-                _ => Type::Invalid,
+            Expression::FunctionCall { function, arguments, .. } => match &**function {
+                Expression::BuiltinFunctionReference(f, _) if f.depends_on_array_type() => {
+                    f.array_return_type(arguments.first().map_or(Type::Invalid, |a| a.ty()))
+                }
+                _ => match function.ty() {
+                    Type::Function { return_type, .. } => *return_type,
+                    Type::Callback { return_type, .. } => return_type.map_or(Type::Void, |x| *x),
+                    _ => Type::Invalid,
+                },
             },
             Expression::SelfAssignment { .. } => Type::Void,
             Expression::ImageReference { .. } => Type::Image,
@@ -761,6 +949,13 @@ pub fn ty(&self) -> Type {
             Expression::ComputeLayoutInfo(..) => crate::layout::layout_info_type(),
             Expression::SolveLayout(..) => Type::LayoutCache,
             Expression::MinMax { ty, .. } => ty.clone(),
+            Expression::ArrayMap { array, expression, filter, .. } => {
+                if *filter {
+                    array.ty()
+                } else {
+                    Type::Array(Box::new(expression.ty()))
+                }
+            }
         }
     }
 
@@ -863,6 +1058,10 @@ pub fn visit(&self, mut visitor: impl FnMut(&Self)) {
                 visitor(lhs);
                 visitor(rhs);
             }
+            Expression::ArrayMap { array, expression, .. } => {
+                visitor(array);
+                visitor(expression);
+            }
         }
     }
 
@@ -967,6 +1166,10 @@ pub fn visit_mut(&mut self, mut visitor: impl FnMut(&mut Self)) {
                 visitor(lhs);
                 visitor(rhs);
             }
+            Expression::ArrayMap { array, expression, .. } => {
+                visitor(array);
+                visitor(expression);
+            }
         }
     }
 
@@ -1042,6 +1245,8 @@ pub fn is_constant(&self) -> bool {
             Expression::ComputeLayoutInfo(..) => false,
             Expression::SolveLayout(..) => false,
             Expression::MinMax { lhs, rhs, .. } => lhs.is_constant() && rhs.is_constant(),
+            // Like for ReadLocalVariable, we don't know if the expression is constant
+            Expression::ArrayMap { .. } => false,
         }
     }
 
@@ -1498,6 +1703,8 @@ pub enum EasingCurve {
     EaseInBounce,
     EaseOutBounce,
     EaseInOutBounce,
+    /// stiffness, damping, mass
+    Spring(f32, f32, f32),
     // CubicBezierNonConst([Box<Expression>; 4]),
     // Custom(Box<dyn Fn(f32)->f32>),
 }
@@ -1674,5 +1881,11 @@ pub fn pretty_print(f: &mut dyn std::fmt::Write, expression: &Expression) -> std
             pretty_print(f, rhs)?;
             write!(f, ")")
         }
+        Expression::ArrayMap { array, variable, expression, filter } => {
+            pretty_print(f, array)?;
+            write!(f, ".{}({} => ", if *filter { "filter" } else { "map" }, variable)?;
+            pretty_print(f, expression)?;
+            write!(f, ")")
+        }
     }
 }
@@ -480,7 +480,7 @@ fn handle_property_init(
                 init = init_expr
             );
 
-            if binding_expression.is_state_info {
+            let set_binding = if binding_expression.is_state_info {
                 format!("slint::private_api::set_state_binding({}, {});", prop_access, binding_code)
             } else {
                 match &binding_expression.animation {
@@ -507,7 +507,11 @@ fn handle_property_init(
                     }
                     None => format!("{}.set_binding({});", prop_access, binding_code),
                 }
-            }
+            };
+            format!(
+                "{set_binding}\n#ifndef NDEBUG\n{prop_access}.set_binding_debug_name(\"{}\");\n#endif",
+                escape_string(&llr::pretty_print::binding_debug_name(prop, ctx))
+            )
         });
     }
 }
@@ -1717,9 +1721,11 @@ fn generate_sub_component(
             let lv_h = access_member(&listview.listview_height, &ctx);
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
+            let scroll_to_row = access_member(&listview.scroll_to_row, &ctx);
 
             format!(
-                "self->{}.ensure_updated_listview(self, &{}, &{}, &{}, {}.get(), {}.get());",
+                "{{ int scroll_to_row = {scroll_to_row}.get(); if (scroll_to_row >= 0) {scroll_to_row}.set(-1); \
+                self->{}.ensure_updated_listview(self, &{}, &{}, &{}, {}.get(), {}.get(), scroll_to_row); }}",
                 repeater_id, vp_w, vp_h, vp_y, lv_w, lv_h
             )
         } else {
@@ -2831,6 +2837,10 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
         Expression::EasingCurve(EasingCurve::EaseInBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInBounce".into(),
         Expression::EasingCurve(EasingCurve::EaseOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseOutElastic".into(),
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => "slint::cbindgen_private::EasingCurve::Tag::EaseInOutElastic".into(),
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => format!(
+            "slint::cbindgen_private::EasingCurve(slint::cbindgen_private::EasingCurve::Tag::Spring, {}, {}, {})",
+            stiffness, damping, mass
+        ),
         Expression::LinearGradient{angle, stops} => {
             let angle = compile_expression(angle, ctx);
             let mut stops_it = stops.iter().map(|(color, stop)| {
@@ -2916,6 +2926,17 @@ fn compile_expression(expr: &llr::Expression, ctx: &EvaluationContext) -> String
                 rhs_code = rhs_code
             )
         }
+        Expression::ArrayMap { array, variable, expression, filter } => {
+            let function = if *filter { "model_filter" } else { "model_map" };
+            let return_ty = if *filter { Type::Bool } else { expression.ty(ctx) };
+            format!(
+                "slint::private_api::{function}({array}, [&](const auto &{variable}) -> {return_ty} {{ return {expression}; }})",
+                array = compile_expression(array, ctx),
+                variable = ident(variable),
+                return_ty = return_ty.cpp_type().unwrap_or_else(|| "auto".into()),
+                expression = compile_expression(expression, ctx),
+            )
+        }
     }
 }
 
@@ -2940,10 +2961,21 @@ fn compile_builtin_function_call(
             )
         }
         BuiltinFunction::AnimationTick => "slint::cbindgen_private::slint_animation_tick()".into(),
+        BuiltinFunction::CurrentTick => "slint::cbindgen_private::slint_current_tick()".into(),
+        BuiltinFunction::ApplyEasingCurve => {
+            format!(
+                "slint::private_api::easing_curve({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::Debug => {
             ctx.generator_state.conditional_includes.iostream.set(true);
             format!("std::cout << {} << std::endl;", a.join("<<"))
         }
+        BuiltinFunction::AssertionFailed => {
+            format!("slint::private_api::assertion_failed({})", a.next().unwrap())
+        }
         BuiltinFunction::Mod => {
             ctx.generator_state.conditional_includes.cmath.set(true);
             format!("std::fmod({}, {})", a.next().unwrap(), a.next().unwrap())
@@ -3026,6 +3058,66 @@ fn compile_builtin_function_call(
             ctx.generator_state.conditional_includes.cstdlib.set(true);
             format!("[](const auto &a){{ auto e1 = std::end(a); auto e2 = const_cast<char*>(e1); auto r = std::strtod(std::begin(a), &e2); return e1 == e2 ? r : 0; }}({})", a.next().unwrap())
         }
+        BuiltinFunction::StringToUppercase => format!("{}.to_uppercase()", a.next().unwrap()),
+        BuiltinFunction::StringToLowercase => format!("{}.to_lowercase()", a.next().unwrap()),
+        BuiltinFunction::StringTrim => format!("{}.trim()", a.next().unwrap()),
+        BuiltinFunction::StringContains => {
+            format!(
+                "slint::private_api::string_contains({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::StringSplit => {
+            format!(
+                "slint::private_api::string_split({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::StringReplace => {
+            format!(
+                "slint::private_api::string_replace({}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::StringCharacterCount => {
+            format!("slint::private_api::string_character_count({})", a.next().unwrap())
+        }
+        BuiltinFunction::FormatNumber => {
+            format!(
+                "slint::private_api::format_number({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::FormatPercent => {
+            format!(
+                "slint::private_api::format_percent({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::FormatByteSize => {
+            format!(
+                "slint::private_api::format_byte_size({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::FormatDuration => {
+            format!("slint::private_api::format_duration({})", a.next().unwrap())
+        }
+        BuiltinFunction::FormatDate => {
+            format!(
+                "slint::private_api::format_date({}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
         BuiltinFunction::ColorBrighter => {
             format!("{}.brighter({})", a.next().unwrap(), a.next().unwrap())
         }
@@ -3047,6 +3139,34 @@ fn compile_builtin_function_call(
         BuiltinFunction::ArrayLength => {
             format!("slint::private_api::model_length({})", a.next().unwrap())
         }
+        BuiltinFunction::ArraySum => {
+            format!("slint::private_api::model_sum({})", a.next().unwrap())
+        }
+        BuiltinFunction::ArrayContains => {
+            format!(
+                "(slint::private_api::model_index_of({}, {}) >= 0)",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ArrayIndexOf => {
+            format!(
+                "slint::private_api::model_index_of({}, {})",
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ArraySlice => {
+            format!(
+                "slint::private_api::model_slice({}, {}, {})",
+                a.next().unwrap(),
+                a.next().unwrap(),
+                a.next().unwrap()
+            )
+        }
+        BuiltinFunction::ArrayJoin => {
+            format!("slint::private_api::model_join({}, {})", a.next().unwrap(), a.next().unwrap())
+        }
         BuiltinFunction::Rgb => {
             format!("slint::Color::from_argb_uint8(std::clamp(static_cast<float>({a}) * 255., 0., 255.), std::clamp(static_cast<int>({r}), 0, 255), std::clamp(static_cast<int>({g}), 0, 255), std::clamp(static_cast<int>({b}), 0, 255))",
                 r = a.next().unwrap(),
@@ -3065,7 +3185,7 @@ fn compile_builtin_function_call(
             format!("{}.text_input_focused()", access_window_field(ctx))
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, llr::Expression::PropertyReference(parent_ref)] =
+            if let [llr::Expression::NumberLiteral(popup_index), x, y, close_on_click, modal, llr::Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -3086,8 +3206,9 @@ fn compile_builtin_function_call(
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
                 let close_on_click = compile_expression(close_on_click, ctx);
+                let modal = compile_expression(modal, ctx);
                 format!(
-                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {{ {parent_component} }})"
+                    "{window}.show_popup<{popup_window_id}>({component_access}, {{ static_cast<float>({x}), static_cast<float>({y}) }}, {close_on_click}, {modal}, {{ {parent_component} }})"
                 )
             } else {
                 panic!("internal error: invalid args to ShowPopupWindow {:?}", arguments)
@@ -3097,6 +3218,43 @@ fn compile_builtin_function_call(
             let window = access_window_field(ctx);
             format!("{window}.close_popup()")
         }
+        BuiltinFunction::ShowChildWindow => {
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut parent_ctx = ctx;
+                let mut component_access = "self".into();
+
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access = format!("{}->parent", component_access);
+                        parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
+                    }
+                };
+
+                let window = access_window_field(ctx);
+                let current_sub_component = parent_ctx.current_sub_component.unwrap();
+                let child_window_id =
+                    ident(&current_sub_component.popup_windows[*popup_index as usize].root.name);
+                let parent_component = access_item_rc(parent_ref, ctx);
+                format!(
+                    "{window}.show_child_window<{child_window_id}>({popup_index}, {component_access}, {{ {parent_component} }})"
+                )
+            } else {
+                panic!("internal error: invalid args to ShowChildWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::CloseChildWindow => {
+            if let [llr::Expression::NumberLiteral(popup_index), llr::Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let window = access_window_field(ctx);
+                let parent_component = access_item_rc(parent_ref, ctx);
+                format!("{window}.close_child_window({popup_index}, {{ {parent_component} }})")
+            } else {
+                panic!("internal error: invalid args to CloseChildWindow {:?}", arguments)
+            }
+        }
         BuiltinFunction::SetSelectionOffsets => {
             if let [llr::Expression::PropertyReference(pr), from, to] = arguments {
                 let item = access_member(pr, ctx);
@@ -3283,7 +3441,7 @@ fn generate_type_aliases(file: &mut File, doc: &Document) {
         .exports
         .iter()
         .filter_map(|export| match &export.1 {
-            Either::Left(component) if !component.is_global() => {
+            Either::Left(component) if !component.is_global() && !component.is_interface => {
                 Some((&export.0.name, &component.id))
             }
             Either::Right(ty) => match &ty {
@@ -12,6 +12,7 @@
     this is usually a local variable to the init code that shouldn't rbe relied upon by the binding code.
 */
 
+use crate::diagnostics::Spanned;
 use crate::expression_tree::{BuiltinFunction, EasingCurve, MinMaxOp, OperatorClass};
 use crate::langtype::{ElementType, Enumeration, EnumerationValue, Type};
 use crate::layout::Orientation;
@@ -19,12 +20,14 @@
     self, EvaluationContext as llr_EvaluationContext, Expression, ParentCtx as llr_ParentCtx,
     TypeResolutionContext as _,
 };
-use crate::object_tree::Document;
+use crate::object_tree::{Component, Document};
 use itertools::Either;
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
 use std::collections::BTreeMap;
 use std::num::NonZeroUsize;
+use std::path::Path;
+use std::rc::Rc;
 use std::str::FromStr;
 
 type EvaluationContext<'a> = llr_EvaluationContext<'a, TokenStream>;
@@ -139,6 +142,30 @@ fn set_primitive_property_value(ty: &Type, value_expression: TokenStream) -> Tok
 
 /// Generate the rust code for the given component.
 pub fn generate(doc: &Document) -> TokenStream {
+    generate_impl(doc, None)
+}
+
+/// Generate the rust code for the given component, with the code of each global and of each
+/// component in its own module.
+///
+/// `write_module` is called with the name of each of these modules, the path of the .slint file
+/// that defines the global or component, and its code. It must save the code in a file and
+/// return its absolute path, which is included by the returned code.
+/// The structs and enums, as well as the embedded resources, are part of the returned code.
+///
+/// The code of a module only depends on the globals and components it uses, so that rustc can
+/// reuse what it compiled for a module when other components change.
+pub fn generate_with_modules(
+    doc: &Document,
+    mut write_module: impl FnMut(&str, Option<&Path>, TokenStream) -> String,
+) -> TokenStream {
+    generate_impl(doc, Some(&mut write_module))
+}
+
+fn generate_impl(
+    doc: &Document,
+    write_module: Option<&mut dyn FnMut(&str, Option<&Path>, TokenStream) -> String>,
+) -> TokenStream {
     let (structs_and_enums_ids, structs_and_enum_def): (Vec<_>, Vec<_>) = doc
         .root_component
         .used_types
@@ -164,15 +191,50 @@ pub fn generate(doc: &Document) -> TokenStream {
 
     let llr = crate::llr::lower_to_item_tree::lower_to_item_tree(&doc.root_component);
 
-    let sub_compos = llr
-        .sub_components
+    // The items used by the other components need to be visible to the parent module when each
+    // component is generated in its own module
+    let vis = if write_module.is_some() { quote!(pub(super)) } else { quote!() };
+
+    // The .slint file that defines a component. The globals and sub components of the LLR are in
+    // the same order as the used types they are lowered from.
+    let source_path = |component: &Rc<Component>| {
+        component.node.source_file().map(|source_file| source_file.path().to_owned())
+    };
+    let used_types = doc.root_component.used_types.borrow();
+
+    // The name, the .slint file, and the code of the globals and components, in that order
+    let mut modules = llr
+        .globals
         .iter()
-        .map(|sub_compo| generate_sub_component(sub_compo, &llr, None, quote!(), None, false))
+        .zip(used_types.globals.iter())
+        .filter(|(glob, _)| !glob.is_builtin)
+        .map(|(glob, global)| {
+            (
+                format_ident!("global_{}", ident(&glob.name)),
+                source_path(global),
+                generate_global(glob, &llr, &vis),
+            )
+        })
         .collect::<Vec<_>>();
+    modules.extend(llr.sub_components.iter().zip(used_types.sub_components.iter()).map(
+        |(sub_compo, component)| {
+            (
+                format_ident!("component_{}", ident(&sub_compo.name)),
+                source_path(component),
+                generate_sub_component(sub_compo, &llr, None, quote!(), None, false, &vis),
+            )
+        },
+    ));
 
-    let compo = generate_public_component(&llr);
+    let compo = generate_public_component(&llr, &vis);
     let compo_id = public_component_id(&llr.item_tree.root);
     let compo_module = format_ident!("slint_generated{}", compo_id);
+    modules.push((
+        format_ident!("component_{}", compo_id),
+        source_path(&doc.root_component),
+        compo,
+    ));
+
     let version_check = format_ident!(
         "VersionCheck_{}_{}_{}",
         env!("CARGO_PKG_VERSION_MAJOR"),
@@ -180,8 +242,6 @@ pub fn generate(doc: &Document) -> TokenStream {
         env!("CARGO_PKG_VERSION_PATCH"),
     );
 
-    let globals =
-        llr.globals.iter().filter(|glob| !glob.is_builtin).map(|glob| generate_global(glob, &llr));
     let globals_ids = llr.globals.iter().filter(|glob| glob.exported).flat_map(|glob| {
         std::iter::once(ident(&glob.name)).chain(glob.aliases.iter().map(|x| ident(x)))
     });
@@ -189,6 +249,32 @@ pub fn generate(doc: &Document) -> TokenStream {
     let resource_symbols = generate_resources(doc);
     let named_exports = generate_named_exports(doc);
 
+    let imports = quote! {
+        use slint::private_unstable_api::re_exports as sp;
+        #[allow(unused_imports)]
+        use sp::{RepeatedItemTree as _, ModelExt as _, Model as _, Float as _};
+    };
+
+    let modules = match write_module {
+        None => modules.into_iter().map(|(_, _, code)| code).collect::<Vec<_>>(),
+        Some(write_module) => modules
+            .into_iter()
+            .map(|(name, source_path, code)| {
+                let path =
+                    write_module(&name.to_string(), source_path.as_deref(), quote!(#imports #code));
+                quote! {
+                    mod #name {
+                        #[allow(unused_imports)]
+                        use super::*;
+                        include!(#path);
+                    }
+                    #[allow(unused_imports)]
+                    pub use #name::*;
+                }
+            })
+            .collect(),
+    };
+
     quote! {
         #[allow(non_snake_case)]
         #[allow(non_camel_case_types)]
@@ -204,13 +290,9 @@ pub fn generate(doc: &Document) -> TokenStream {
                                           // according to clippy!
         #[allow(clippy::overly_complex_bool_expr)]
         mod #compo_module {
-            use slint::private_unstable_api::re_exports as sp;
-            #[allow(unused_imports)]
-            use sp::{RepeatedItemTree as _, ModelExt as _, Model as _, Float as _};
+            #imports
             #(#structs_and_enum_def)*
-            #(#globals)*
-            #(#sub_compos)*
-            #compo
+            #(#modules)*
             #(#resource_symbols)*
             const _THE_SAME_VERSION_MUST_BE_USED_FOR_THE_COMPILER_AND_THE_RUNTIME : slint::#version_check = slint::#version_check;
         }
@@ -221,7 +303,7 @@ mod #compo_module {
     }
 }
 
-fn generate_public_component(llr: &llr::PublicComponent) -> TokenStream {
+fn generate_public_component(llr: &llr::PublicComponent, vis: &TokenStream) -> TokenStream {
     let public_component_id = public_component_id(&llr.item_tree.root);
     let inner_component_id = inner_component_id(&llr.item_tree.root);
     let global_container_id = format_ident!("Globals_{}", public_component_id);
@@ -231,10 +313,11 @@ fn generate_public_component(llr: &llr::PublicComponent) -> TokenStream {
         llr,
         None,
         quote!(
-            globals: #global_container_id,
+            #vis globals: #global_container_id,
             window_adapter_: sp::OnceCell<sp::WindowAdapterRc>,
         ),
         None,
+        vis,
     );
 
     let ctx = EvaluationContext {
@@ -259,7 +342,7 @@ fn generate_public_component(llr: &llr::PublicComponent) -> TokenStream {
 
     quote!(
         #component
-        pub struct #public_component_id(sp::VRc<sp::ItemTreeVTable, #inner_component_id>);
+        pub struct #public_component_id(#vis sp::VRc<sp::ItemTreeVTable, #inner_component_id>);
 
         impl #public_component_id {
             pub fn new() -> core::result::Result<Self, slint::PlatformError> {
@@ -317,8 +400,8 @@ fn global<'a, T: slint::Global<'a, Self>>(&'a self) -> T {
         }
 
         #[allow(dead_code)] // FIXME: some global are unused because of optimization, we should then remove them completely
-        struct #global_container_id {
-            #(#global_names : ::core::pin::Pin<sp::Rc<#global_types>>,)*
+        #vis struct #global_container_id {
+            #(#vis #global_names : ::core::pin::Pin<sp::Rc<#global_types>>,)*
         }
         impl::core::default::Default for #global_container_id {
             fn default() -> Self {
@@ -444,7 +527,7 @@ fn handle_property_init(
                 (#tokens_for_expression) #maybe_cast_to_property_type
             });
 
-            if binding_expression.is_state_info {
+            let set_binding = if binding_expression.is_state_info {
                 quote! { {
                     slint::private_unstable_api::set_property_state_binding(#rust_property, &self_rc, #binding_tokens);
                 } }
@@ -474,6 +557,12 @@ fn handle_property_init(
                         } }
                     }
                 }
+            };
+            let debug_name = llr::pretty_print::binding_debug_name(prop, ctx);
+            quote! {
+                #set_binding
+                #[cfg(debug_assertions)]
+                #rust_property.set_binding_debug_name(#debug_name);
             }
         });
     }
@@ -594,6 +683,7 @@ fn generate_sub_component(
     extra_fields: TokenStream,
     index_property: Option<llr::PropertyIndex>,
     pinned_drop: bool,
+    vis: &TokenStream,
 ) -> TokenStream {
     let inner_component_id = inner_component_id(component);
 
@@ -606,7 +696,7 @@ fn generate_sub_component(
     let mut extra_components = component
         .popup_windows
         .iter()
-        .map(|c| generate_item_tree(c, root, Some(ParentCtx::new(&ctx, None)), quote!(), None))
+        .map(|c| generate_item_tree(c, root, Some(ParentCtx::new(&ctx, None)), quote!(), None, vis))
         .collect::<Vec<_>>();
 
     let mut declared_property_vars = vec![];
@@ -681,6 +771,7 @@ fn generate_sub_component(
             repeated,
             root,
             ParentCtx::new(&ctx, Some(idx)),
+            vis,
         ));
         let repeater_id = format_ident!("repeater{}", idx);
         let rep_inner_component_id = self::inner_component_id(&repeated.sub_tree.root);
@@ -706,11 +797,16 @@ fn generate_sub_component(
             let lv_h = access_member(&listview.listview_height, &ctx);
             let vp_w = access_member(&listview.viewport_width, &ctx);
             let lv_w = access_member(&listview.listview_width, &ctx);
+            let scroll_to_row = access_member(&listview.scroll_to_row, &ctx);
 
             quote! {
+                let scroll_to_row = #scroll_to_row.get();
+                if scroll_to_row >= 0 {
+                    #scroll_to_row.set(-1);
+                }
                 #inner_component_id::FIELD_OFFSETS.#repeater_id.apply_pin(_self).ensure_updated_listview(
                     || { #rep_inner_component_id::new(_self.self_weak.get().unwrap().clone()).unwrap().into() },
-                    #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h
+                    #vp_w, #vp_h, #vp_y, #lv_w.get(), #lv_h, scroll_to_row
                 );
             }
         } else {
@@ -937,8 +1033,11 @@ fn generate_sub_component(
     let layout_info_v = compile_expression(&component.layout_info_v.borrow(), &ctx);
 
     // FIXME! this is only public because of the ComponentHandle::Inner. we should find another way
-    let visibility =
-        core::ptr::eq(&root.item_tree.root as *const _, component as *const _).then(|| quote!(pub));
+    let visibility = if core::ptr::eq(&root.item_tree.root as *const _, component as *const _) {
+        quote!(pub)
+    } else {
+        vis.clone()
+    };
 
     let subtree_index_function = if let Some(property_index) = index_property {
         let prop = access_member(
@@ -959,16 +1058,16 @@ fn generate_sub_component(
         #pin_macro
         #visibility
         struct #inner_component_id {
-            #(#item_names : sp::#item_types,)*
-            #(#sub_component_names : #sub_component_types,)*
-            #(#declared_property_vars : sp::Property<#declared_property_types>,)*
-            #(#declared_callbacks : sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
-            #(#repeated_element_names : sp::Repeater<#repeated_element_components>,)*
-            self_weak : sp::OnceCell<sp::VWeakMapped<sp::ItemTreeVTable, #inner_component_id>>,
-            #(parent : #parent_component_type,)*
-            root : sp::OnceCell<sp::VWeak<sp::ItemTreeVTable, #root_component_id>>,
-            tree_index: ::core::cell::Cell<u32>,
-            tree_index_of_first_child: ::core::cell::Cell<u32>,
+            #(#vis #item_names : sp::#item_types,)*
+            #(#vis #sub_component_names : #sub_component_types,)*
+            #(#vis #declared_property_vars : sp::Property<#declared_property_types>,)*
+            #(#vis #declared_callbacks : sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
+            #(#vis #repeated_element_names : sp::Repeater<#repeated_element_components>,)*
+            #vis self_weak : sp::OnceCell<sp::VWeakMapped<sp::ItemTreeVTable, #inner_component_id>>,
+            #(#vis parent : #parent_component_type,)*
+            #vis root : sp::OnceCell<sp::VWeak<sp::ItemTreeVTable, #root_component_id>>,
+            #vis tree_index: ::core::cell::Cell<u32>,
+            #vis tree_index_of_first_child: ::core::cell::Cell<u32>,
             #extra_fields
         }
 
@@ -990,7 +1089,7 @@ pub fn user_init(self_rc: sp::VRcMapped<sp::ItemTreeVTable, Self>) {
                 #(#user_init_code)*
             }
 
-            fn visit_dynamic_children(
+            #vis fn visit_dynamic_children(
                 self: ::core::pin::Pin<&Self>,
                 dyn_index: u32,
                 order: sp::TraversalOrder,
@@ -1004,7 +1103,7 @@ fn visit_dynamic_children(
                 }
             }
 
-            fn layout_info(self: ::core::pin::Pin<&Self>, orientation: sp::Orientation) -> sp::LayoutInfo {
+            #vis fn layout_info(self: ::core::pin::Pin<&Self>, orientation: sp::Orientation) -> sp::LayoutInfo {
                 #![allow(unused)]
                 let _self = self;
                 match orientation {
@@ -1013,7 +1112,7 @@ fn layout_info(self: ::core::pin::Pin<&Self>, orientation: sp::Orientation) -> s
                 }
             }
 
-            fn subtree_range(self: ::core::pin::Pin<&Self>, dyn_index: u32) -> sp::IndexRange {
+            #vis fn subtree_range(self: ::core::pin::Pin<&Self>, dyn_index: u32) -> sp::IndexRange {
                 #![allow(unused)]
                 let _self = self;
                 match dyn_index {
@@ -1022,7 +1121,7 @@ fn subtree_range(self: ::core::pin::Pin<&Self>, dyn_index: u32) -> sp::IndexRang
                 }
             }
 
-            fn subtree_component(self: ::core::pin::Pin<&Self>, dyn_index: u32, subtree_index: usize, result: &mut sp::ItemTreeWeak) {
+            #vis fn subtree_component(self: ::core::pin::Pin<&Self>, dyn_index: u32, subtree_index: usize, result: &mut sp::ItemTreeWeak) {
                 #![allow(unused)]
                 let _self = self;
                 match dyn_index {
@@ -1031,13 +1130,13 @@ fn subtree_component(self: ::core::pin::Pin<&Self>, dyn_index: u32, subtree_inde
                 };
             }
 
-            fn index_property(self: ::core::pin::Pin<&Self>) -> usize {
+            #vis fn index_property(self: ::core::pin::Pin<&Self>) -> usize {
                 #![allow(unused)]
                 let _self = self;
                 #subtree_index_function
             }
 
-            fn item_geometry(self: ::core::pin::Pin<&Self>, index: u32) -> sp::LogicalRect {
+            #vis fn item_geometry(self: ::core::pin::Pin<&Self>, index: u32) -> sp::LogicalRect {
                 #![allow(unused)]
                 let _self = self;
                 // The result of the expression is an anonymous struct, `{height: length, width: length, x: length, y: length}`
@@ -1049,7 +1148,7 @@ fn item_geometry(self: ::core::pin::Pin<&Self>, index: u32) -> sp::LogicalRect {
                 sp::euclid::rect(x, y, w, h)
             }
 
-            fn accessible_role(self: ::core::pin::Pin<&Self>, index: u32) -> sp::AccessibleRole {
+            #vis fn accessible_role(self: ::core::pin::Pin<&Self>, index: u32) -> sp::AccessibleRole {
                 #![allow(unused)]
                 let _self = self;
                 match index {
@@ -1059,7 +1158,7 @@ fn accessible_role(self: ::core::pin::Pin<&Self>, index: u32) -> sp::AccessibleR
                 }
             }
 
-            fn accessible_string_property(
+            #vis fn accessible_string_property(
                 self: ::core::pin::Pin<&Self>,
                 index: u32,
                 what: sp::AccessibleStringProperty,
@@ -1114,7 +1213,11 @@ pub fn #fn_id(self: ::core::pin::Pin<&Self>, #(#args_name : #args_ty,)*) -> #ret
         .collect()
 }
 
-fn generate_global(global: &llr::GlobalComponent, root: &llr::PublicComponent) -> TokenStream {
+fn generate_global(
+    global: &llr::GlobalComponent,
+    root: &llr::PublicComponent,
+    vis: &TokenStream,
+) -> TokenStream {
     let mut declared_property_vars = vec![];
     let mut declared_property_types = vec![];
     let mut declared_callbacks = vec![];
@@ -1216,17 +1319,17 @@ fn get(component: &'a #root_component_id) -> Self {
         #[const_field_offset(sp::const_field_offset)]
         #[repr(C)]
         #[pin]
-        struct #inner_component_id {
-            #(#declared_property_vars: sp::Property<#declared_property_types>,)*
-            #(#declared_callbacks: sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
+        #vis struct #inner_component_id {
+            #(#vis #declared_property_vars: sp::Property<#declared_property_types>,)*
+            #(#vis #declared_callbacks: sp::Callback<(#(#declared_callbacks_types,)*), #declared_callbacks_ret>,)*
             root : sp::OnceCell<sp::VWeak<sp::ItemTreeVTable, #root_component_id>>,
         }
 
         impl #inner_component_id {
-            fn new() -> ::core::pin::Pin<sp::Rc<Self>> {
+            #vis fn new() -> ::core::pin::Pin<sp::Rc<Self>> {
                 sp::Rc::pin(Self::default())
             }
-            fn init(self: ::core::pin::Pin<sp::Rc<Self>>, root: &sp::VRc<sp::ItemTreeVTable, #root_component_id>) {
+            #vis fn init(self: ::core::pin::Pin<sp::Rc<Self>>, root: &sp::VRc<sp::ItemTreeVTable, #root_component_id>) {
                 #![allow(unused)]
                 self.root.set(sp::VRc::downgrade(root));
                 let self_rc = self;
@@ -1247,6 +1350,7 @@ fn generate_item_tree(
     parent_ctx: Option<ParentCtx>,
     extra_fields: TokenStream,
     index_property: Option<llr::PropertyIndex>,
+    vis: &TokenStream,
 ) -> TokenStream {
     let sub_comp = generate_sub_component(
         &sub_tree.root,
@@ -1255,6 +1359,7 @@ fn generate_item_tree(
         extra_fields,
         index_property,
         true,
+        vis,
     );
     let inner_component_id = self::inner_component_id(&sub_tree.root);
     let parent_component_type = parent_ctx
@@ -1275,12 +1380,14 @@ fn generate_item_tree(
         (
             quote!(
                 #[allow(unused)]
-                fn window_adapter_impl(&self) -> sp::Rc<dyn sp::WindowAdapter> {
+                #vis fn window_adapter_impl(&self) -> sp::Rc<dyn sp::WindowAdapter> {
                     self.root.get().unwrap().upgrade().unwrap().window_adapter_impl()
                 }
 
                 #[allow(unused)]
-                fn maybe_window_adapter_impl(&self) -> sp::Option<sp::Rc<dyn sp::WindowAdapter>> {
+                #vis fn maybe_window_adapter_impl(
+                    &self,
+                ) -> sp::Option<sp::Rc<dyn sp::WindowAdapter>> {
                     self.root
                         .get()
                         .and_then(|root_weak| root_weak.upgrade())
@@ -1301,11 +1408,11 @@ fn maybe_window_adapter_impl(&self) -> sp::Option<sp::Rc<dyn sp::WindowAdapter>>
         (
             quote!(
                 #[allow(unused)]
-                fn window_adapter_impl(&self) -> sp::Rc<dyn sp::WindowAdapter> {
+                #vis fn window_adapter_impl(&self) -> sp::Rc<dyn sp::WindowAdapter> {
                     sp::Rc::clone(self.window_adapter_ref().unwrap())
                 }
 
-                fn window_adapter_ref(
+                #vis fn window_adapter_ref(
                     &self,
                 ) -> sp::Result<&sp::Rc<dyn sp::WindowAdapter>, slint::PlatformError>
                 {
@@ -1320,7 +1427,9 @@ fn window_adapter_ref(
                 }
 
                 #[allow(unused)]
-                fn maybe_window_adapter_impl(&self) -> sp::Option<sp::Rc<dyn sp::WindowAdapter>> {
+                #vis fn maybe_window_adapter_impl(
+                    &self,
+                ) -> sp::Option<sp::Rc<dyn sp::WindowAdapter>> {
                     self.window_adapter_.get().cloned()
                 }
             ),
@@ -1533,6 +1642,7 @@ fn generate_repeated_component(
     repeated: &llr::RepeatedElement,
     root: &llr::PublicComponent,
     parent_ctx: ParentCtx,
+    vis: &TokenStream,
 ) -> TokenStream {
     let component = generate_item_tree(
         &repeated.sub_tree,
@@ -1540,6 +1650,7 @@ fn generate_repeated_component(
         Some(parent_ctx),
         quote!(),
         repeated.index_prop,
+        vis,
     );
 
     let ctx = EvaluationContext {
@@ -1575,6 +1686,10 @@ fn listview_layout(
                     viewport_width.set(w);
                 }
             }
+            fn listview_item_height(self: core::pin::Pin<&Self>) -> sp::LogicalLength {
+                let _self = self;
+                #p_height.get()
+            }
         }
     } else {
         // TODO: we could generate this code only if we know that this component is in a box layout
@@ -2219,6 +2334,9 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
         Expression::EasingCurve(EasingCurve::EaseInOutBounce) => {
             quote!(sp::EasingCurve::EaseInOutBounce)
         }
+        Expression::EasingCurve(EasingCurve::Spring(stiffness, damping, mass)) => {
+            quote!(sp::EasingCurve::Spring([#stiffness, #damping, #mass]))
+        }
         Expression::LinearGradient { angle, stops } => {
             let angle = compile_expression(angle, ctx);
             let stops = stops.iter().map(|(color, stop)| {
@@ -2307,6 +2425,44 @@ fn compile_expression(expr: &Expression, ctx: &EvaluationContext) -> TokenStream
                 }
             }
         }
+        Expression::ArrayMap { array, variable, expression, filter } => {
+            let array = compile_expression(array, ctx);
+            let variable = ident(variable);
+            let ty = expression.ty(ctx);
+            let expression = compile_expression(expression, ctx);
+            if *filter {
+                quote!(match &#array { x => {
+                    x.model_tracker().track_row_count_changes();
+                    sp::ModelRc::new(sp::VecModel::from(
+                        (0..x.row_count())
+                            .filter_map(|i| x.row_data_tracked(i))
+                            .filter(|#variable| #expression)
+                            .collect::<sp::Vec<_>>()
+                    ))
+                }})
+            } else {
+                let expression = match rust_primitive_type(&ty) {
+                    Some(t)
+                        if ty.default_unit().is_some()
+                            || matches!(ty, Type::Int32 | Type::Float32 | Type::Percent) =>
+                    {
+                        quote!((#expression) as #t)
+                    }
+                    _ => expression,
+                };
+                quote!(match &#array { x => {
+                    x.model_tracker().track_row_count_changes();
+                    sp::ModelRc::new(sp::VecModel::from(
+                        (0..x.row_count())
+                            .map(|i| {
+                                let #variable = x.row_data_tracked(i).unwrap_or_default();
+                                #expression
+                            })
+                            .collect::<sp::Vec<_>>()
+                    ))
+                }})
+            }
+        }
     }
 }
 
@@ -2329,7 +2485,7 @@ fn compile_builtin_function_call(
             }
         }
         BuiltinFunction::ShowPopupWindow => {
-            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, Expression::PropertyReference(parent_ref)] =
+            if let [Expression::NumberLiteral(popup_index), x, y, close_on_click, modal, Expression::PropertyReference(parent_ref)] =
                 arguments
             {
                 let mut parent_ctx = ctx;
@@ -2349,6 +2505,7 @@ fn compile_builtin_function_call(
                 let x = compile_expression(x, ctx);
                 let y = compile_expression(y, ctx);
                 let close_on_click = compile_expression(close_on_click, ctx);
+                let modal = compile_expression(modal, ctx);
                 let window_adapter_tokens = access_window_adapter_field(ctx);
                 quote!(
                     sp::WindowInner::from_pub(#window_adapter_tokens.window()).show_popup(
@@ -2359,6 +2516,7 @@ fn compile_builtin_function_call(
                         }),
                         sp::Point::new(#x as sp::Coord, #y as sp::Coord),
                         #close_on_click,
+                        #modal,
                         #parent_component
                     )
                 )
@@ -2372,6 +2530,60 @@ fn compile_builtin_function_call(
                 sp::WindowInner::from_pub(#window_adapter_tokens.window()).close_popup()
             )
         }
+        BuiltinFunction::ShowChildWindow => {
+            if let [Expression::NumberLiteral(popup_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let mut parent_ctx = ctx;
+                let mut component_access_tokens = quote!(_self);
+                if let llr::PropertyReference::InParent { level, .. } = parent_ref {
+                    for _ in 0..level.get() {
+                        component_access_tokens =
+                            quote!(#component_access_tokens.parent.upgrade().unwrap().as_pin_ref());
+                        parent_ctx = parent_ctx.parent.as_ref().unwrap().ctx;
+                    }
+                }
+                let current_sub_component = parent_ctx.current_sub_component.unwrap();
+                let child_window_id = inner_component_id(
+                    &current_sub_component.popup_windows[*popup_index as usize].root,
+                );
+                let parent_component = access_item_rc(parent_ref, ctx);
+                let popup_index = *popup_index as usize;
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                quote!({
+                    let window = sp::WindowInner::from_pub(#window_adapter_tokens.window());
+                    let parent_item = #parent_component;
+                    if !window.is_child_window_shown(#popup_index, parent_item) {
+                        window.show_child_window(
+                            #popup_index,
+                            &sp::VRc::into_dyn({
+                                let instance = #child_window_id::new(#component_access_tokens.self_weak.get().unwrap().clone()).unwrap();
+                                #child_window_id::user_init(sp::VRc::map(instance.clone(), |x| x));
+                                instance.into()
+                            }),
+                            parent_item,
+                        )
+                    }
+                })
+            } else {
+                panic!("internal error: invalid args to ShowChildWindow {:?}", arguments)
+            }
+        }
+        BuiltinFunction::CloseChildWindow => {
+            if let [Expression::NumberLiteral(popup_index), Expression::PropertyReference(parent_ref)] =
+                arguments
+            {
+                let parent_component = access_item_rc(parent_ref, ctx);
+                let popup_index = *popup_index as usize;
+                let window_adapter_tokens = access_window_adapter_field(ctx);
+                quote!(
+                    sp::WindowInner::from_pub(#window_adapter_tokens.window())
+                        .close_child_window(#popup_index, #parent_component)
+                )
+            } else {
+                panic!("internal error: invalid args to CloseChildWindow {:?}", arguments)
+            }
+        }
         BuiltinFunction::SetSelectionOffsets => {
             if let [llr::Expression::PropertyReference(pr), from, to] = arguments {
                 let item = access_member(pr, ctx);
@@ -2453,7 +2665,15 @@ fn compile_builtin_function_call(
         BuiltinFunction::AnimationTick => {
             quote!(sp::animation_tick())
         }
+        BuiltinFunction::CurrentTick => {
+            quote!(sp::current_tick().0)
+        }
+        BuiltinFunction::ApplyEasingCurve => {
+            let (curve, value) = (a.next().unwrap(), a.next().unwrap());
+            quote!(sp::easing_curve(&#curve, #value as f32))
+        }
         BuiltinFunction::Debug => quote!(slint::private_unstable_api::debug(#(#a)*)),
+        BuiltinFunction::AssertionFailed => quote!(sp::assertion_failed(&#(#a)*)),
         BuiltinFunction::Mod => quote!((#(#a as f64)%*)),
         BuiltinFunction::Round => quote!((#(#a)* as f64).round()),
         BuiltinFunction::Ceil => quote!((#(#a)* as f64).ceil()),
@@ -2478,6 +2698,57 @@ fn compile_builtin_function_call(
             quote!(#(#a)*.as_str().parse::<f64>().unwrap_or_default())
         }
         BuiltinFunction::StringIsFloat => quote!(#(#a)*.as_str().parse::<f64>().is_ok()),
+        BuiltinFunction::StringToUppercase => {
+            quote!(sp::SharedString::from(#(#a)*.as_str().to_uppercase()))
+        }
+        BuiltinFunction::StringToLowercase => {
+            quote!(sp::SharedString::from(#(#a)*.as_str().to_lowercase()))
+        }
+        BuiltinFunction::StringTrim => quote!(sp::SharedString::from(#(#a)*.as_str().trim())),
+        BuiltinFunction::StringContains => {
+            let (s, pattern) = (a.next().unwrap(), a.next().unwrap());
+            quote!(match (#s, #pattern) { (s, pattern) => s.as_str().contains(pattern.as_str()) })
+        }
+        BuiltinFunction::StringSplit => {
+            let (s, separator) = (a.next().unwrap(), a.next().unwrap());
+            quote!(match (#s, #separator) { (s, separator) => {
+                let parts: sp::Vec<sp::SharedString> = if separator.is_empty() {
+                    sp::vec![s.clone()]
+                } else {
+                    s.as_str().split(separator.as_str()).map(sp::SharedString::from).collect()
+                };
+                sp::ModelRc::new(sp::VecModel::from(parts))
+            }})
+        }
+        BuiltinFunction::StringReplace => {
+            let (s, from, to) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(match (#s, #from, #to) { (s, from, to) => {
+                if from.is_empty() {
+                    s
+                } else {
+                    sp::SharedString::from(s.as_str().replace(from.as_str(), to.as_str()))
+                }
+            }})
+        }
+        BuiltinFunction::StringCharacterCount => {
+            quote!(#(#a)*.as_str().chars().count() as i32)
+        }
+        BuiltinFunction::FormatNumber
+        | BuiltinFunction::FormatPercent
+        | BuiltinFunction::FormatByteSize => {
+            let (value, decimals) = (a.next().unwrap(), a.next().unwrap());
+            let f = match function {
+                BuiltinFunction::FormatNumber => quote!(format_number),
+                BuiltinFunction::FormatPercent => quote!(format_percent),
+                _ => quote!(format_byte_size),
+            };
+            quote!(sp::#f(#value as f64, #decimals as i32))
+        }
+        BuiltinFunction::FormatDuration => quote!(sp::format_duration(#(#a)* as i64)),
+        BuiltinFunction::FormatDate => {
+            let (year, month, day) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(sp::format_date(#year as i32, #month as i32, #day as i32))
+        }
         BuiltinFunction::ColorBrighter => {
             let x = a.next().unwrap();
             let factor = a.next().unwrap();
@@ -2511,6 +2782,64 @@ fn compile_builtin_function_call(
                 x.row_count() as i32
             }})
         }
+        BuiltinFunction::ArraySum => {
+            let ty =
+                rust_primitive_type(&function.array_return_type(arguments[0].ty(ctx))).unwrap();
+            quote!(match &#(#a)* { x => {
+                x.model_tracker().track_row_count_changes();
+                (0..x.row_count()).fold(<#ty as ::core::default::Default>::default(), |acc, i| {
+                    acc + x.row_data_tracked(i).unwrap_or_default()
+                })
+            }})
+        }
+        BuiltinFunction::ArrayContains | BuiltinFunction::ArrayIndexOf => {
+            let (array, value) = (a.next().unwrap(), a.next().unwrap());
+            let value = match arguments[0].ty(ctx) {
+                Type::Array(ty)
+                    if ty.default_unit().is_some()
+                        || matches!(*ty, Type::Int32 | Type::Float32 | Type::Percent) =>
+                {
+                    let ty = rust_primitive_type(&ty).unwrap();
+                    quote!((#value) as #ty)
+                }
+                _ => value,
+            };
+            let position = quote!(match (&#array, #value) { (x, value) => {
+                x.model_tracker().track_row_count_changes();
+                (0..x.row_count()).position(|i| x.row_data_tracked(i).map_or(false, |v| v == value))
+            }});
+            if function == BuiltinFunction::ArrayContains {
+                quote!(#position.is_some())
+            } else {
+                quote!(#position.map_or(-1, |i| i as i32))
+            }
+        }
+        BuiltinFunction::ArraySlice => {
+            let (array, start, end) = (a.next().unwrap(), a.next().unwrap(), a.next().unwrap());
+            quote!(match (&#array, (#start) as i32, (#end) as i32) { (x, start, end) => {
+                x.model_tracker().track_row_count_changes();
+                let len = x.row_count();
+                let start = (start.max(0) as usize).min(len);
+                let end = (end.max(0) as usize).min(len).max(start);
+                sp::ModelRc::new(sp::VecModel::from(
+                    (start..end).map(|i| x.row_data_tracked(i).unwrap_or_default()).collect::<sp::Vec<_>>()
+                ))
+            }})
+        }
+        BuiltinFunction::ArrayJoin => {
+            let (array, separator) = (a.next().unwrap(), a.next().unwrap());
+            quote!(match (&#array, #separator) { (x, separator) => {
+                x.model_tracker().track_row_count_changes();
+                let mut result = sp::SharedString::default();
+                for i in 0..x.row_count() {
+                    if i > 0 {
+                        result.push_str(separator.as_str());
+                    }
+                    result.push_str(x.row_data_tracked(i).unwrap_or_default().as_str());
+                }
+                result
+            }})
+        }
 
         BuiltinFunction::Rgb => {
             let (r, g, b, a) =
@@ -2764,7 +3093,7 @@ fn generate_named_exports(doc: &Document) -> Vec<TokenStream> {
     doc.exports
         .iter()
         .filter_map(|export| match &export.1 {
-            Either::Left(component) if !component.is_global() => {
+            Either::Left(component) if !component.is_global() && !component.is_interface => {
                 Some((&export.0.name, &component.id))
             }
             Either::Right(ty) => match &ty {
@@ -2784,3 +3113,103 @@ fn generate_named_exports(doc: &Document) -> Vec<TokenStream> {
         })
         .collect::<Vec<_>>()
 }
+
+#[test]
+fn split_modules() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Rust);
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+export global Settings {
+    in-out property <string> caption;
+}
+export component App {
+    Text { text: Settings.caption; }
+}
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        None,
+        &mut test_diags,
+    );
+    let (doc, diag) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    assert!(!diag.has_error());
+
+    // Everything is in the same module, nothing needs to be visible outside of it
+    let single = generate(&doc).to_string();
+    assert!(!single.contains("pub (super)"));
+
+    let mut modules = Vec::new();
+    let main = generate_with_modules(&doc, |name, source_path, code| {
+        assert_eq!(source_path, Some(Path::new("HELLO")));
+        modules.push((name.to_string(), code.to_string()));
+        format!("/out/{name}.rs")
+    })
+    .to_string();
+    let names = modules.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>();
+    assert_eq!(names.first(), Some(&"global_Settings"));
+    assert_eq!(names.last(), Some(&"component_App"));
+    for (name, code) in &modules {
+        assert!(main.contains(&format!("mod {name} {{")), "{name} not declared in {main}");
+        assert!(main.contains(&format!("include ! (\"/out/{name}.rs\")")));
+        assert!(code.contains("pub (super)"), "{name} has no item visible to the other modules");
+    }
+    assert!(!main.contains("InnerSettings"), "the global is generated in the main module");
+}
+
+#[test]
+fn split_modules_are_independent() {
+    let generate_modules = |first: &str| {
+        let mut compiler_config =
+            crate::CompilerConfiguration::new(crate::generator::OutputFormat::Rust);
+        compiler_config.style = Some("fluent".into());
+        let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+        let doc_node = crate::parser::parse(
+            format!(
+                r#"
+component First {{
+    {first}
+}}
+component Second {{
+    in property <[int]> model;
+    txt := Text {{ text: "second"; }}
+    for x in model: Rectangle {{ width: x * 1px; }}
+}}
+export component App {{
+    First {{ }}
+    Second {{ }}
+}}
+"#
+            ),
+            Some(std::path::Path::new("HELLO")),
+            None,
+            &mut test_diags,
+        );
+        let (doc, diag) =
+            spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+        assert!(!diag.has_error());
+        let mut modules = std::collections::HashMap::new();
+        generate_with_modules(&doc, |name, _, code| {
+            modules.insert(name.to_string(), code.to_string());
+            format!("/out/{name}.rs")
+        });
+        modules
+    };
+    let before = generate_modules("Text { text: \"first\"; }");
+    let after = generate_modules(
+        "Rectangle { Text { text: \"first\"; } } for x in 3: Rectangle { y: x * 10px; }",
+    );
+    let module = |modules: &std::collections::HashMap<String, String>, component: &str| {
+        modules
+            .iter()
+            .find(|(name, _)| name.starts_with(&format!("component_{component}_")))
+            .map(|(_, code)| code.clone())
+            .unwrap_or_else(|| panic!("no module for {component} in {:?}", modules.keys()))
+    };
+    assert_ne!(module(&before, "First"), module(&after, "First"));
+    // The elements added to First don't change the code generated for Second
+    assert_eq!(module(&before, "Second"), module(&after, "Second"));
+}
@@ -92,6 +92,14 @@ pub struct CompilerConfiguration {
 
     /// The domain used as one of the parameter to the translate function
     pub translation_domain: Option<String>,
+
+    /// Emit warnings for the private properties, callbacks, functions, element ids, imports
+    /// and components that are never used
+    pub warn_unused: bool,
+
+    /// Generate the Rust code of each global and of each component in its own module, which
+    /// slint-build writes in its own file
+    pub split_modules: bool,
 }
 
 impl CompilerConfiguration {
@@ -152,6 +160,8 @@ pub fn new(output_format: crate::generator::OutputFormat) -> Self {
             accessibility: true,
             enable_component_containers,
             translation_domain: None,
+            warn_unused: false,
+            split_modules: false,
         }
     }
 }
@@ -209,10 +219,12 @@ pub async fn compile_syntax_node(
         passes::run_passes(&doc, &mut diagnostics, &mut loader, &compiler_config).await;
     } else {
         // Don't run all the passes in case of errors because because some invariants are not met.
-        passes::run_import_passes(&doc, &mut loader, &mut diagnostics);
+        passes::run_import_passes(&doc, &mut loader, true, &mut diagnostics);
     }
 
     diagnostics.all_loaded_files = loader.all_files().cloned().collect();
+    diagnostics.all_loaded_imports =
+        loader.all_imports().map(|(file, imports)| (file.clone(), imports.clone())).collect();
 
     (doc, diagnostics)
 }
@@ -185,6 +185,15 @@ pub enum Expression {
         lhs: Box<Expression>,
         rhs: Box<Expression>,
     },
+
+    /// `array.map(variable => expression)`, or `array.filter(variable => expression)` when
+    /// `filter` is true. The element is in the local variable `variable`.
+    ArrayMap {
+        array: Box<Expression>,
+        variable: String,
+        expression: Box<Expression>,
+        filter: bool,
+    },
 }
 
 impl Expression {
@@ -260,6 +269,11 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
             },
             Self::Cast { to, .. } => to.clone(),
             Self::CodeBlock(sub) => sub.last().map_or(Type::Void, |e| e.ty(ctx)),
+            Self::BuiltinFunctionCall { function, arguments }
+                if function.depends_on_array_type() =>
+            {
+                function.array_return_type(arguments[0].ty(ctx))
+            }
             Self::BuiltinFunctionCall { function, .. } => match function.ty() {
                 Type::Function { return_type, .. } => *return_type,
                 _ => unreachable!(),
@@ -298,6 +312,13 @@ pub fn ty(&self, ctx: &dyn TypeResolutionContext) -> Type {
                 Type::Array(super::lower_expression::grid_layout_cell_data_ty().into())
             }
             Self::MinMax { ty, .. } => ty.clone(),
+            Self::ArrayMap { array, expression, filter, .. } => {
+                if *filter {
+                    array.ty(ctx)
+                } else {
+                    Type::Array(Box::new(expression.ty(ctx)))
+                }
+            }
         }
     }
 }
@@ -379,6 +400,10 @@ macro_rules! visit_impl {
                 $visitor(lhs);
                 $visitor(rhs);
             }
+            Expression::ArrayMap { array, variable: _, expression, filter: _ } => {
+                $visitor(array);
+                $visitor(expression);
+            }
         }
     };
 }
@@ -119,6 +119,8 @@ pub struct ListViewInfo {
     pub listview_height: PropertyReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: PropertyReference,
+    /// The row to scroll to on the next layout, or -1
+    pub scroll_to_row: PropertyReference,
 
     // In the repeated component context
     pub prop_y: PropertyReference,
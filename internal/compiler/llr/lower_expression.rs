@@ -123,6 +123,10 @@ pub fn lower_expression(
                     arguments: vec![],
                 }
             }
+            tree_Expression::BuiltinFunctionReference(
+                f @ (BuiltinFunction::ShowChildWindow | BuiltinFunction::CloseChildWindow),
+                _,
+            ) => lower_child_window_function(f.clone(), arguments, ctx),
             tree_Expression::BuiltinFunctionReference(f, _) => {
                 let mut arguments =
                     arguments.iter().map(|e| lower_expression(e, ctx)).collect::<Vec<_>>();
@@ -210,6 +214,14 @@ pub fn lower_expression(
             lhs: Box::new(lower_expression(lhs, ctx)),
             rhs: Box::new(lower_expression(rhs, ctx)),
         },
+        tree_Expression::ArrayMap { array, variable, expression, filter } => {
+            llr_Expression::ArrayMap {
+                array: Box::new(lower_expression(array, ctx)),
+                variable: variable.clone(),
+                expression: Box::new(lower_expression(expression, ctx)),
+                filter: *filter,
+            }
+        }
     }
 }
 
@@ -379,6 +391,7 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
                 x,
                 y,
                 llr_Expression::BoolLiteral(popup.close_on_click),
+                llr_Expression::BoolLiteral(popup.modal),
                 item_ref,
             ],
         }
@@ -387,6 +400,41 @@ fn lower_show_popup(args: &[tree_Expression], ctx: &ExpressionContext) -> llr_Ex
     }
 }
 
+fn lower_child_window_function(
+    function: BuiltinFunction,
+    args: &[tree_Expression],
+    ctx: &ExpressionContext,
+) -> llr_Expression {
+    if let [tree_Expression::ElementReference(e)] = args {
+        let child_window = e.upgrade().unwrap();
+        let child_comp = child_window.borrow().enclosing_component.upgrade().unwrap();
+        let parent_component = child_comp
+            .parent_element
+            .upgrade()
+            .unwrap()
+            .borrow()
+            .enclosing_component
+            .upgrade()
+            .unwrap();
+        let popup_list = parent_component.popup_windows.borrow();
+        let (popup_index, popup) = popup_list
+            .iter()
+            .enumerate()
+            .find(|(_, p)| Rc::ptr_eq(&p.component, &child_comp))
+            .unwrap();
+        let item_ref = lower_expression(
+            &tree_Expression::ElementReference(Rc::downgrade(&popup.parent_element)),
+            ctx,
+        );
+        llr_Expression::BuiltinFunctionCall {
+            function,
+            arguments: vec![llr_Expression::NumberLiteral(popup_index as _), item_ref],
+        }
+    } else {
+        panic!("invalid arguments to {function:?}");
+    }
+}
+
 pub fn lower_animation(a: &PropertyAnimation, ctx: &ExpressionContext<'_>) -> Animation {
     fn lower_animation_element(a: &ElementRc, ctx: &ExpressionContext<'_>) -> llr_Expression {
         llr_Expression::Struct {
@@ -537,15 +537,23 @@ fn lower_repeated_component(elem: &ElementRc, ctx: &ExpressionContext) -> Repeat
 
     let geom = component.root_element.borrow().geometry_props.clone().unwrap();
 
-    let listview = repeated.is_listview.as_ref().map(|lv| ListViewInfo {
-        viewport_y: ctx.map_property_reference(&lv.viewport_y),
-        viewport_height: ctx.map_property_reference(&lv.viewport_height),
-        viewport_width: ctx.map_property_reference(&lv.viewport_width),
-        listview_height: ctx.map_property_reference(&lv.listview_height),
-        listview_width: ctx.map_property_reference(&lv.listview_width),
-        prop_y: sc.mapping.map_property_reference(&geom.y, ctx.state),
-        prop_width: sc.mapping.map_property_reference(&geom.width, ctx.state),
-        prop_height: sc.mapping.map_property_reference(&geom.height, ctx.state),
+    let listview = repeated.is_listview.as_ref().map(|lv| {
+        let (y, width, height) = if lv.horizontal {
+            (&geom.x, &geom.height, &geom.width)
+        } else {
+            (&geom.y, &geom.width, &geom.height)
+        };
+        ListViewInfo {
+            viewport_y: ctx.map_property_reference(&lv.viewport_y),
+            viewport_height: ctx.map_property_reference(&lv.viewport_height),
+            viewport_width: ctx.map_property_reference(&lv.viewport_width),
+            listview_height: ctx.map_property_reference(&lv.listview_height),
+            listview_width: ctx.map_property_reference(&lv.listview_width),
+            scroll_to_row: ctx.map_property_reference(&lv.scroll_to_row),
+            prop_y: sc.mapping.map_property_reference(y, ctx.state),
+            prop_width: sc.mapping.map_property_reference(width, ctx.state),
+            prop_height: sc.mapping.map_property_reference(height, ctx.state),
+        }
     });
 
     RepeatedElement {
@@ -70,6 +70,7 @@ pub fn count_property_use(root: &PublicComponent) {
                 visit_property(&lv.viewport_height, ctx);
                 visit_property(&lv.listview_width, ctx);
                 visit_property(&lv.listview_height, ctx);
+                visit_property(&lv.scroll_to_row, ctx);
 
                 let rep_ctx = EvaluationContext::new_sub_component(
                     root,
@@ -54,6 +54,7 @@ fn expression_cost(exp: &Expression, ctx: &EvaluationContext) -> isize {
         Expression::BoxLayoutFunction { .. } => return isize::MAX,
         Expression::ComputeDialogLayoutCells { .. } => return isize::MAX,
         Expression::MinMax { .. } => 10,
+        Expression::ArrayMap { .. } => ALLOC_COST,
     };
 
     exp.visit(|e| cost = cost.saturating_add(expression_cost(e, ctx)));
@@ -71,7 +72,10 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::GetWindowScaleFactor => PROPERTY_ACCESS_COST,
         BuiltinFunction::GetWindowDefaultFontSize => PROPERTY_ACCESS_COST,
         BuiltinFunction::AnimationTick => PROPERTY_ACCESS_COST,
+        BuiltinFunction::CurrentTick => PROPERTY_ACCESS_COST,
+        BuiltinFunction::ApplyEasingCurve => 50,
         BuiltinFunction::Debug => isize::MAX,
+        BuiltinFunction::AssertionFailed => isize::MAX,
         BuiltinFunction::Mod => 10,
         BuiltinFunction::Round => 10,
         BuiltinFunction::Ceil => 10,
@@ -88,10 +92,23 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::Pow => 10,
         BuiltinFunction::SetFocusItem => isize::MAX,
         BuiltinFunction::ShowPopupWindow | BuiltinFunction::ClosePopupWindow => isize::MAX,
+        BuiltinFunction::ShowChildWindow | BuiltinFunction::CloseChildWindow => isize::MAX,
         BuiltinFunction::SetSelectionOffsets => isize::MAX,
         BuiltinFunction::ItemMemberFunction(..) => isize::MAX,
         BuiltinFunction::StringToFloat => 50,
         BuiltinFunction::StringIsFloat => 50,
+        BuiltinFunction::StringToUppercase => 50,
+        BuiltinFunction::StringToLowercase => 50,
+        BuiltinFunction::StringTrim => 50,
+        BuiltinFunction::StringContains => 50,
+        BuiltinFunction::StringSplit => 100,
+        BuiltinFunction::StringReplace => 100,
+        BuiltinFunction::StringCharacterCount => 50,
+        BuiltinFunction::FormatNumber => 100,
+        BuiltinFunction::FormatPercent => 100,
+        BuiltinFunction::FormatByteSize => 100,
+        BuiltinFunction::FormatDuration => 100,
+        BuiltinFunction::FormatDate => 100,
         BuiltinFunction::ColorBrighter => 50,
         BuiltinFunction::ColorDarker => 50,
         BuiltinFunction::ColorTransparentize => 50,
@@ -99,6 +116,11 @@ fn builtin_function_cost(function: &BuiltinFunction) -> isize {
         BuiltinFunction::ColorWithAlpha => 50,
         BuiltinFunction::ImageSize => 50,
         BuiltinFunction::ArrayLength => 50,
+        BuiltinFunction::ArraySum => 100,
+        BuiltinFunction::ArrayContains => 100,
+        BuiltinFunction::ArrayIndexOf => 100,
+        BuiltinFunction::ArraySlice => 100,
+        BuiltinFunction::ArrayJoin => 100,
         BuiltinFunction::Rgb => 50,
         BuiltinFunction::ImplicitLayoutInfo(_) => isize::MAX,
         BuiltinFunction::ItemAbsolutePosition => isize::MAX,
@@ -104,6 +104,17 @@ fn indent(&mut self) -> Result {
     }
 }
 
+/// The name of the property, as shown when a binding loop is detected at run-time
+pub fn binding_debug_name<T>(prop: &PropertyReference, ctx: &EvaluationContext<T>) -> String {
+    let name = DisplayPropertyRef(prop, ctx);
+    match ctx.current_sub_component {
+        Some(sc) if !matches!(prop, PropertyReference::Global { .. }) => {
+            format!("{}::{}", sc.name, name)
+        }
+        _ => name.to_string(),
+    }
+}
+
 pub struct DisplayPropertyRef<'a, T>(pub &'a PropertyReference, pub &'a EvaluationContext<'a, T>);
 impl<T> Display for DisplayPropertyRef<'_, T> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
@@ -259,6 +270,14 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result {
                 MinMaxOp::Min => write!(f, "min({}, {})", e(lhs), e(rhs)),
                 MinMaxOp::Max => write!(f, "max({}, {})", e(lhs), e(rhs)),
             },
+            Expression::ArrayMap { array, variable, expression, filter } => write!(
+                f,
+                "{}.{}({} => {})",
+                e(array),
+                if *filter { "filter" } else { "map" },
+                variable,
+                e(expression)
+            ),
         }
     }
 }
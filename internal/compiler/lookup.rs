@@ -34,6 +34,10 @@ pub struct LookupCtx<'a> {
     /// The name of the arguments of the callback or function
     pub arguments: Vec<String>,
 
+    /// The parameters of the enclosing lambdas, such as `item` in `array.map(item => ...)`.
+    /// (the last element is looked up first)
+    pub local_variables: Vec<(String, Type)>,
+
     /// The type register in which to look for Globals
     pub type_register: &'a TypeRegister,
 
@@ -54,6 +58,7 @@ pub fn empty_context(type_register: &'a TypeRegister, diag: &'a mut BuildDiagnos
             component_scope: Default::default(),
             diag,
             arguments: Default::default(),
+            local_variables: Default::default(),
             type_register,
             type_loader: None,
             current_token: None,
@@ -99,6 +104,7 @@ pub enum LookupResult {
 pub enum BuiltinNamespace {
     Colors,
     Math,
+    Format,
     Key,
     SlintInternal,
 }
@@ -162,6 +168,9 @@ fn for_each_entry<R>(
                 (ColorSpecific, ColorFunctions).for_each_entry(ctx, f)
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.for_each_entry(ctx, f),
+            LookupResult::Namespace(BuiltinNamespace::Format) => {
+                FormatFunctions.for_each_entry(ctx, f)
+            }
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.for_each_entry(ctx, f),
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.for_each_entry(ctx, f)
@@ -177,6 +186,7 @@ fn lookup(&self, ctx: &LookupCtx, name: &str) -> Option<LookupResult> {
                 (ColorSpecific, ColorFunctions).lookup(ctx, name)
             }
             LookupResult::Namespace(BuiltinNamespace::Math) => MathFunctions.lookup(ctx, name),
+            LookupResult::Namespace(BuiltinNamespace::Format) => FormatFunctions.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::Key) => KeysLookup.lookup(ctx, name),
             LookupResult::Namespace(BuiltinNamespace::SlintInternal) => {
                 SlintInternal.lookup(ctx, name)
@@ -207,6 +217,24 @@ fn for_each_entry<R>(
     }
 }
 
+struct LocalVariableLookup;
+impl LookupObject for LocalVariableLookup {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        for (name, ty) in ctx.local_variables.iter().rev() {
+            if let Some(r) =
+                f(name, Expression::ReadLocalVariable { name: name.clone(), ty: ty.clone() }.into())
+            {
+                return Some(r);
+            }
+        }
+        None
+    }
+}
+
 struct SpecialIdLookup;
 impl LookupObject for SpecialIdLookup {
     fn for_each_entry<R>(
@@ -719,6 +747,16 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "spring",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Spring,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
             .or_else(|| {
                 f("ease-in-elastic", Expression::EasingCurve(EasingCurve::EaseInElastic).into())
             })
@@ -796,6 +834,30 @@ fn for_each_entry<R>(
     }
 }
 
+struct FormatFunctions;
+impl LookupObject for FormatFunctions {
+    fn for_each_entry<R>(
+        &self,
+        ctx: &LookupCtx,
+        f: &mut impl FnMut(&str, LookupResult) -> Option<R>,
+    ) -> Option<R> {
+        use Expression::BuiltinFunctionReference;
+        let sl = || ctx.current_token.as_ref().map(|t| t.to_source_location());
+        let mut f = |n, e: Expression| f(n, e.into());
+        None.or_else(|| f("number", BuiltinFunctionReference(BuiltinFunction::FormatNumber, sl())))
+            .or_else(|| {
+                f("percent", BuiltinFunctionReference(BuiltinFunction::FormatPercent, sl()))
+            })
+            .or_else(|| {
+                f("byte-size", BuiltinFunctionReference(BuiltinFunction::FormatByteSize, sl()))
+            })
+            .or_else(|| {
+                f("duration", BuiltinFunctionReference(BuiltinFunction::FormatDuration, sl()))
+            })
+            .or_else(|| f("date", BuiltinFunctionReference(BuiltinFunction::FormatDate, sl())))
+    }
+}
+
 struct SlintInternal;
 impl LookupObject for SlintInternal {
     fn for_each_entry<R>(
@@ -853,6 +915,36 @@ fn for_each_entry<R>(
                     .into(),
                 )
             })
+            .or_else(|| {
+                f(
+                    "assert",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Assert,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "assert-eq",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::AssertEq,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
+            .or_else(|| {
+                f(
+                    "keyframes",
+                    Expression::BuiltinMacroReference(
+                        BuiltinMacroFunction::Keyframes,
+                        ctx.current_token.clone(),
+                    )
+                    .into(),
+                )
+            })
             .or_else(|| {
                 f(
                     "animation-tick",
@@ -875,6 +967,7 @@ fn for_each_entry<R>(
     ) -> Option<R> {
         None.or_else(|| f("Colors", LookupResult::Namespace(BuiltinNamespace::Colors)))
             .or_else(|| f("Math", LookupResult::Namespace(BuiltinNamespace::Math)))
+            .or_else(|| f("Format", LookupResult::Namespace(BuiltinNamespace::Format)))
             .or_else(|| f("Key", LookupResult::Namespace(BuiltinNamespace::Key)))
             .or_else(|| {
                 if ctx.type_register.expose_internal_types {
@@ -888,16 +981,22 @@ fn for_each_entry<R>(
 
 pub fn global_lookup() -> impl LookupObject {
     (
-        ArgumentsLookup,
+        LocalVariableLookup,
         (
-            SpecialIdLookup,
+            ArgumentsLookup,
             (
-                IdLookup,
+                SpecialIdLookup,
                 (
-                    InScopeLookup,
+                    IdLookup,
                     (
-                        LookupType,
-                        (BuiltinNamespaceLookup, (ReturnTypeSpecificLookup, BuiltinFunctionLookup)),
+                        InScopeLookup,
+                        (
+                            LookupType,
+                            (
+                                BuiltinNamespaceLookup,
+                                (ReturnTypeSpecificLookup, BuiltinFunctionLookup),
+                            ),
+                        ),
                     ),
                 ),
             ),
@@ -975,8 +1074,25 @@ fn for_each_entry<R>(
                 )),
             })
         };
+        let property = |f: BuiltinFunction| {
+            LookupResult::from(Expression::FunctionCall {
+                function: Box::new(Expression::BuiltinFunctionReference(
+                    f,
+                    ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                )),
+                source_location: ctx.current_token.as_ref().map(|t| t.to_source_location()),
+                arguments: vec![self.0.clone()],
+            })
+        };
         None.or_else(|| f("is-float", member_function(BuiltinFunction::StringIsFloat)))
             .or_else(|| f("to-float", member_function(BuiltinFunction::StringToFloat)))
+            .or_else(|| f("to-uppercase", member_function(BuiltinFunction::StringToUppercase)))
+            .or_else(|| f("to-lowercase", member_function(BuiltinFunction::StringToLowercase)))
+            .or_else(|| f("trim", member_function(BuiltinFunction::StringTrim)))
+            .or_else(|| f("contains", member_function(BuiltinFunction::StringContains)))
+            .or_else(|| f("split", member_function(BuiltinFunction::StringSplit)))
+            .or_else(|| f("replace", member_function(BuiltinFunction::StringReplace)))
+            .or_else(|| f("character-count", property(BuiltinFunction::StringCharacterCount)))
     }
 }
 struct ColorExpression<'a>(&'a Expression);
@@ -1046,6 +1162,20 @@ fn for_each_entry<R>(
                 arguments: vec![self.0.clone()],
             })
         };
+        let member_macro = |m: BuiltinMacroFunction| {
+            LookupResult::from(Expression::MemberFunction {
+                base: Box::new(self.0.clone()),
+                base_node: ctx.current_token.clone(), // Note that this is not the base_node, but the function's node
+                member: Box::new(Expression::BuiltinMacroReference(m, ctx.current_token.clone())),
+            })
+        };
         None.or_else(|| f("length", member_function(BuiltinFunction::ArrayLength)))
+            .or_else(|| f("sum", member_macro(BuiltinMacroFunction::ArraySum)))
+            .or_else(|| f("contains", member_macro(BuiltinMacroFunction::ArrayContains)))
+            .or_else(|| f("index-of", member_macro(BuiltinMacroFunction::ArrayIndexOf)))
+            .or_else(|| f("slice", member_macro(BuiltinMacroFunction::ArraySlice)))
+            .or_else(|| f("join", member_macro(BuiltinMacroFunction::ArrayJoin)))
+            .or_else(|| f("map", member_macro(BuiltinMacroFunction::ArrayMap)))
+            .or_else(|| f("filter", member_macro(BuiltinMacroFunction::ArrayFilter)))
     }
 }
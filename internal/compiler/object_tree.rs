@@ -168,7 +168,7 @@ pub fn from_node(
                     })
                     .and_then(|import| local_registry.lookup_element(&import.internal_name).ok())
                     .and_then(|c| match c {
-                        ElementType::Component(c) => Some(c),
+                        ElementType::Component(c) if !c.is_interface => Some(c),
                         _ => None,
                     })
             })
@@ -255,6 +255,7 @@ pub struct PopupWindow {
     pub x: NamedReference,
     pub y: NamedReference,
     pub close_on_click: bool,
+    pub modal: bool,
     pub parent_element: ElementRc,
 }
 
@@ -348,6 +349,14 @@ pub struct Component {
     /// This is the main entry point for the code generators. Such a component
     /// should have the full API, etc.
     pub is_root_component: Cell<bool>,
+
+    /// This component was declared with the `interface` keyword: it only lists the properties,
+    /// callbacks, and functions that components implementing it must declare, and it can't be
+    /// instantiated.
+    pub is_interface: bool,
+
+    /// The interfaces listed in the `implements` clause of this component.
+    pub implemented_interfaces: Vec<Rc<Component>>,
 }
 
 impl Component {
@@ -358,13 +367,52 @@ pub fn from_node(
     ) -> Rc<Self> {
         let mut child_insertion_point = None;
         let is_legacy_syntax = node.child_token(SyntaxKind::ColonEqual).is_some();
+        let keyword = node.child_text(SyntaxKind::Identifier);
+        let is_interface = keyword.as_ref().map_or(false, |t| t == "interface");
+        let implemented_interfaces = node
+            .ImplementsSpecifier()
+            .into_iter()
+            .flat_map(|s| s.QualifiedName())
+            .filter_map(|qualified_name| {
+                let name = QualifiedTypeName::from_node(qualified_name.clone()).to_string();
+                match tr.lookup_element(&name) {
+                    Ok(ElementType::Component(c)) if c.is_interface => Some(c),
+                    Ok(_) => {
+                        diag.push_error(format!("'{name}' is not an interface"), &qualified_name);
+                        None
+                    }
+                    Err(err) => {
+                        diag.push_error(err, &qualified_name);
+                        None
+                    }
+                }
+            })
+            .collect();
+        if is_interface {
+            check_interface_element(&node.Element(), diag);
+        } else {
+            let functions = node.Element().descendants().filter_map(|n| {
+                syntax_nodes::Function::new(SyntaxNode {
+                    node: n,
+                    source_file: node.source_file.clone(),
+                })
+            });
+            for func in functions {
+                if func.CodeBlock().is_none() {
+                    diag.push_error(
+                        "Only functions declared in an interface can omit the body".into(),
+                        &func.DeclaredIdentifier(),
+                    );
+                }
+            }
+        }
         let c = Component {
             node: Some(node.clone().into()),
             id: parser::identifier_text(&node.DeclaredIdentifier()).unwrap_or_default(),
             root_element: Element::from_node(
                 node.Element(),
                 "root".into(),
-                if node.child_text(SyntaxKind::Identifier).map_or(false, |t| t == "global") {
+                if keyword.map_or(false, |t| t == "global") {
                     ElementType::Global
                 } else {
                     ElementType::Error
@@ -375,8 +423,23 @@ pub fn from_node(
                 tr,
             ),
             child_insertion_point: RefCell::new(child_insertion_point),
+            is_interface,
+            implemented_interfaces,
             ..Default::default()
         };
+        if is_interface {
+            for (name, decl) in &c.root_element.borrow().property_declarations {
+                if matches!(
+                    decl.visibility,
+                    PropertyVisibility::Private | PropertyVisibility::Protected
+                ) {
+                    diag.push_error(
+                        format!("'{name}' must be public to be part of an interface"),
+                        &decl.node,
+                    );
+                }
+            }
+        }
         let c = Rc::new(c);
         let weak = Rc::downgrade(&c);
         recurse_elem(&c.root_element, &(), &mut |e, _| {
@@ -402,6 +465,36 @@ pub fn visible_in_public_api(&self) -> bool {
         }
     }
 
+    /// Returns the signatures of the public properties, callbacks, and functions of this component,
+    /// one per member, sorted.
+    ///
+    /// A `ComponentContainer` whose `interface` is set checks that the component factory declares
+    /// every member of the signature of the interface. An `in-out` property is also listed as an `in`
+    /// and as an `out` property, because it implements both.
+    pub fn public_api_signature(&self) -> Vec<String> {
+        let mut signature = Vec::new();
+        for (name, decl) in &self.root_element.borrow().property_declarations {
+            let ty = &decl.property_type;
+            match (ty, decl.visibility) {
+                (Type::Callback { .. }, _)
+                | (Type::Function { .. }, PropertyVisibility::Public) => {
+                    signature.push(format!("{name}: {ty}"))
+                }
+                (Type::Function { .. }, _) => (),
+                (_, PropertyVisibility::Input) => signature.push(format!("in {name}: {ty}")),
+                (_, PropertyVisibility::Output) => signature.push(format!("out {name}: {ty}")),
+                (_, PropertyVisibility::InOut) => {
+                    for visibility in ["in", "out", "in-out"] {
+                        signature.push(format!("{visibility} {name}: {ty}"));
+                    }
+                }
+                _ => (),
+            }
+        }
+        signature.sort();
+        signature
+    }
+
     /// Returns the names of aliases to global singletons, exactly as
     /// specified in the .slint markup (not normalized).
     pub fn global_aliases(&self) -> Vec<String> {
@@ -434,6 +527,36 @@ pub fn repeater_count(&self) -> u32 {
     }
 }
 
+/// An interface only declares properties, callbacks, and functions: check that it has nothing else
+fn check_interface_element(node: &syntax_nodes::Element, diag: &mut BuildDiagnostics) {
+    let mut error_on = |node: &dyn Spanned, what: &str| {
+        diag.push_error(format!("An interface cannot have {}", what), node);
+    };
+    node.SubElement().for_each(|n| error_on(&n, "sub elements"));
+    node.RepeatedElement().for_each(|n| error_on(&n, "sub elements"));
+    if let Some(n) = node.ChildrenPlaceholder() {
+        error_on(&n, "sub elements");
+    }
+    node.Binding().for_each(|n| error_on(&n, "bindings"));
+    node.TwoWayBinding().for_each(|n| error_on(&n, "bindings"));
+    node.CallbackConnection().for_each(|n| error_on(&n, "callback handlers"));
+    node.PropertyAnimation().for_each(|n| error_on(&n, "animations"));
+    node.States().for_each(|n| error_on(&n, "states"));
+    node.Transitions().for_each(|n| error_on(&n, "transitions"));
+    for prop_decl in node.PropertyDeclaration() {
+        if let Some(n) = prop_decl.BindingExpression() {
+            error_on(&n, "bindings");
+        }
+        if let Some(n) = prop_decl.TwoWayBinding() {
+            error_on(&n, "bindings");
+        }
+    }
+    node.CallbackDeclaration().filter_map(|cb| cb.TwoWayBinding()).for_each(|n| {
+        error_on(&n, "bindings");
+    });
+    node.Function().filter_map(|f| f.CodeBlock()).for_each(|n| error_on(&n, "function bodies"));
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
 pub enum PropertyVisibility {
     #[default]
@@ -803,6 +926,8 @@ pub fn is_used(&self) -> bool {
 
 #[derive(Debug, Clone)]
 pub struct ListViewInfo {
+    /// For a HorizontalListView, the x, width and height are swapped with the y, height and width
+    pub horizontal: bool,
     pub viewport_y: NamedReference,
     pub viewport_height: NamedReference,
     pub viewport_width: NamedReference,
@@ -810,6 +935,8 @@ pub struct ListViewInfo {
     pub listview_height: NamedReference,
     /// The ListView's inner visible width (not counting eventual scrollbar)
     pub listview_width: NamedReference,
+    /// The row to scroll to on the next layout, or -1
+    pub scroll_to_row: NamedReference,
 }
 
 #[derive(Debug, Clone)]
@@ -857,6 +984,10 @@ pub fn from_node(
                     );
                     ElementType::Error
                 }
+                Ok(ElementType::Component(c)) if c.is_interface => {
+                    diag.push_error("Cannot create an instance of an interface".into(), &base_node);
+                    ElementType::Error
+                }
                 Ok(ty) => ty,
                 Err(err) => {
                     diag.push_error(err, &base_node);
@@ -1140,9 +1271,13 @@ pub fn from_node(
                 func.ReturnType()
                     .map_or(Type::Void, |ret_ty| type_from_node(ret_ty.Type(), diag, tr)),
             );
-            if r.bindings
-                .insert(name.clone(), BindingExpression::new_uncompiled(func.clone().into()).into())
-                .is_some()
+            if func.CodeBlock().is_some()
+                && r.bindings
+                    .insert(
+                        name.clone(),
+                        BindingExpression::new_uncompiled(func.clone().into()).into(),
+                    )
+                    .is_some()
             {
                 assert!(diag.has_error());
             }
@@ -1385,7 +1520,7 @@ pub fn from_node(
             }
         }
 
-        if r.borrow().base_type.to_string() == "ListView" {
+        if listview_is_horizontal(&r.borrow().base_type).is_some() {
             let mut seen_for = false;
             for se in node.children() {
                 if se.kind() == SyntaxKind::RepeatedElement && !seen_for {
@@ -1440,16 +1575,26 @@ fn from_repeated_node(
         diag: &mut BuildDiagnostics,
         tr: &TypeRegister,
     ) -> ElementRc {
-        let is_listview = if parent.borrow().base_type.to_string() == "ListView" {
-            Some(ListViewInfo {
+        let is_listview = match listview_is_horizontal(&parent.borrow().base_type) {
+            Some(false) => Some(ListViewInfo {
+                horizontal: false,
                 viewport_y: NamedReference::new(parent, "viewport-y"),
                 viewport_height: NamedReference::new(parent, "viewport-height"),
                 viewport_width: NamedReference::new(parent, "viewport-width"),
                 listview_height: NamedReference::new(parent, "visible-height"),
                 listview_width: NamedReference::new(parent, "visible-width"),
-            })
-        } else {
-            None
+                scroll_to_row: NamedReference::new(parent, "scroll-to-row-request"),
+            }),
+            Some(true) => Some(ListViewInfo {
+                horizontal: true,
+                viewport_y: NamedReference::new(parent, "viewport-x"),
+                viewport_height: NamedReference::new(parent, "viewport-width"),
+                viewport_width: NamedReference::new(parent, "viewport-height"),
+                listview_height: NamedReference::new(parent, "visible-width"),
+                listview_width: NamedReference::new(parent, "visible-height"),
+                scroll_to_row: NamedReference::new(parent, "scroll-to-row-request"),
+            }),
+            None => None,
         };
         let rei = RepeatedElementInfo {
             model: Expression::Uncompiled(node.Expression().into()),
@@ -1696,6 +1841,20 @@ pub fn sub_component(&self) -> Option<&Rc<Component>> {
 }
 
 /// Apply default property values defined in `builtins.slint` to the element.
+/// Returns None if the type is not a `ListView`, otherwise whether the items are laid out
+/// horizontally. A component that inherits a `ListView` lays out its items horizontally when it
+/// declares the `horizontal-layout` property, like the `HorizontalListView` widget.
+fn listview_is_horizontal(ty: &ElementType) -> Option<bool> {
+    let ElementType::Component(c) = ty else { return None };
+    if c.id == "ListView" {
+        return Some(false);
+    }
+    let root = c.root_element.borrow();
+    (root.property_declarations.contains_key("horizontal-layout")
+        && listview_is_horizontal(&root.base_type).is_some())
+    .then_some(true)
+}
+
 fn apply_default_type_properties(element: &mut Element) {
     // Apply default property values on top:
     if let ElementType::Builtin(builtin_base) = &element.base_type {
@@ -2128,6 +2287,7 @@ pub fn visit_all_named_references_in_element(
             vis(&mut lv.viewport_width);
             vis(&mut lv.listview_height);
             vis(&mut lv.listview_width);
+            vis(&mut lv.scroll_to_row);
         }
     }
     elem.borrow_mut().repeated = repeated;
@@ -2309,7 +2469,7 @@ pub fn from_node(
             |it: &mut dyn Iterator<Item = (ExportedName, Either<Rc<Component>, Type>)>| {
                 for (name, compo_or_type) in it {
                     match compo_or_type.as_ref().left() {
-                        Some(compo) if !compo.is_global() => {
+                        Some(compo) if !compo.is_global() && !compo.is_interface => {
                             last_exported_component = Some(compo.clone())
                         }
                         _ => {}
@@ -2433,7 +2593,7 @@ pub fn from_node(
         }
 
         if last_exported_component.is_none() {
-            last_exported_component = inner_components.last().cloned();
+            last_exported_component = inner_components.last().filter(|c| !c.is_interface).cloned();
         }
 
         Self { components_or_types: sorted_deduped_exports, last_exported_component }
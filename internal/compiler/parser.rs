@@ -328,7 +328,9 @@ fn source_file(&self) -> Option<&SourceFile> {
     {
         Document -> [ *Component, *ExportsList, *ImportSpecifier, *StructDeclaration, *EnumDeclaration ],
         /// `DeclaredIdentifier := Element { ... }`
-        Component -> [ DeclaredIdentifier, Element ],
+        Component -> [ DeclaredIdentifier, ?ImplementsSpecifier, Element ],
+        /// `implements Foo, Bar` (after the component name)
+        ImplementsSpecifier -> [ *QualifiedName ],
         /// `id := Element { ... }`
         SubElement -> [ Element ],
         Element -> [ ?QualifiedName, *PropertyDeclaration, *Binding, *CallbackConnection,
@@ -338,7 +340,7 @@ fn source_file(&self) -> Option<&SourceFile> {
         RepeatedIndex -> [],
         ConditionalElement -> [ Expression , SubElement],
         CallbackDeclaration -> [ DeclaredIdentifier, *Type, ?ReturnType, ?TwoWayBinding ],
-        Function -> [DeclaredIdentifier, *ArgumentDeclaration, ?ReturnType, CodeBlock ],
+        Function -> [DeclaredIdentifier, *ArgumentDeclaration, ?ReturnType, ?CodeBlock ],
         ArgumentDeclaration -> [DeclaredIdentifier, Type],
         /// `-> type`  (but without the ->)
         ReturnType -> [Type],
@@ -378,7 +380,9 @@ fn source_file(&self) -> Option<&SourceFile> {
         /// `| "foo" % n`  in a `AtTr` node
         TrPlural -> [Expression],
         /// expression()
-        FunctionCallExpression -> [*Expression],
+        FunctionCallExpression -> [*Expression, *Lambda],
+        /// `item => expression` as argument of a function call, such as `array.map(item => ...)`
+        Lambda -> [DeclaredIdentifier, Expression],
         /// `expression[index]`
         IndexExpression -> [2 Expression],
         /// `expression += expression`
@@ -99,26 +99,43 @@ pub fn parse_document(p: &mut impl Parser) -> bool {
 /// global Struct { property<int> xx; }
 /// component C { property<int> xx; }
 /// component C inherits D { }
+/// interface I { in property <int> xx; callback cc(); public function ff(x: int) -> int; }
+/// component C implements I { }
+/// component C implements I, J inherits D { }
 /// ```
 pub fn parse_component(p: &mut impl Parser) -> bool {
     let simple_component = p.nth(1).kind() == SyntaxKind::ColonEqual;
     let is_global = !simple_component && p.peek().as_str() == "global";
+    let is_interface = !simple_component && p.peek().as_str() == "interface";
     let is_new_component = !simple_component && p.peek().as_str() == "component";
-    if !is_global && !simple_component && !is_new_component {
+    if !is_global && !is_interface && !simple_component && !is_new_component {
         p.error(
             "Parse error: expected a top-level item such as a component, a struct, or a global",
         );
         return false;
     }
     let mut p = p.start_node(SyntaxKind::Component);
-    if is_global || is_new_component {
+    if is_global || is_interface || is_new_component {
         p.consume();
     }
     if !p.start_node(SyntaxKind::DeclaredIdentifier).expect(SyntaxKind::Identifier) {
         drop(p.start_node(SyntaxKind::Element));
         return false;
     }
-    if is_global {
+    if is_new_component && p.peek().as_str() == "implements" {
+        if !parse_implements_specifier(&mut *p) {
+            drop(p.start_node(SyntaxKind::Element));
+            return false;
+        }
+    }
+    if is_interface {
+        let mut p = p.start_node(SyntaxKind::Element);
+        if !p.expect(SyntaxKind::LBrace) {
+            return false;
+        }
+        parse_element_content(&mut *p);
+        return p.expect(SyntaxKind::RBrace);
+    } else if is_global {
         if p.peek().kind() == SyntaxKind::ColonEqual {
             p.warning("':=' to declare a global is deprecated. Remove the ':='");
             p.consume();
@@ -154,6 +171,25 @@ pub fn parse_component(p: &mut impl Parser) -> bool {
     parse_element(&mut *p)
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,ImplementsSpecifier
+/// implements Foo
+/// implements Foo, Bar.Baz
+/// ```
+fn parse_implements_specifier(p: &mut impl Parser) -> bool {
+    debug_assert_eq!(p.peek().as_str(), "implements");
+    let mut p = p.start_node(SyntaxKind::ImplementsSpecifier);
+    p.consume(); // "implements"
+    loop {
+        if !parse_qualified_name(&mut *p) {
+            return false;
+        }
+        if !p.test(SyntaxKind::Comma) {
+            return true;
+        }
+    }
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,QualifiedName
 /// Rectangle
@@ -185,6 +221,7 @@ pub fn parse_qualified_name(p: &mut impl Parser) -> bool {
 /// export Foo := Item { }
 /// export struct Foo := { foo: bar }
 /// export enum Foo { bar }
+/// export interface Foo { in property <int> foo; }
 /// export * from "foo";
 /// ```
 fn parse_export<P: Parser>(p: &mut P, checkpoint: Option<P::Checkpoint>) -> bool {
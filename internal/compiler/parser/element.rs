@@ -577,6 +577,7 @@ fn parse_transition_inner(p: &mut impl Parser) -> bool {
 /// function bar(xx : int,) -> int { return 42; }
 /// public function aa(x: int, b: {a: int}, c: int) {}
 /// protected pure function fff() {}
+/// public function declared_only(x: int) -> int;
 /// ```
 fn parse_function(p: &mut impl Parser) {
     let mut p = p.start_node(SyntaxKind::Function);
@@ -623,5 +624,8 @@ fn parse_function(p: &mut impl Parser) {
             parse_type(&mut *p);
         }
     }
-    parse_code_block(&mut *p);
+    // A function without body is only valid in an interface. This is checked in the object tree.
+    if !p.test(SyntaxKind::Semicolon) {
+        parse_code_block(&mut *p);
+    }
 }
@@ -297,12 +297,17 @@ fn parse_object_notation(p: &mut impl Parser) {
 /// (foo)
 /// (foo, bar, foo)
 /// (foo, bar(), xx+xx,)
+/// (x => x + 1)
 /// ```
 fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::LParent);
 
     while p.nth(0).kind() != SyntaxKind::RParent {
-        parse_expression(&mut *p);
+        if p.nth(0).kind() == SyntaxKind::Identifier && p.nth(1).kind() == SyntaxKind::FatArrow {
+            parse_lambda(&mut *p);
+        } else {
+            parse_expression(&mut *p);
+        }
         if !p.test(SyntaxKind::Comma) {
             break;
         }
@@ -310,6 +315,21 @@ fn parse_function_arguments(p: &mut impl Parser) {
     p.expect(SyntaxKind::RParent);
 }
 
+#[cfg_attr(test, parser_test)]
+/// ```test,Lambda
+/// x => x + 1
+/// item => item.name == "foo"
+/// ```
+fn parse_lambda(p: &mut impl Parser) {
+    let mut p = p.start_node(SyntaxKind::Lambda);
+    {
+        let mut p = p.start_node(SyntaxKind::DeclaredIdentifier);
+        p.expect(SyntaxKind::Identifier);
+    }
+    p.expect(SyntaxKind::FatArrow);
+    parse_expression(&mut *p);
+}
+
 #[cfg_attr(test, parser_test)]
 /// ```test,StringTemplate
 /// "foo\{bar}"
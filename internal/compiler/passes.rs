@@ -6,6 +6,7 @@
 mod check_expressions;
 mod check_public_api;
 mod check_rotation;
+mod check_unused;
 mod clip;
 mod collect_custom_fonts;
 mod collect_globals;
@@ -35,6 +36,7 @@
 mod lower_states;
 mod lower_tabwidget;
 mod lower_text_input_interface;
+mod lower_timelines;
 pub mod materialize_fake_properties;
 pub mod move_declarations;
 mod optimize_useless_rectangles;
@@ -78,7 +80,7 @@ pub async fn run_passes(
 
     let global_type_registry = type_loader.global_type_registry.clone();
     let root_component = &doc.root_component;
-    run_import_passes(doc, type_loader, diag);
+    run_import_passes(doc, type_loader, true, diag);
     check_public_api::check_public_api(doc, diag);
 
     collect_subcomponents::collect_subcomponents(root_component);
@@ -115,6 +117,7 @@ pub async fn run_passes(
         repeater_component::process_repeater_components(component);
         lower_popups::lower_popups(component, &doc.local_registry, diag);
         lower_component_container::lower_component_container(component, &doc.local_registry, diag);
+        lower_timelines::lower_timelines(component, diag);
 
         lower_layout::lower_layouts(component, type_loader, diag).await;
         default_geometry::default_geometry(component, diag);
@@ -265,14 +268,20 @@ pub async fn run_passes(
     root_component.is_root_component.set(true);
 }
 
-/// Run the passes on imported documents
+/// Run the passes on imported documents, and on the main document before the other passes.
+/// The unused declarations are only reported in the main document.
 pub fn run_import_passes(
     doc: &crate::object_tree::Document,
     type_loader: &crate::typeloader::TypeLoader,
+    is_main_document: bool,
     diag: &mut crate::diagnostics::BuildDiagnostics,
 ) {
     infer_aliases_types::resolve_aliases(doc, diag);
     resolving::resolve_expressions(doc, type_loader, diag);
+    if is_main_document && type_loader.compiler_config.warn_unused {
+        // Before the passes that change the bindings, like the removal of `forward-focus`
+        check_unused::check_unused(doc, diag);
+    }
     focus_handling::replace_forward_focus_bindings_with_focus_functions(doc, diag);
     check_expressions::check_expressions(doc, diag);
     purity_check::purity_check(doc, diag);
@@ -10,6 +10,7 @@
 use by_address::ByAddress;
 
 use crate::diagnostics::BuildDiagnostics;
+use crate::diagnostics::SourceLocation;
 use crate::diagnostics::Spanned;
 use crate::expression_tree::BindingExpression;
 use crate::expression_tree::BuiltinFunction;
@@ -189,6 +190,7 @@ fn analyze_element(
             process_property(&lv.viewport_width.clone().into(), context, reverse_aliases, diag);
             process_property(&lv.listview_height.clone().into(), context, reverse_aliases, diag);
             process_property(&lv.listview_width.clone().into(), context, reverse_aliases, diag);
+            process_property(&lv.scroll_to_row.clone().into(), context, reverse_aliases, diag);
         }
     }
     if let Some((h, v)) = &elem.borrow().layout_info_prop {
@@ -222,6 +224,7 @@ fn analyze_binding(
     }
 
     if context.currently_analyzing.contains(current) {
+        let cycle = binding_loop_locations(current, context);
         for it in context.currently_analyzing.iter().rev() {
             let p = &it.prop;
             let elem = p.element();
@@ -231,9 +234,10 @@ fn analyze_binding(
                 break;
             }
 
-            diag.push_error(
+            diag.push_error_with_related_locations(
                 format!("The binding for the property '{}' is part of a binding loop", p.name()),
                 &binding.span.clone().unwrap_or_else(|| elem.to_source_location()),
+                cycle.clone(),
             );
 
             if it == current {
@@ -317,6 +321,63 @@ fn analyze_binding(
     depends_on_external
 }
 
+/// Return the location of each property in the binding loop that goes back to `current`,
+/// in the order of the dependencies, with a message describing the dependency
+fn binding_loop_locations(
+    current: &PropertyPath,
+    context: &AnalysisContext,
+) -> Vec<(String, SourceLocation)> {
+    let cycle =
+        context.currently_analyzing.iter().skip_while(|p| *p != current).collect::<Vec<_>>();
+    cycle
+        .iter()
+        .enumerate()
+        .map(|(i, p)| {
+            let next = cycle.get(i + 1).copied().unwrap_or(current);
+            let element = p.prop.element();
+            let element = element.borrow();
+            let location = element
+                .bindings
+                .get(p.prop.name())
+                .and_then(|b| b.borrow().span.clone())
+                .unwrap_or_else(|| element.to_source_location());
+            (format!("{} depends on {}", describe_property(p), describe_property(next)), location)
+        })
+        .collect()
+}
+
+/// Describe the property for the binding loop diagnostics.
+/// The properties generated for the layouts are given a more understandable name.
+fn describe_property(path: &PropertyPath) -> String {
+    let element_name = |e: &ElementRc| {
+        let e = e.borrow();
+        if e.id.is_empty() {
+            e.base_type.to_string()
+        } else {
+            e.id.clone()
+        }
+    };
+    let element = path.prop.element();
+    let mut names = path.elements.iter().map(|e| element_name(&e.0)).collect::<Vec<_>>();
+    let is_component_root = element
+        .borrow()
+        .enclosing_component
+        .upgrade()
+        .map_or(false, |c| Rc::ptr_eq(&c.root_element, &element));
+    if names.is_empty() || !is_component_root {
+        names.push(element_name(&element));
+    }
+    let element_name = names.join(".");
+    match path.prop.name() {
+        "layoutinfo-h" => format!("the horizontal layout constraints of '{element_name}'"),
+        "layoutinfo-v" => format!("the vertical layout constraints of '{element_name}'"),
+        "layout-cache" | "layout-cache-h" | "layout-cache-v" => {
+            format!("the layout of '{element_name}'")
+        }
+        name => format!("'{element_name}.{name}'"),
+    }
+}
+
 /// Process the property `prop`
 ///
 /// This will visit all the bindings from that property
@@ -602,3 +663,40 @@ fn mark_used_base_properties(component: &Rc<Component>) {
         mark_used_base_properties(c);
     }
 }
+
+#[test]
+fn test_binding_loop_related_locations() {
+    let mut compiler_config =
+        crate::CompilerConfiguration::new(crate::generator::OutputFormat::Interpreter);
+    compiler_config.style = Some("fluent".into());
+    let mut test_diags = crate::diagnostics::BuildDiagnostics::default();
+    let doc_node = crate::parser::parse(
+        r#"
+export component Foo {
+    property <int> a: b;
+    property <int> b: c + 1;
+    property <int> c: a;
+    out property <int> d: a;
+}
+"#
+        .into(),
+        Some(std::path::Path::new("HELLO")),
+        None,
+        &mut test_diags,
+    );
+    let (_, diag) =
+        spin_on::spin_on(crate::compile_syntax_node(doc_node, test_diags, compiler_config));
+    let errors = diag.iter().collect::<Vec<_>>();
+    assert_eq!(errors.len(), 3);
+    for error in errors {
+        assert!(error.message().contains("is part of a binding loop"));
+        let related = error.related_locations().collect::<Vec<_>>();
+        assert_eq!(related.len(), 3);
+        assert_eq!(related[0].0, "'root.a' depends on 'root.b'");
+        assert_eq!(related[0].2, (3, 23));
+        assert_eq!(related[1].0, "'root.b' depends on 'root.c'");
+        assert_eq!(related[1].2, (4, 23));
+        assert_eq!(related[2].0, "'root.c' depends on 'root.a'");
+        assert_eq!(related[2].2, (5, 23));
+    }
+}
@@ -2,13 +2,20 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
 //! Pass that check that the public api is ok and mark the property as exposed
+//!
+//! This also checks that components declare everything required by the interfaces they implement.
 
 use std::rc::Rc;
 
 use crate::diagnostics::{BuildDiagnostics, DiagnosticLevel};
+use crate::langtype::Type;
 use crate::object_tree::{Component, Document, PropertyVisibility};
+use crate::parser::syntax_nodes;
 
 pub fn check_public_api(doc: &Document, diag: &mut BuildDiagnostics) {
+    for c in &doc.inner_components {
+        check_implemented_interfaces(c, diag);
+    }
     check_public_api_component(&doc.root_component, diag);
     for (export_name, e) in &*doc.exports {
         if let Some(c) = e.as_ref().left() {
@@ -44,3 +51,58 @@ fn check_public_api_component(root_component: &Rc<Component>, diag: &mut BuildDi
         }
     });
 }
+
+fn check_implemented_interfaces(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    let Some(implements) = component
+        .node
+        .clone()
+        .and_then(syntax_nodes::Component::new)
+        .and_then(|n| n.ImplementsSpecifier())
+    else {
+        return;
+    };
+    let root_elem = component.root_element.borrow();
+    for interface in &component.implemented_interfaces {
+        for (name, required) in &interface.root_element.borrow().property_declarations {
+            let what = match required.property_type {
+                Type::Callback { .. } => "callback",
+                Type::Function { .. } => "function",
+                _ => "property",
+            };
+            let lookup_result = root_elem.lookup_property(name);
+            if !lookup_result.is_valid() {
+                diag.push_error(
+                    format!(
+                        "'{}' does not declare the {what} '{name}' required by interface '{}'",
+                        component.id, interface.id
+                    ),
+                    &implements,
+                );
+            } else if lookup_result.property_type != required.property_type {
+                diag.push_error(
+                    format!(
+                        "The {what} '{name}' has type '{}' but interface '{}' requires '{}'",
+                        lookup_result.property_type, interface.id, required.property_type
+                    ),
+                    &implements,
+                );
+            } else if !visibility_satisfies(required.visibility, lookup_result.property_visibility)
+            {
+                diag.push_error(
+                    format!(
+                        "The {what} '{name}' is {} but interface '{}' requires it to be {}",
+                        lookup_result.property_visibility, interface.id, required.visibility
+                    ),
+                    &implements,
+                );
+            }
+        }
+    }
+}
+
+/// An in-out property can implement an input or an output property of an interface
+fn visibility_satisfies(required: PropertyVisibility, actual: PropertyVisibility) -> bool {
+    required == actual
+        || (actual == PropertyVisibility::InOut
+            && matches!(required, PropertyVisibility::Input | PropertyVisibility::Output))
+}
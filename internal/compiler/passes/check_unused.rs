@@ -0,0 +1,176 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Warn about the private properties, callbacks and functions, the element ids, the imports
+//! and the components that are declared but never used.
+//!
+//! This is only done for the main document, when `CompilerConfiguration::warn_unused` is set.
+//! Items whose name starts with an underscore are never reported.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::Expression;
+use crate::langtype::Type;
+use crate::object_tree::{Component, Document, PropertyVisibility};
+use crate::parser::{normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode};
+use by_address::ByAddress;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+pub fn check_unused(doc: &Document, diag: &mut BuildDiagnostics) {
+    let Some(node) = &doc.node else { return };
+    if node.source_file.path().starts_with("builtin:") {
+        return;
+    }
+
+    for component in &doc.inner_components {
+        if component.is_interface {
+            continue;
+        }
+        check_unused_declarations(component, diag);
+        check_unused_ids(component, diag);
+    }
+
+    check_unused_components_and_imports(doc, node, diag);
+}
+
+/// Names starting with an underscore (normalized to a dash) are never reported
+fn is_silenced(name: &str) -> bool {
+    name.starts_with('-')
+}
+
+fn check_unused_declarations(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    let mut used = HashSet::new();
+    crate::object_tree::visit_all_named_references(component, &mut |nr| {
+        used.insert((ByAddress(nr.element()), nr.name().to_string()));
+    });
+
+    crate::object_tree::recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+        // Only the declarations of the root element can be part of the public API
+        let is_root = Rc::ptr_eq(elem, &component.root_element);
+        for (name, decl) in &elem.borrow().property_declarations {
+            if (is_root && decl.visibility != PropertyVisibility::Private) || is_silenced(name) {
+                continue;
+            }
+            let Some(node) = &decl.node else { continue };
+            if used.contains(&(ByAddress(elem.clone()), name.clone())) {
+                continue;
+            }
+            let what = match decl.property_type {
+                Type::Function { .. } => "Function",
+                Type::Callback { .. } => "Callback",
+                _ => "Property",
+            };
+            diag.push_warning(
+                format!("{what} '{name}' is never used"),
+                &node.child_node(SyntaxKind::DeclaredIdentifier).unwrap_or_else(|| node.clone()),
+            );
+        }
+    });
+}
+
+fn check_unused_ids(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    // The elements referenced from the expressions of other elements, including the two way
+    // bindings and the states. References from the element itself, like `self.x`, don't count.
+    let mut referenced = HashSet::new();
+    crate::object_tree::recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+        crate::object_tree::visit_all_named_references_in_element(elem, |nr| {
+            let element = nr.element();
+            if !Rc::ptr_eq(&element, elem) {
+                referenced.insert(ByAddress(element));
+            }
+        });
+        crate::object_tree::visit_element_expressions(elem, |expr, _, _| {
+            expr.visit_recursive(&mut |e| {
+                if let Expression::ElementReference(element) = e {
+                    if let Some(element) = element.upgrade().filter(|e| !Rc::ptr_eq(e, elem)) {
+                        referenced.insert(ByAddress(element));
+                    }
+                }
+            })
+        });
+    });
+
+    // References through `parent` also resolve to the element: The id must be written as well
+    let written = component
+        .node
+        .iter()
+        .flat_map(descendants)
+        .filter(|n| n.kind() == SyntaxKind::QualifiedName)
+        .filter_map(|n| n.child_text(SyntaxKind::Identifier))
+        .map(|id| normalize_identifier(&id))
+        .collect::<HashSet<_>>();
+
+    crate::object_tree::recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
+        let e = elem.borrow();
+        let Some(id_token) = e
+            .node
+            .first()
+            .and_then(|n| n.parent())
+            .filter(|p| p.kind() == SyntaxKind::SubElement)
+            .and_then(|p| p.child_token(SyntaxKind::Identifier))
+        else {
+            return;
+        };
+        let id = normalize_identifier(id_token.text());
+        if !is_silenced(&id)
+            && !(referenced.contains(&ByAddress(elem.clone())) && written.contains(&id))
+        {
+            diag.push_warning(format!("Element id '{id}' is never used"), &id_token);
+        }
+    });
+}
+
+fn check_unused_components_and_imports(
+    doc: &Document,
+    node: &syntax_nodes::Document,
+    diag: &mut BuildDiagnostics,
+) {
+    // The first identifier of all the qualified names: they may refer to a type or a global
+    let mut referenced = descendants(node)
+        .filter(|n| n.kind() == SyntaxKind::QualifiedName)
+        .filter_map(|n| n.child_text(SyntaxKind::Identifier))
+        .map(|name| normalize_identifier(&name))
+        .collect::<HashSet<_>>();
+    referenced.extend(
+        node.ExportsList()
+            .flat_map(|exports| exports.ExportSpecifier())
+            .filter_map(|specifier| crate::parser::identifier_text(&specifier.ExportIdentifier())),
+    );
+
+    for component in &doc.inner_components {
+        if doc
+            .exports
+            .iter()
+            .any(|(_, e)| e.as_ref().left().map_or(false, |c| Rc::ptr_eq(c, component)))
+            || is_silenced(&component.id)
+            || referenced.contains(&component.id)
+        {
+            continue;
+        }
+        let Some(component_node) = &component.node else { continue };
+        diag.push_warning(
+            format!("Component '{}' is never used", component.id),
+            &component_node
+                .child_node(SyntaxKind::DeclaredIdentifier)
+                .unwrap_or_else(|| component_node.clone()),
+        );
+    }
+
+    for import in node.ImportSpecifier() {
+        let Some(list) = import.ImportIdentifierList() else { continue };
+        for identifier in list.ImportIdentifier() {
+            let local_name = identifier
+                .InternalName()
+                .and_then(|n| crate::parser::identifier_text(&n))
+                .or_else(|| crate::parser::identifier_text(&identifier.ExternalName()));
+            let Some(local_name) = local_name else { continue };
+            if !is_silenced(&local_name) && !referenced.contains(&local_name) {
+                diag.push_warning(format!("Import '{local_name}' is never used"), &identifier);
+            }
+        }
+    }
+}
+
+fn descendants(node: &SyntaxNode) -> impl Iterator<Item = SyntaxNode> + '_ {
+    node.node.descendants().map(|n| SyntaxNode { node: n, source_file: node.source_file.clone() })
+}
@@ -339,6 +339,8 @@ fn duplicate_sub_component(
         exported_global_names: component_to_duplicate.exported_global_names.clone(),
         is_root_component: Default::default(),
         private_properties: Default::default(),
+        is_interface: component_to_duplicate.is_interface,
+        implemented_interfaces: component_to_duplicate.implemented_interfaces.clone(),
     };
 
     let new_component = Rc::new(new_component);
@@ -376,6 +378,7 @@ fn duplicate_popup(
         x: p.x.clone(),
         y: p.y.clone(),
         close_on_click: p.close_on_click,
+        modal: p.modal,
         component: duplicate_sub_component(&p.component, &parent, mapping, priority_delta),
         parent_element: mapping
             .get(&element_key(p.parent_element.clone()))
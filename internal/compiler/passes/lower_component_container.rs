@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
 use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::Expression;
 use crate::langtype::ElementType;
 use crate::object_tree::*;
 use crate::typeregister::TypeRegister;
@@ -17,6 +18,7 @@ pub fn lower_component_container(
     recurse_elem_including_sub_components_no_borrow(component, &None, &mut |elem, _| {
         if matches!(&elem.borrow().builtin_type(), Some(b) if b.name == "ComponentContainer") {
             diagnose_component_container(elem, diag);
+            lower_interface(elem, type_register, diag);
             process_component_container(elem, &empty_type);
         }
         Some(elem.clone())
@@ -30,6 +32,31 @@ fn diagnose_component_container(element: &ElementRc, diag: &mut BuildDiagnostics
     }
 }
 
+/// The `interface` property must name an interface. It is replaced by the signature of the interface,
+/// which the ComponentContainer checks at run-time against the public API declared by the component
+/// factory.
+fn lower_interface(element: &ElementRc, type_register: &TypeRegister, diag: &mut BuildDiagnostics) {
+    let elem = element.borrow();
+    let Some(binding) = elem.bindings.get("interface") else {
+        return;
+    };
+    let mut binding = binding.borrow_mut();
+    let Expression::StringLiteral(name) = &binding.expression else {
+        diag.push_error(
+            "The interface of a ComponentContainer must be a string literal".into(),
+            &*binding,
+        );
+        return;
+    };
+    match type_register.lookup_element(name) {
+        Ok(ElementType::Component(c)) if c.is_interface => {
+            binding.expression = Expression::StringLiteral(c.public_api_signature().join("\n"));
+        }
+        Ok(_) => diag.push_error(format!("'{name}' is not an interface"), &*binding),
+        Err(err) => diag.push_error(err, &*binding),
+    }
+}
+
 fn process_component_container(element: &ElementRc, empty_type: &ElementType) {
     let mut elem = element.borrow_mut();
 
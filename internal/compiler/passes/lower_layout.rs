@@ -195,6 +195,8 @@ fn lower_grid_layout(
                 col = 0;
             }
             component.optimized_elements.borrow_mut().push(layout_child);
+        } else if is_timer(&layout_child) {
+            collected_children.push(layout_child);
         } else {
             grid.add_element(
                 &layout_child,
@@ -659,6 +661,9 @@ fn create_layout_item(
     item_element: &ElementRc,
     diag: &mut BuildDiagnostics,
 ) -> Option<CreateLayoutItemResult> {
+    if is_timer(item_element) {
+        return None;
+    }
     let fix_explicit_percent = |prop: &str, item: &ElementRc| {
         if !item.borrow().bindings.get(prop).map_or(false, |b| b.borrow().ty() == Type::Percent) {
             return;
@@ -708,6 +713,11 @@ fn create_layout_item(
     })
 }
 
+/// A Timer is not visible, so it doesn't take any space in the layout
+fn is_timer(elem: &ElementRc) -> bool {
+    matches!(&elem.borrow().base_type, ElementType::Builtin(b) if b.name == "Timer")
+}
+
 fn set_prop_from_cache(
     elem: &ElementRc,
     prop: &str,
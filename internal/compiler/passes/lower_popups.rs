@@ -1,7 +1,7 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
-//! Passe that transform the PopupWindow element into a component
+//! Passe that transform the PopupWindow and ChildWindow elements into a component
 
 use crate::diagnostics::BuildDiagnostics;
 use crate::expression_tree::{Expression, NamedReference};
@@ -22,9 +22,22 @@ pub fn lower_popups(
         component,
         &None,
         &mut |elem, parent_element: &Option<ElementRc>| {
-            let is_popup = matches!(&elem.borrow().base_type, ElementType::Builtin(base_type) if base_type.name == "PopupWindow");
-            if is_popup {
-                lower_popup_window(elem, parent_element.as_ref(), &window_type, diag);
+            let popup_type_name = match &elem.borrow().base_type {
+                ElementType::Builtin(base_type)
+                    if matches!(base_type.name.as_str(), "PopupWindow" | "ChildWindow") =>
+                {
+                    Some(base_type.name.clone())
+                }
+                _ => None,
+            };
+            if let Some(popup_type_name) = popup_type_name {
+                lower_popup_window(
+                    elem,
+                    &popup_type_name,
+                    parent_element.as_ref(),
+                    &window_type,
+                    diag,
+                );
             }
             Some(elem.clone())
         },
@@ -33,6 +46,7 @@ pub fn lower_popups(
 
 fn lower_popup_window(
     popup_window_element: &ElementRc,
+    popup_type_name: &str,
     parent_element: Option<&ElementRc>,
     window_type: &ElementType,
     diag: &mut BuildDiagnostics,
@@ -40,7 +54,7 @@ fn lower_popup_window(
     let parent_element = match parent_element {
         None => {
             diag.push_error(
-                "PopupWindow cannot be the top level".into(),
+                format!("{popup_type_name} cannot be the top level"),
                 &*popup_window_element.borrow(),
             );
             return;
@@ -51,7 +65,7 @@ fn lower_popup_window(
     let parent_component = popup_window_element.borrow().enclosing_component.upgrade().unwrap();
     if Rc::ptr_eq(&parent_component.root_element, popup_window_element) {
         diag.push_error(
-            "PopupWindow cannot be directly repeated or conditional".into(),
+            format!("{popup_type_name} cannot be directly repeated or conditional"),
             &*popup_window_element.borrow(),
         );
         return;
@@ -69,23 +83,14 @@ fn lower_popup_window(
 
     popup_window_element.borrow_mut().base_type = window_type.clone();
 
-    let close_on_click =
-        match popup_window_element.borrow_mut().bindings.remove("close-on-click").map_or_else(
-            || Ok(true),
-            |binding| match binding.borrow().expression {
-                Expression::BoolLiteral(value) => Ok(value),
-                _ => Err(binding.borrow().span.clone()),
-            },
-        ) {
-            Ok(coc) => coc,
-            Err(location) => {
-                diag.push_error(
-                    "The close-on-click property only supports constants at the moment".into(),
-                    &location,
-                );
-                return;
-            }
-        };
+    let Some(close_on_click) =
+        take_constant_bool(popup_window_element, "close-on-click", true, diag)
+    else {
+        return;
+    };
+    let Some(modal) = take_constant_bool(popup_window_element, "modal", false, diag) else {
+        return;
+    };
 
     let popup_comp = Rc::new(Component {
         root_element: popup_window_element.clone(),
@@ -111,7 +116,7 @@ fn lower_popup_window(
     visit_all_named_references(&parent_component, &mut |nr| {
         if std::rc::Weak::ptr_eq(&nr.element().borrow().enclosing_component, &weak) {
             diag.push_error(
-                "Cannot access the inside of a PopupWindow from enclosing component".into(),
+                format!("Cannot access the inside of a {popup_type_name} from enclosing component"),
                 &*popup_window_element.borrow(),
             );
             // just set it to whatever is a valid NamedReference, otherwise we'll panic later
@@ -124,10 +129,37 @@ fn lower_popup_window(
         x: coord_x,
         y: coord_y,
         close_on_click,
+        modal,
         parent_element: parent_element.clone(),
     });
 }
 
+/// Removes the binding of the property `name` from the popup and returns its value.
+/// Reports an error and returns None if it's not a constant.
+fn take_constant_bool(
+    popup_window_element: &ElementRc,
+    name: &str,
+    default: bool,
+    diag: &mut BuildDiagnostics,
+) -> Option<bool> {
+    match popup_window_element.borrow_mut().bindings.remove(name).map_or_else(
+        || Ok(default),
+        |binding| match binding.borrow().expression {
+            Expression::BoolLiteral(value) => Ok(value),
+            _ => Err(binding.borrow().span.clone()),
+        },
+    ) {
+        Ok(value) => Some(value),
+        Err(location) => {
+            diag.push_error(
+                format!("The {name} property only supports constants at the moment"),
+                &location,
+            );
+            None
+        }
+    }
+}
+
 fn create_coordinate(
     popup_comp: &Rc<Component>,
     parent_element: &ElementRc,
@@ -0,0 +1,463 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Passe that lowers the Timeline elements.
+//!
+//! A Timeline is not an item: it is removed from the tree and put in the optimized elements
+//! of its component so that the move_declarations pass keeps its properties. The `progress`
+//! and `running` properties get bindings computed from the `animation-tick()`, which stop
+//! requesting new frames once the timeline has finished, and calls to the member functions
+//! (`start()`, `pause()`, ...) are replaced by assignments to some internal properties holding
+//! the state of the timeline.
+//!
+//! Nested Timelines don't have their own clock: they are driven by the time of their parent,
+//! either all at once, or one after the other if the parent is `sequential`.
+
+use crate::diagnostics::BuildDiagnostics;
+use crate::expression_tree::{BuiltinFunction, Expression, MinMaxOp, NamedReference, Unit};
+use crate::langtype::{ElementType, Type};
+use crate::object_tree::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+pub fn lower_timelines(component: &Rc<Component>, diag: &mut BuildDiagnostics) {
+    let mut timelines = Vec::new();
+    let mut nested_timelines = Vec::new();
+    recurse_elem_including_sub_components_no_borrow(
+        component,
+        &None,
+        &mut |elem, parent_element: &Option<ElementRc>| {
+            if is_timeline(elem) {
+                if parent_element.as_ref().map_or(false, is_timeline) {
+                    nested_timelines.push(elem.clone());
+                } else {
+                    timelines.push((elem.clone(), parent_element.clone()));
+                }
+            }
+            Some(elem.clone())
+        },
+    );
+    if timelines.is_empty() {
+        return;
+    }
+
+    visit_all_expressions(component, |expr, _| {
+        expr.visit_recursive_mut(&mut |expr| {
+            lower_member_function_call(expr, &nested_timelines, diag)
+        })
+    });
+
+    for (timeline, parent_element) in timelines {
+        let enclosing = timeline.borrow().enclosing_component.upgrade().unwrap();
+        if Rc::ptr_eq(&enclosing.root_element, &timeline) {
+            diag.push_error(
+                "A Timeline cannot be the root of a component, be repeated or be in a conditional"
+                    .into(),
+                &*timeline.borrow(),
+            );
+            continue;
+        }
+        if let Some(parent_element) = parent_element {
+            parent_element.borrow_mut().children.retain(|c| !Rc::ptr_eq(c, &timeline));
+        }
+        lower_timeline(&timeline, None, &enclosing, diag);
+    }
+}
+
+fn is_timeline(elem: &ElementRc) -> bool {
+    matches!(&elem.borrow().base_type, ElementType::Builtin(b) if b.name == "Timeline")
+}
+
+/// Replace `timeline.start()` and friends by assignments to the state of the timeline
+fn lower_member_function_call(
+    expr: &mut Expression,
+    nested_timelines: &[ElementRc],
+    diag: &mut BuildDiagnostics,
+) {
+    let Expression::FunctionCall { function, arguments, source_location } = expr else {
+        return;
+    };
+    let Expression::BuiltinFunctionReference(BuiltinFunction::ItemMemberFunction(name), _) =
+        function.as_ref()
+    else {
+        return;
+    };
+    let Some(timeline) = arguments.first().and_then(|a| match a {
+        Expression::ElementReference(e) => e.upgrade().filter(is_timeline),
+        _ => None,
+    }) else {
+        return;
+    };
+    if nested_timelines.iter().any(|n| Rc::ptr_eq(n, &timeline)) {
+        diag.push_error(
+            format!(
+                "'{name}' cannot be called on a nested Timeline, which is driven by its parent"
+            ),
+            &source_location.clone(),
+        );
+        return;
+    }
+    let name = name.clone();
+
+    let prop = |name: &str| Expression::PropertyReference(NamedReference::new(&timeline, name));
+    let assign = |name: &str, value: Expression| Expression::SelfAssignment {
+        lhs: Box::new(prop(name)),
+        rhs: Box::new(value),
+        op: '=',
+        node: None,
+    };
+    let local =
+        |name: &str| Expression::ReadLocalVariable { name: name.into(), ty: Type::Duration };
+
+    *expr = match name.as_str() {
+        "start" => Expression::CodeBlock(vec![
+            assign("start-tick", animation_tick()),
+            assign("paused-elapsed", duration(0.)),
+            assign("reversed", Expression::BoolLiteral(false)),
+            assign("playing", Expression::BoolLiteral(true)),
+        ]),
+        "stop" => Expression::CodeBlock(vec![
+            assign("playing", Expression::BoolLiteral(false)),
+            assign("paused-elapsed", duration(0.)),
+            assign("reversed", Expression::BoolLiteral(false)),
+        ]),
+        "pause" => condition(
+            prop("playing"),
+            Expression::CodeBlock(vec![
+                assign("paused-elapsed", binop(animation_tick(), '-', prop("start-tick"))),
+                assign("playing", Expression::BoolLiteral(false)),
+            ]),
+            Expression::CodeBlock(vec![]),
+        ),
+        "resume" => condition(
+            prop("playing"),
+            Expression::CodeBlock(vec![]),
+            Expression::CodeBlock(vec![
+                assign("start-tick", binop(animation_tick(), '-', prop("paused-elapsed"))),
+                assign("playing", Expression::BoolLiteral(true)),
+            ]),
+        ),
+        "reverse" => {
+            // Mirror the current time around the end of the timeline (or the end of the
+            // current iteration if it repeats forever) so that the progress stays the same
+            let active_time = max(prop("active-time"), duration(0.));
+            let mirror = condition(
+                binop(prop("iteration-count"), '≥', number(0.)),
+                binop(prop("duration"), '*', prop("iteration-count")),
+                condition(
+                    binop(prop("duration"), '>', duration(0.)),
+                    binop(
+                        ceil(binop(active_time.clone(), '/', prop("duration"))),
+                        '*',
+                        prop("duration"),
+                    ),
+                    duration(0.),
+                ),
+            );
+            let new_elapsed = binop(
+                binop(prop("delay"), '+', local("timeline_mirror")),
+                '-',
+                min(active_time, local("timeline_mirror")),
+            );
+            Expression::CodeBlock(vec![
+                Expression::StoreLocalVariable {
+                    name: "timeline_mirror".into(),
+                    value: Box::new(mirror),
+                },
+                Expression::StoreLocalVariable {
+                    name: "timeline_elapsed".into(),
+                    value: Box::new(new_elapsed),
+                },
+                assign(
+                    "reversed",
+                    Expression::UnaryOp { sub: Box::new(prop("reversed")), op: '!' },
+                ),
+                condition(
+                    prop("playing"),
+                    assign("start-tick", binop(animation_tick(), '-', local("timeline_elapsed"))),
+                    assign("paused-elapsed", local("timeline_elapsed")),
+                ),
+            ])
+        }
+        _ => return,
+    };
+}
+
+/// Remove the timeline from the tree and add the bindings computing its progress.
+/// `parent` is the parent Timeline and the offset (in the parent's time) at which this
+/// nested timeline starts.
+fn lower_timeline(
+    timeline: &ElementRc,
+    parent: Option<(&ElementRc, Expression)>,
+    enclosing: &Rc<Component>,
+    diag: &mut BuildDiagnostics,
+) {
+    let prop = |name: &str| Expression::PropertyReference(NamedReference::new(timeline, name));
+
+    let children = std::mem::take(&mut timeline.borrow_mut().children);
+    let has_children = !children.is_empty();
+
+    let elapsed = match &parent {
+        None => {
+            declare(timeline, "playing", Type::Bool);
+            declare(timeline, "reversed", Type::Bool);
+            declare(timeline, "start-tick", Type::Duration);
+            declare(timeline, "paused-elapsed", Type::Duration);
+            // Once the end is reached, the time is read without requesting the next frame, so
+            // that a finished timeline doesn't keep the window redrawing
+            let end =
+                binop(prop("delay"), '+', binop(prop("duration"), '*', prop("iteration-count")));
+            let now = binop(tick(BuiltinFunction::CurrentTick), '-', prop("start-tick"));
+            let ended = binop(
+                binop(prop("iteration-count"), '≥', number(0.)),
+                '&',
+                binop(now.clone(), '≥', end),
+            );
+            condition(
+                prop("playing"),
+                condition(ended, now, binop(animation_tick(), '-', prop("start-tick"))),
+                prop("paused-elapsed"),
+            )
+        }
+        Some((parent, offset)) => binop(
+            Expression::PropertyReference(NamedReference::new(parent, "group-time")),
+            '-',
+            offset.clone(),
+        ),
+    };
+    set_internal_binding(timeline, "elapsed", Type::Duration, elapsed);
+    set_internal_binding(
+        timeline,
+        "active-time",
+        Type::Duration,
+        binop(prop("elapsed"), '-', prop("delay")),
+    );
+    set_internal_binding(
+        timeline,
+        "finished",
+        Type::Bool,
+        binop(
+            binop(prop("iteration-count"), '≥', number(0.)),
+            '&',
+            binop(prop("active-time"), '≥', binop(prop("duration"), '*', prop("iteration-count"))),
+        ),
+    );
+    // The number of iterations done so far, including the fractional part
+    set_internal_binding(
+        timeline,
+        "position",
+        Type::Float32,
+        condition(
+            prop("finished"),
+            max(prop("iteration-count"), number(0.)),
+            condition(
+                binop(prop("duration"), '>', duration(0.)),
+                binop(max(prop("active-time"), duration(0.)), '/', prop("duration")),
+                number(0.),
+            ),
+        ),
+    );
+    set_internal_binding(
+        timeline,
+        "cycle",
+        Type::Float32,
+        max(binop(ceil(prop("position")), '-', number(1.)), number(0.)),
+    );
+    let odd_cycle = binop(
+        Expression::Cast {
+            from: Box::new(Expression::FunctionCall {
+                function: Box::new(Expression::BuiltinFunctionReference(
+                    BuiltinFunction::Mod,
+                    None,
+                )),
+                arguments: vec![prop("cycle"), number(2.)],
+                source_location: None,
+            }),
+            to: Type::Float32,
+        },
+        '≥',
+        number(1.),
+    );
+    let mut flipped = binop(prop("alternate"), '&', odd_cycle);
+    if parent.is_none() {
+        flipped = binop(prop("reversed"), '!', flipped);
+    }
+    let fraction = binop(prop("position"), '-', prop("cycle"));
+    set_internal_binding(
+        timeline,
+        "fraction",
+        Type::Float32,
+        condition(flipped, binop(number(1.), '-', fraction.clone()), fraction),
+    );
+    set_binding(
+        timeline,
+        "progress",
+        Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(
+                BuiltinFunction::ApplyEasingCurve,
+                None,
+            )),
+            arguments: vec![prop("easing"), prop("fraction")],
+            source_location: None,
+        },
+    );
+    let not_finished = Expression::UnaryOp { sub: Box::new(prop("finished")), op: '!' };
+    let running = match &parent {
+        None => binop(prop("playing"), '&', not_finished),
+        Some((parent, _)) => binop(
+            binop(
+                Expression::PropertyReference(NamedReference::new(parent, "running")),
+                '&',
+                binop(prop("active-time"), '≥', duration(0.)),
+            ),
+            '&',
+            not_finished,
+        ),
+    };
+    set_binding(timeline, "running", running);
+    if parent.is_some() {
+        // The time this timeline takes in its parent
+        set_internal_binding(
+            timeline,
+            "span",
+            Type::Duration,
+            binop(
+                prop("delay"),
+                '+',
+                binop(prop("duration"), '*', max(prop("iteration-count"), number(0.))),
+            ),
+        );
+    }
+
+    enclosing.optimized_elements.borrow_mut().push(timeline.clone());
+
+    if !has_children {
+        return;
+    }
+    // Rounded to the millisecond, so that the children reach exactly their end
+    let group_time = Expression::Cast {
+        from: Box::new(Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(BuiltinFunction::Round, None)),
+            arguments: vec![binop(
+                binop(prop("fraction"), '*', prop("duration")),
+                '/',
+                duration(1.),
+            )],
+            source_location: None,
+        }),
+        to: Type::Float32,
+    };
+    set_internal_binding(
+        timeline,
+        "group-time",
+        Type::Duration,
+        binop(group_time, '*', duration(1.)),
+    );
+
+    let sequential = match timeline.borrow().bindings.get("sequential") {
+        None => false,
+        Some(b) => match &b.borrow().expression {
+            Expression::BoolLiteral(b) => *b,
+            _ => {
+                diag.push_error(
+                    "The 'sequential' property of a Timeline must be set to true or false".into(),
+                    &*b.borrow(),
+                );
+                false
+            }
+        },
+    };
+
+    let mut offset = duration(0.);
+    let mut total_span: Option<Expression> = None;
+    for child in children {
+        if !is_timeline(&child) {
+            diag.push_error("Only Timeline can be children of a Timeline".into(), &*child.borrow());
+            continue;
+        }
+        lower_timeline(&child, Some((timeline, offset.clone())), enclosing, diag);
+        let child_span = Expression::PropertyReference(NamedReference::new(&child, "span"));
+        if sequential {
+            offset = binop(offset, '+', child_span.clone());
+        }
+        total_span = Some(match total_span {
+            None => child_span,
+            Some(total) if sequential => binop(total, '+', child_span),
+            Some(total) => max(total, child_span),
+        });
+    }
+
+    // The duration of a group is, by default, the time needed by all its children
+    if let Some(total_span) = total_span {
+        timeline
+            .borrow_mut()
+            .bindings
+            .entry("duration".into())
+            .or_insert_with(|| RefCell::new(total_span.into()));
+    }
+}
+
+fn declare(elem: &ElementRc, name: &str, ty: Type) {
+    elem.borrow_mut().property_declarations.insert(name.into(), ty.into());
+}
+
+fn set_binding(elem: &ElementRc, name: &str, expr: Expression) {
+    elem.borrow_mut().bindings.insert(name.into(), RefCell::new(expr.into()));
+}
+
+/// Declare an internal property of the timeline with the given binding
+fn set_internal_binding(elem: &ElementRc, name: &str, ty: Type, expr: Expression) {
+    declare(elem, name, ty);
+    set_binding(elem, name, expr);
+}
+
+fn animation_tick() -> Expression {
+    tick(BuiltinFunction::AnimationTick)
+}
+
+fn tick(function: BuiltinFunction) -> Expression {
+    Expression::FunctionCall {
+        function: Box::new(Expression::BuiltinFunctionReference(function, None)),
+        arguments: vec![],
+        source_location: None,
+    }
+}
+
+fn ceil(value: Expression) -> Expression {
+    Expression::Cast {
+        from: Box::new(Expression::FunctionCall {
+            function: Box::new(Expression::BuiltinFunctionReference(BuiltinFunction::Ceil, None)),
+            arguments: vec![value],
+            source_location: None,
+        }),
+        to: Type::Float32,
+    }
+}
+
+fn min(lhs: Expression, rhs: Expression) -> Expression {
+    crate::builtin_macros::min_max_expression(lhs, rhs, MinMaxOp::Min)
+}
+
+fn max(lhs: Expression, rhs: Expression) -> Expression {
+    crate::builtin_macros::min_max_expression(lhs, rhs, MinMaxOp::Max)
+}
+
+fn binop(lhs: Expression, op: char, rhs: Expression) -> Expression {
+    Expression::BinaryExpression { lhs: Box::new(lhs), rhs: Box::new(rhs), op }
+}
+
+fn condition(condition: Expression, true_expr: Expression, false_expr: Expression) -> Expression {
+    Expression::Condition {
+        condition: Box::new(condition),
+        true_expr: Box::new(true_expr),
+        false_expr: Box::new(false_expr),
+    }
+}
+
+fn number(value: f64) -> Expression {
+    Expression::NumberLiteral(value, Unit::None)
+}
+
+fn duration(ms: f64) -> Expression {
+    Expression::NumberLiteral(ms, Unit::Ms)
+}
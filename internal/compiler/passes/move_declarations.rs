@@ -6,7 +6,7 @@
 use crate::expression_tree::NamedReference;
 use crate::object_tree::*;
 
-use crate::langtype::ElementType;
+use crate::langtype::{ElementType, Type};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -153,17 +153,21 @@ fn simplify_optimized_items(items: &[ElementRc]) {
             let base = core::mem::take(&mut elem.borrow_mut().base_type);
             if let ElementType::Builtin(c) = base {
                 // This assume that all properties of builtin items are fine with the default value
-                elem.borrow_mut().property_declarations.extend(c.properties.iter().map(
-                    |(k, v)| {
-                        (
-                            k.clone(),
-                            PropertyDeclaration {
-                                property_type: v.ty.clone(),
-                                ..Default::default()
-                            },
-                        )
-                    },
-                ));
+                // Member functions are lowered by the pass that optimized the item
+                elem.borrow_mut().property_declarations.extend(
+                    c.properties
+                        .iter()
+                        .filter(|(_, v)| !matches!(v.ty, Type::Function { .. }))
+                        .map(|(k, v)| {
+                            (
+                                k.clone(),
+                                PropertyDeclaration {
+                                    property_type: v.ty.clone(),
+                                    ..Default::default()
+                                },
+                            )
+                        }),
+                );
             } else {
                 unreachable!("Only builtin items should be optimized")
             }
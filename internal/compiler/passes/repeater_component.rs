@@ -58,24 +58,30 @@ fn create_repeater_components(component: &Rc<Component>) {
         });
 
         if let Some(listview) = is_listview {
-            if !comp.root_element.borrow().is_binding_set("height", false) {
+            // The size along the list defaults to the preferred size, the other one fills the list
+            let (y, height, width) = if listview.horizontal {
+                ("x", "width", "height")
+            } else {
+                ("y", "height", "width")
+            };
+            if !comp.root_element.borrow().is_binding_set(height, false) {
                 let preferred = Expression::PropertyReference(NamedReference::new(
                     &comp.root_element,
-                    "preferred-height",
+                    &format!("preferred-{height}"),
                 ));
                 comp.root_element
                     .borrow_mut()
                     .bindings
-                    .insert("height".into(), RefCell::new(preferred.into()));
+                    .insert(height.into(), RefCell::new(preferred.into()));
             }
-            if !comp.root_element.borrow().is_binding_set("width", false) {
+            if !comp.root_element.borrow().is_binding_set(width, false) {
                 comp.root_element.borrow_mut().bindings.insert(
-                    "width".into(),
+                    width.into(),
                     RefCell::new(Expression::PropertyReference(listview.listview_width).into()),
                 );
             }
 
-            NamedReference::new(&comp.root_element, "y").mark_as_set();
+            NamedReference::new(&comp.root_element, y).mark_as_set();
         }
 
         let weak = Rc::downgrade(&comp);
@@ -39,6 +39,7 @@ fn resolve_expression(
             component_scope: &scope.0,
             diag,
             arguments: vec![],
+            local_variables: vec![],
             type_register,
             type_loader: Some(type_loader),
             current_token: None,
@@ -239,7 +240,12 @@ fn from_function(node: syntax_nodes::Function, ctx: &mut LookupCtx) -> Expressio
             .ArgumentDeclaration()
             .map(|x| identifier_text(&x.DeclaredIdentifier()).unwrap_or_default())
             .collect();
-        Self::from_codeblock_node(node.CodeBlock(), ctx).maybe_convert_to(
+        let Some(code_block) = node.CodeBlock() else {
+            // Functions without body are only declared in interfaces and have no binding
+            debug_assert!(ctx.diag.has_error());
+            return Expression::Invalid;
+        };
+        Self::from_codeblock_node(code_block, ctx).maybe_convert_to(
             ctx.return_type().clone(),
             &node,
             ctx.diag,
@@ -854,6 +860,33 @@ fn from_function_call_node(
                 .unwrap_or_else(|| Self::from_expression_node(n, ctx))
         });
 
+        if let Expression::MemberFunction { base, member, .. } = &function {
+            if let Expression::BuiltinMacroReference(
+                mac @ (BuiltinMacroFunction::ArrayMap | BuiltinMacroFunction::ArrayFilter),
+                _,
+            ) = &**member
+            {
+                // The only argument is the lambda: `node.Expression()` only contains the function
+                let mut lambdas = node.Lambda();
+                if let (Some(lambda), None, 1) =
+                    (lambdas.next(), lambdas.next(), node.Expression().count())
+                {
+                    return Self::from_array_map_lambda(
+                        (**base).clone(),
+                        lambda,
+                        matches!(mac, BuiltinMacroFunction::ArrayFilter),
+                        ctx,
+                    );
+                }
+            }
+        }
+        for lambda in node.Lambda() {
+            ctx.diag.push_error(
+                "Lambdas can only be passed to the map() and filter() functions of arrays".into(),
+                &lambda,
+            );
+        }
+
         let sub_expr = sub_expr.map(|n| {
             (Self::from_expression_node(n.clone(), ctx), Some(NodeOrToken::from((*n).clone())))
         });
@@ -865,6 +898,15 @@ fn from_function_call_node(
             }
             Expression::MemberFunction { base, base_node, member } => {
                 arguments.push((*base, base_node));
+                if let Expression::BuiltinMacroReference(mac, n) = *member {
+                    arguments.extend(sub_expr);
+                    return crate::builtin_macros::lower_macro(
+                        mac,
+                        n,
+                        arguments.into_iter(),
+                        ctx.diag,
+                    );
+                }
                 member
             }
             _ => Box::new(function),
@@ -908,6 +950,51 @@ fn from_function_call_node(
         }
     }
 
+    /// `array.map(item => expression)` or `array.filter(item => condition)`
+    fn from_array_map_lambda(
+        array: Expression,
+        lambda: syntax_nodes::Lambda,
+        filter: bool,
+        ctx: &mut LookupCtx,
+    ) -> Expression {
+        let element_ty = match array.ty() {
+            Type::Array(ty) => *ty,
+            Type::Invalid => return Expression::Invalid,
+            _ => {
+                ctx.diag.push_error("Expected an array".into(), &lambda);
+                return Expression::Invalid;
+            }
+        };
+        let Some(variable) = identifier_text(&lambda.DeclaredIdentifier()) else {
+            return Expression::Invalid;
+        };
+
+        ctx.local_variables.push((variable.clone(), element_ty));
+        let expression = Self::from_expression_node(lambda.Expression(), ctx);
+        ctx.local_variables.pop();
+
+        let expression = if filter {
+            expression.maybe_convert_to(Type::Bool, &lambda.Expression(), ctx.diag)
+        } else {
+            match expression.ty() {
+                Type::Void | Type::Function { .. } | Type::Callback { .. } => {
+                    ctx.diag.push_error(
+                        format!("Cannot map an array to '{}'", expression.ty()),
+                        &lambda.Expression(),
+                    );
+                    return Expression::Invalid;
+                }
+                _ => expression,
+            }
+        };
+        Expression::ArrayMap {
+            array: Box::new(array),
+            variable,
+            expression: Box::new(expression),
+            filter,
+        }
+    }
+
     fn from_member_access_node(
         node: syntax_nodes::MemberAccess,
         ctx: &mut LookupCtx,
@@ -1487,6 +1574,7 @@ fn resolve_two_way_bindings(
                             component_scope: &new_scope.0,
                             diag,
                             arguments: vec![],
+                            local_variables: vec![],
                             type_register,
                             type_loader: None,
                             current_token: Some(node.clone().into()),
@@ -9,34 +9,58 @@
 
 /// This pass make sure that the id of the elements are unique
 ///
-/// It currently does so by adding a number to the existing id
+/// It currently does so by adding a number to the existing id. The elements are numbered in each
+/// component, and the ids of the root elements, which name the generated sub-components, are also
+/// prefixed by the name of the component. So the ids don't depend on the other components, and
+/// neither does the code generated for a component.
 pub fn assign_unique_id(doc: &Document) {
-    let mut count = 0;
-    assign_unique_id_in_component(&doc.root_component, &mut count);
+    let mut component_names = HashMap::new();
+    assign_unique_id_in_component(&doc.root_component, &mut component_names);
     for c in &doc.root_component.used_types.borrow().sub_components {
-        assign_unique_id_in_component(c, &mut count);
+        assign_unique_id_in_component(c, &mut component_names);
     }
 
-    rename_globals(&doc.root_component, count);
+    rename_globals(&doc.root_component);
 }
 
-fn assign_unique_id_in_component(component: &Rc<Component>, count: &mut u32) {
+/// `component_names` counts the components with the same name, that are then told apart by a number
+fn assign_unique_id_in_component(
+    component: &Rc<Component>,
+    component_names: &mut HashMap<String, usize>,
+) {
+    let occurrences = component_names.entry(component.id.clone()).or_default();
+    *occurrences += 1;
+    let prefix = if *occurrences == 1 {
+        component.id.clone()
+    } else {
+        format!("{}-{}", component.id, occurrences)
+    };
+    let mut count = 0;
     recurse_elem_including_sub_components(component, &(), &mut |elem, _| {
-        *count += 1;
+        count += 1;
+        let is_root = elem
+            .borrow()
+            .enclosing_component
+            .upgrade()
+            .map_or(false, |c| Rc::ptr_eq(&c.root_element, elem));
         let mut elem_mut = elem.borrow_mut();
         let old_id = if !elem_mut.id.is_empty() {
             elem_mut.id.clone()
         } else {
             elem_mut.base_type.to_string().to_ascii_lowercase()
         };
-        elem_mut.id = format!("{}-{}", old_id, count);
+        elem_mut.id = if is_root {
+            format!("{}-{}-{}", prefix, old_id, count)
+        } else {
+            format!("{}-{}", old_id, count)
+        };
     });
 }
 
 /// Give globals unique name
-fn rename_globals(component: &Rc<Component>, mut count: u32) {
+fn rename_globals(component: &Rc<Component>) {
+    let mut global_names = HashMap::<String, usize>::new();
     for g in &component.used_types.borrow().globals {
-        count += 1;
         let mut root = g.root_element.borrow_mut();
         if matches!(&root.base_type, ElementType::Builtin(_)) {
             // builtin global keeps its name
@@ -44,7 +68,9 @@ fn rename_globals(component: &Rc<Component>, mut count: u32) {
         } else if let Some(s) = g.exported_global_names.borrow().first() {
             root.id = s.to_string();
         } else {
-            root.id = format!("{}-{}", g.id, count);
+            let occurrences = global_names.entry(g.id.clone()).or_default();
+            *occurrences += 1;
+            root.id = format!("{}-{}", g.id, occurrences);
         }
     }
 }
@@ -191,6 +191,7 @@ fn process_file_source(
     );
     compiler_config.enable_component_containers = true;
     compiler_config.style = Some("fluent".into());
+    compiler_config.warn_unused = path.parent().map_or(false, |p| p.ends_with("unused"));
     let compile_diagnostics = if !parse_diagnostics.has_error() {
         let (_, build_diags) = spin_on::spin_on(i_slint_compiler::compile_syntax_node(
             syntax_node.clone(),
@@ -25,6 +25,8 @@ struct LoadedDocuments {
     /// the same task will remove the entry from the map when finished, and awake all
     /// wakers.
     currently_loading: HashMap<PathBuf, Vec<std::task::Waker>>,
+    /// maps from the file name of a document to the canonical file names of the documents it imports
+    imports: HashMap<PathBuf, Vec<PathBuf>>,
 }
 
 pub enum ImportKind {
@@ -168,6 +170,12 @@ fn load_dependencies_recursively_impl<'a: 'b, 'b>(
                         .await?;
                         let mut state = state.borrow_mut();
                         let state = &mut *state;
+                        if let Some(importer) = import.import_uri_token.source_file() {
+                            let imports = state.tl.all_documents.imports.entry(importer.path().to_owned()).or_default();
+                            if !imports.contains(&doc_path) {
+                                imports.push(doc_path.clone());
+                            }
+                        }
                         let doc = state.tl.all_documents.docs.get(&doc_path).unwrap();
                         match &import.import_kind {
                             ImportKind::ImportList(imported_types) => {
@@ -384,6 +392,7 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
                     &path_canon,
                     source,
                     builtin.is_some(),
+                    false,
                     &import_stack,
                 )
                 .await;
@@ -428,6 +437,7 @@ async fn ensure_document_loaded<'a: 'b, 'b>(
     }
 
     /// Load a file, and its dependency not run the passes.
+    /// The file is the main document for the unused declarations warnings.
     ///
     /// the path must be the canonical path
     pub async fn load_file(
@@ -447,6 +457,7 @@ pub async fn load_file(
             source_path,
             source_code,
             is_builtin,
+            true,
             &Default::default(),
         )
         .await
@@ -459,6 +470,7 @@ async fn load_file_impl<'a>(
         source_path: &Path,
         source_code: String,
         is_builtin: bool,
+        is_main_document: bool,
         import_stack: &HashSet<PathBuf>,
     ) {
         let dependency_doc: syntax_nodes::Document =
@@ -504,7 +516,7 @@ async fn load_file_impl<'a>(
             state.diag,
             &dependency_registry,
         );
-        crate::passes::run_import_passes(&doc, state.tl, state.diag);
+        crate::passes::run_import_passes(&doc, state.tl, is_main_document, state.diag);
         state.tl.all_documents.docs.insert(path.to_owned(), doc);
     }
 
@@ -644,11 +656,24 @@ pub fn get_document<'b>(&'b self, path: &Path) -> Option<&'b object_tree::Docume
         self.all_documents.docs.get(&path)
     }
 
+    /// Forget a document that was loaded, for example because the file was removed.
+    /// Returns false if it was not loaded.
+    pub fn drop_document(&mut self, path: &Path) -> bool {
+        let path = crate::pathutils::clean_path(path);
+        self.all_documents.imports.remove(&path);
+        self.all_documents.docs.remove(&path).is_some()
+    }
+
     /// Return an iterator over all the loaded file path
     pub fn all_files(&self) -> impl Iterator<Item = &PathBuf> {
         self.all_documents.docs.keys()
     }
 
+    /// Returns an iterator over the loaded files, including the main file, and the files they import
+    pub fn all_imports(&self) -> impl Iterator<Item = (&PathBuf, &Vec<PathBuf>)> {
+        self.all_documents.imports.iter()
+    }
+
     /// Returns an iterator over all the loaded documents
     pub fn all_documents(&self) -> impl Iterator<Item = &object_tree::Document> + '_ {
         self.all_documents.docs.values()
@@ -765,6 +790,13 @@ fn test_dependency_loading() {
     assert!(!test_diags.has_error());
     assert!(!build_diagnostics.has_error());
     assert!(foreign_imports.is_empty());
+
+    let (_, main_imports) = loader
+        .all_imports()
+        .find(|(file, _)| file.ends_with("dependency_test_main.slint"))
+        .expect("the imports of the main file are recorded");
+    assert!(main_imports.iter().any(|file| file.ends_with("dependency_local.slint")));
+    assert!(main_imports.iter().any(|file| file.ends_with("dependency_from_incpath.slint")));
 }
 
 #[test]
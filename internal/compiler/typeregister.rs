@@ -330,6 +330,31 @@ struct $Name:ident {
 
                 popup.properties.get_mut("close-on-click").unwrap().property_visibility =
                     PropertyVisibility::Constexpr;
+                popup.properties.get_mut("modal").unwrap().property_visibility =
+                    PropertyVisibility::Constexpr;
+            }
+
+            _ => unreachable!(),
+        };
+
+        match &mut register.elements.get_mut("ChildWindow").unwrap() {
+            ElementType::Builtin(ref mut b) => {
+                let child_window = Rc::get_mut(b).unwrap();
+                child_window.properties.insert(
+                    "show".into(),
+                    BuiltinPropertyInfo::new(BuiltinFunction::ShowChildWindow.ty()),
+                );
+                child_window
+                    .member_functions
+                    .insert("show".into(), BuiltinFunction::ShowChildWindow);
+
+                child_window.properties.insert(
+                    "close".into(),
+                    BuiltinPropertyInfo::new(BuiltinFunction::CloseChildWindow.ty()),
+                );
+                child_window
+                    .member_functions
+                    .insert("close".into(), BuiltinFunction::CloseChildWindow);
             }
 
             _ => unreachable!(),
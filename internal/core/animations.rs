@@ -159,6 +159,10 @@ pub enum EasingCurve {
     EaseOutBounce,
     /// Easing curve as defined at: <https://easings.net/#easeInOutBounce>
     EaseInOutBounce,
+    /// A spring simulated as a damped harmonic oscillator, with its stiffness, damping and mass.
+    /// When used in a property animation, the duration is ignored and the animation runs until
+    /// the spring comes to rest.
+    Spring([f32; 3]),
     // Custom(Box<dyn Fn(f32) -> f32>),
 }
 
@@ -377,7 +381,70 @@ pub fn easing_curve(curve: &EasingCurve, value: f32) -> f32 {
                 (1.0 + ease_out_bounce_curve(2.0 * value - 1.0)) / 2.0
             }
         }
+        EasingCurve::Spring(params) => {
+            if value >= 1.0 {
+                1.0
+            } else {
+                spring_curve(params, 0.0, value * spring_settling_time(params)).0
+            }
+        }
+    }
+}
+
+/// Returns the position and the velocity (per second) of a spring released at position 0 towards
+/// position 1 with the given initial velocity, after `time` seconds.
+/// `params` are the stiffness, damping and mass of the spring.
+pub fn spring_curve(params: &[f32; 3], initial_velocity: f32, time: f32) -> (f32, f32) {
+    let [stiffness, damping, mass] = *params;
+    if stiffness <= 0.0 || mass <= 0.0 {
+        return (1.0, 0.0);
+    }
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping.max(0.0) / (2.0 * (stiffness * mass).sqrt());
+    // displacement from the rest position
+    let x0 = -1.0;
+    let v0 = initial_velocity;
+
+    let (x, v) = if (zeta - 1.0).abs() < 1e-3 {
+        // critically damped
+        let c = v0 + omega * x0;
+        let e = (-omega * time).exp();
+        ((x0 + c * time) * e, (v0 - omega * c * time) * e)
+    } else if zeta < 1.0 {
+        // under-damped: oscillates around the rest position
+        let decay = zeta * omega;
+        let omega_d = omega * (1.0 - zeta * zeta).sqrt();
+        let b = (v0 + decay * x0) / omega_d;
+        let e = (-decay * time).exp();
+        let (sin, cos) = (omega_d * time).sin_cos();
+        ((x0 * cos + b * sin) * e, (v0 * cos - (decay * b + x0 * omega_d) * sin) * e)
+    } else {
+        // over-damped
+        let root = (zeta * zeta - 1.0).sqrt();
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        let c2 = (v0 - r1 * x0) / (r2 - r1);
+        let c1 = x0 - c2;
+        let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+        (c1 * e1 + c2 * e2, r1 * c1 * e1 + r2 * c2 * e2)
+    };
+    (1.0 + x, v)
+}
+
+/// Returns the time, in seconds, after which the amplitude of the spring oscillation
+/// has decayed to a negligible value. This is used to map the spring to a finite duration
+/// when it is used as a plain easing curve.
+fn spring_settling_time(params: &[f32; 3]) -> f32 {
+    let [stiffness, damping, mass] = *params;
+    if stiffness <= 0.0 || mass <= 0.0 {
+        return 0.0;
     }
+    let omega = (stiffness / mass).sqrt();
+    let zeta = damping.max(0.0) / (2.0 * (stiffness * mass).sqrt());
+    let decay_rate =
+        if zeta < 1.0 { zeta * omega } else { omega * (zeta - (zeta * zeta - 1.0).sqrt()) };
+    // e^(-7) is less than 0.1%
+    (7.0 / decay_rate).min(60.0)
 }
 
 /*
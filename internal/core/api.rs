@@ -451,6 +451,49 @@ pub fn set_fullscreen(&self, fullscreen: bool) {
         self.0.set_fullscreen(fullscreen);
     }
 
+    /// Returns true if the window is minimized.
+    ///
+    /// This is the value of the `minimized` property of the root `Window` element.
+    pub fn is_minimized(&self) -> bool {
+        self.0.is_minimized()
+    }
+
+    /// Minimize the window, or restore it from the minimized state.
+    pub fn set_minimized(&self, minimized: bool) {
+        self.0.set_minimized(minimized);
+    }
+
+    /// Returns true if the window is maximized.
+    ///
+    /// This is the value of the `maximized` property of the root `Window` element.
+    pub fn is_maximized(&self) -> bool {
+        self.0.is_maximized()
+    }
+
+    /// Maximize the window, or restore it from the maximized state.
+    pub fn set_maximized(&self, maximized: bool) {
+        self.0.set_maximized(maximized);
+    }
+
+    /// Sets the minimum size of the window, in logical pixels. This overrides the minimum size that is
+    /// computed from the layout of the root `Window` element. Pass `None` to use the size of the layout again.
+    pub fn set_minimum_size(&self, size: Option<LogicalSize>) {
+        self.0.set_minimum_size(size);
+    }
+
+    /// Sets the maximum size of the window, in logical pixels. This overrides the maximum size that is
+    /// computed from the layout of the root `Window` element. Pass `None` to use the size of the layout again.
+    pub fn set_maximum_size(&self, size: Option<LogicalSize>) {
+        self.0.set_maximum_size(size);
+    }
+
+    /// This function allows registering a callback that's invoked when the windowing system minimized,
+    /// maximized or restored the window. Use [`Self::is_minimized()`] and [`Self::is_maximized()`]
+    /// to query the new state.
+    pub fn on_window_state_changed(&self, callback: impl FnMut() + 'static) {
+        self.0.on_window_state_changed(callback);
+    }
+
     /// Dispatch a window event to the scene.
     ///
     /// Use this when you're implementing your own backend and want to forward user input events.
@@ -530,6 +573,9 @@ pub fn dispatch_event(&self, event: crate::platform::WindowEvent) {
                 }
             }
             crate::platform::WindowEvent::WindowActiveChanged(bool) => self.0.set_active(bool),
+            crate::platform::WindowEvent::WindowStateChanged { minimized, maximized } => {
+                self.0.set_window_state(minimized, maximized)
+            }
         }
     }
 
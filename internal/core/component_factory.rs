@@ -6,8 +6,10 @@
 //! This module defines a `ComponentFactory` and related code.
 use crate::api::ComponentHandle;
 use crate::item_tree::{ItemTreeRc, ItemTreeVTable, ItemTreeWeak};
+use crate::SharedString;
 use alloc::boxed::Box;
 use alloc::rc::Rc;
+use alloc::vec::Vec;
 use core::fmt::Debug;
 
 /// The `FactoryContext` provides extra information to the ComponentFactory
@@ -20,11 +22,15 @@ pub struct FactoryContext {
 }
 
 #[derive(Clone)]
-struct ComponentFactoryInner(Rc<dyn Fn(FactoryContext) -> Option<ItemTreeRc> + 'static>);
+struct ComponentFactoryInner(
+    Rc<dyn Fn(FactoryContext) -> Option<ItemTreeRc> + 'static>,
+    /// The signatures of the public properties, callbacks, and functions of the produced components
+    Rc<[SharedString]>,
+);
 
 impl PartialEq for ComponentFactoryInner {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.0, &other.0)
+        Rc::ptr_eq(&self.0, &other.0) && self.1 == other.1
     }
 }
 
@@ -58,10 +64,34 @@ pub fn new<T: ComponentHandle + 'static>(
     {
         let factory = Box::new(factory) as Box<dyn Fn(FactoryContext) -> Option<T> + 'static>;
 
-        Self(Some(ComponentFactoryInner(Rc::new(move |ctx| -> Option<ItemTreeRc> {
-            let product = (factory)(ctx);
-            product.map(|p| vtable::VRc::into_dyn(p.as_weak().inner().upgrade().unwrap()))
-        }))))
+        Self(Some(ComponentFactoryInner(
+            Rc::new(move |ctx| -> Option<ItemTreeRc> {
+                let product = (factory)(ctx);
+                product.map(|p| vtable::VRc::into_dyn(p.as_weak().inner().upgrade().unwrap()))
+            }),
+            Rc::new([]),
+        )))
+    }
+
+    /// Declare the public properties, callbacks, and functions of the components produced by this
+    /// factory, one signature per member.
+    ///
+    /// A `ComponentContainer` whose `interface` property is set only embeds components from a
+    /// factory that declares every member of that interface, with the same type. With the
+    /// interpreter, `ComponentDefinition::public_api()` returns the signature of a component.
+    #[must_use]
+    pub fn with_public_api(mut self, api: impl IntoIterator<Item = SharedString>) -> Self {
+        if let Some(inner) = self.0.as_mut() {
+            inner.1 = api.into_iter().collect::<Vec<_>>().into();
+        }
+        self
+    }
+
+    /// Returns the first member of the signature of an interface, as generated by the compiler
+    /// with one member per line, that the components produced by this factory don't declare.
+    pub(crate) fn missing_member<'a>(&self, interface: &'a str) -> Option<&'a str> {
+        let api = self.0.as_ref().map_or(&[][..], |inner| &inner.1[..]);
+        interface.lines().find(|member| !api.iter().any(|m| m.as_str() == *member))
     }
 
     /// Build a `Component`
@@ -0,0 +1,307 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+/*!
+Locale aware formatting of numbers, byte sizes, durations and dates.
+
+This module implements the functions of the `Format` namespace of the .slint language.
+Only a small table of conventions is embedded, so that it can also be used without the
+standard library. Locales that are not in that table use the English conventions.
+*/
+
+use crate::SharedString;
+use alloc::boxed::Box;
+use core::fmt::Write;
+use core::pin::Pin;
+
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+thread_local!(
+    /// The locale used for formatting. Bindings that format values depend on this property,
+    /// so they are re-evaluated when the locale changes.
+    static CURRENT_LOCALE : Pin<Box<crate::Property<SharedString>>> = Box::pin(
+        crate::Property::new_named(default_locale(), "i_slint_core::formatting::CURRENT_LOCALE")
+    )
+);
+
+/// The locale of the environment, from the `LC_ALL`, `LC_NUMERIC` or `LANG` environment variables
+fn default_locale() -> SharedString {
+    #[cfg(all(feature = "std", not(target_arch = "wasm32")))]
+    for var in ["LC_ALL", "LC_NUMERIC", "LANG"] {
+        if let Some(locale) = std::env::var(var).ok().filter(|l| !l.is_empty()) {
+            return locale.as_str().into();
+        }
+    }
+    "en".into()
+}
+
+/// Sets the locale that is used to format numbers, byte sizes, durations and dates with the
+/// functions of the `Format` namespace in .slint files.
+///
+/// The locale is a language tag such as `"en-US"`, `"de"` or `"fr_CH.UTF-8"`. By default, it is
+/// taken from the `LC_ALL`, `LC_NUMERIC` or `LANG` environment variables, or is `"en"`.
+/// The bindings that format values are updated when the locale changes.
+pub fn set_locale(locale: &str) {
+    CURRENT_LOCALE.with(|l| l.as_ref().set(locale.into()))
+}
+
+/// Returns the locale that was set with [`set_locale`]. This registers a dependency on the locale
+/// in the current binding.
+pub fn current_locale() -> SharedString {
+    CURRENT_LOCALE.with(|l| l.as_ref().get())
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum DateOrder {
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+}
+
+/// The formatting conventions of a locale
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Conventions {
+    decimal_separator: char,
+    group_separator: &'static str,
+    /// Inserted between the number and the percent sign
+    percent_separator: &'static str,
+    date_order: DateOrder,
+    date_separator: char,
+}
+
+const ENGLISH: Conventions = Conventions {
+    decimal_separator: '.',
+    group_separator: ",",
+    percent_separator: "",
+    date_order: DateOrder::MonthDayYear,
+    date_separator: '/',
+};
+
+impl Conventions {
+    fn for_locale(locale: &str) -> Self {
+        // Strip the encoding and modifier of POSIX locales, such as in "de_DE.UTF-8@euro"
+        let locale = locale.split(['.', '@']).next().unwrap_or_default();
+        let mut parts = locale.split(['-', '_']);
+        let language = parts.next().unwrap_or_default().to_ascii_lowercase();
+        let region = parts.find(|p| p.len() == 2).unwrap_or_default().to_ascii_uppercase();
+
+        let european = |group_separator, date_separator| Conventions {
+            decimal_separator: ',',
+            group_separator,
+            percent_separator: "\u{a0}",
+            date_order: DateOrder::DayMonthYear,
+            date_separator,
+        };
+        match (language.as_str(), region.as_str()) {
+            ("en", "US" | "PH" | "") => ENGLISH,
+            ("en", "CA") => {
+                Conventions { date_order: DateOrder::YearMonthDay, date_separator: '-', ..ENGLISH }
+            }
+            ("en", _) => Conventions { date_order: DateOrder::DayMonthYear, ..ENGLISH },
+            ("de", "CH") | ("it", "CH") => Conventions {
+                decimal_separator: '.',
+                group_separator: "’",
+                percent_separator: "",
+                ..european(".", '.')
+            },
+            ("de", _) => european(".", '.'),
+            ("fr", _) => european("\u{202f}", '/'),
+            ("es", _) | ("it", _) | ("pt", _) => {
+                Conventions { percent_separator: "", ..european(".", '/') }
+            }
+            ("nl", _) => Conventions { percent_separator: "", ..european(".", '-') },
+            ("da", _) | ("tr", _) => european(".", '.'),
+            ("ru", _)
+            | ("uk", _)
+            | ("pl", _)
+            | ("cs", _)
+            | ("sk", _)
+            | ("fi", _)
+            | ("nb", _)
+            | ("no", _) => european("\u{a0}", '.'),
+            ("sv", _) => {
+                Conventions { date_order: DateOrder::YearMonthDay, ..european("\u{a0}", '-') }
+            }
+            ("hu", _) => {
+                Conventions { date_order: DateOrder::YearMonthDay, ..european("\u{a0}", '.') }
+            }
+            ("ja", _) | ("zh", _) => {
+                Conventions { date_order: DateOrder::YearMonthDay, date_separator: '/', ..ENGLISH }
+            }
+            ("ko", _) => {
+                Conventions { date_order: DateOrder::YearMonthDay, date_separator: '.', ..ENGLISH }
+            }
+            _ => ENGLISH,
+        }
+    }
+
+    fn current() -> Self {
+        Self::for_locale(&current_locale())
+    }
+
+    /// Write `value` with `decimals` digits after the decimal separator, and with group separators
+    fn write_number(&self, out: &mut SharedString, value: f64, decimals: i32) {
+        if !value.is_finite() {
+            write!(out, "{value}").unwrap();
+            return;
+        }
+        let decimals = decimals.clamp(0, 20) as usize;
+        let digits = crate::format!("{:.*}", decimals, if value < 0. { -value } else { value });
+        let (integer, fraction) = digits.split_once('.').unwrap_or((&digits, ""));
+        // Don't show a sign when the value is rounded to 0
+        if value < 0. && digits.bytes().any(|b| !matches!(b, b'0' | b'.')) {
+            out.push_str("-");
+        }
+        for (i, digit) in integer.char_indices() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                out.push_str(self.group_separator);
+            }
+            out.push_str(digit.encode_utf8(&mut [0; 4]));
+        }
+        if !fraction.is_empty() {
+            out.push_str(self.decimal_separator.encode_utf8(&mut [0; 4]));
+            out.push_str(fraction);
+        }
+    }
+}
+
+/// Format `value` with `decimals` digits after the decimal separator, and with group separators
+pub fn format_number(value: f64, decimals: i32) -> SharedString {
+    let mut out = SharedString::default();
+    Conventions::current().write_number(&mut out, value, decimals);
+    out
+}
+
+/// Format `value` as a percentage, where 1 is 100%
+pub fn format_percent(value: f64, decimals: i32) -> SharedString {
+    let conventions = Conventions::current();
+    let mut out = SharedString::default();
+    conventions.write_number(&mut out, value * 100., decimals);
+    out.push_str(conventions.percent_separator);
+    out.push_str("%");
+    out
+}
+
+/// Format a number of bytes with the largest decimal unit (kB, MB, ...) for which the value is at least 1
+pub fn format_byte_size(bytes: f64, decimals: i32) -> SharedString {
+    const UNITS: [&str; 6] = ["B", "kB", "MB", "GB", "TB", "PB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while (value >= 1000. || value <= -1000.) && unit < UNITS.len() - 1 {
+        value /= 1000.;
+        unit += 1;
+    }
+    let mut out = SharedString::default();
+    Conventions::current().write_number(&mut out, value, if unit == 0 { 0 } else { decimals });
+    out.push_str(" ");
+    out.push_str(UNITS[unit]);
+    out
+}
+
+/// Format a duration in milliseconds as `h:mm:ss`, or `m:ss` when it is shorter than an hour
+pub fn format_duration(milliseconds: i64) -> SharedString {
+    let mut out = SharedString::default();
+    if milliseconds < 0 {
+        out.push_str("-");
+    }
+    let seconds = milliseconds.unsigned_abs() / 1000;
+    let (hours, minutes, seconds) = (seconds / 3600, (seconds / 60) % 60, seconds % 60);
+    if hours > 0 {
+        write!(out, "{hours}:{minutes:02}:{seconds:02}").unwrap();
+    } else {
+        write!(out, "{minutes}:{seconds:02}").unwrap();
+    }
+    out
+}
+
+/// Format a date given by its year, month (1 to 12) and day (1 to 31) in the order of the locale
+pub fn format_date(year: i32, month: i32, day: i32) -> SharedString {
+    let conventions = Conventions::current();
+    let sep = conventions.date_separator;
+    let mut out = SharedString::default();
+    match conventions.date_order {
+        DateOrder::MonthDayYear => write!(out, "{month}{sep}{day}{sep}{year}"),
+        DateOrder::DayMonthYear => write!(out, "{day:02}{sep}{month:02}{sep}{year}"),
+        DateOrder::YearMonthDay => write!(out, "{year}{sep}{month:02}{sep}{day:02}"),
+    }
+    .unwrap();
+    out
+}
+
+#[test]
+fn test_conventions() {
+    assert_eq!(Conventions::for_locale("en_US.UTF-8"), ENGLISH);
+    assert_eq!(Conventions::for_locale("C"), ENGLISH);
+    assert_eq!(Conventions::for_locale("de_DE.UTF-8@euro").decimal_separator, ',');
+    assert_eq!(Conventions::for_locale("de-CH").decimal_separator, '.');
+    assert_eq!(Conventions::for_locale("sv-Latn-SE").date_order, DateOrder::YearMonthDay);
+    assert_eq!(Conventions::for_locale("en-GB").date_order, DateOrder::DayMonthYear);
+}
+
+#[test]
+fn test_format() {
+    let number = |locale: &str, value: f64, decimals: i32| {
+        let mut out = SharedString::default();
+        Conventions::for_locale(locale).write_number(&mut out, value, decimals);
+        out
+    };
+    assert_eq!(number("en", 10. / 3., 2), "3.33");
+    assert_eq!(number("en", 1234567.891, 1), "1,234,567.9");
+    assert_eq!(number("en", -999.6, 0), "-1,000");
+    assert_eq!(number("en", -0.001, 2), "0.00");
+    assert_eq!(number("en", 123., 0), "123");
+    assert_eq!(number("de", 1234.5, 2), "1.234,50");
+    assert_eq!(number("fr", 1234.5, 1), "1\u{202f}234,5");
+
+    set_locale("en");
+    assert_eq!(format_percent(0.256, 1), "25.6%");
+    assert_eq!(format_byte_size(512., 1), "512 B");
+    assert_eq!(format_byte_size(1536000., 1), "1.5 MB");
+    assert_eq!(format_duration(62_000), "1:02");
+    assert_eq!(format_duration(3_723_000), "1:02:03");
+    assert_eq!(format_duration(-5_000), "-0:05");
+    assert_eq!(format_date(2024, 3, 7), "3/7/2024");
+    set_locale("de_DE.UTF-8");
+    assert_eq!(format_percent(0.5, 0), "50\u{a0}%");
+    assert_eq!(format_byte_size(1536000., 1), "1,5 MB");
+    assert_eq!(format_date(2024, 3, 7), "07.03.2024");
+    set_locale("sv_SE");
+    assert_eq!(format_date(2024, 3, 7), "2024-03-07");
+}
+
+#[cfg(feature = "ffi")]
+mod ffi {
+    #![allow(unsafe_code)]
+    use super::*;
+
+    #[no_mangle]
+    pub extern "C" fn slint_set_locale(locale: &SharedString) {
+        set_locale(locale)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_format_number(out: &mut SharedString, value: f64, decimals: i32) {
+        *out = format_number(value, decimals)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_format_percent(out: &mut SharedString, value: f64, decimals: i32) {
+        *out = format_percent(value, decimals)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_format_byte_size(out: &mut SharedString, bytes: f64, decimals: i32) {
+        *out = format_byte_size(bytes, decimals)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_format_duration(out: &mut SharedString, milliseconds: i64) {
+        *out = format_duration(milliseconds)
+    }
+
+    #[no_mangle]
+    pub extern "C" fn slint_format_date(out: &mut SharedString, year: i32, month: i32, day: i32) {
+        *out = format_date(year, month, day)
+    }
+}
@@ -0,0 +1,613 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+/*!
+ The element tree inspector: external tools connect to a local socket to browse the item tree of
+ the windows of the application, and to read and change the properties of its elements.
+
+ The server listens on the address in the `SLINT_INSPECTOR_ADDRESS` environment variable, or on
+ [`DEFAULT_ADDRESS`]. Each request is a JSON object on a single line, and so is its response.
+
+ Other local processes, and web pages through the browser, can connect to the address too. So the
+ first request of a connection must authenticate it with the token of the session, which is the
+ value of the `SLINT_INSPECTOR_TOKEN` environment variable, or a random token printed to the
+ standard error when the server starts. Connections that send anything else, such as an HTTP
+ request or a line longer than 1024 bytes, are closed.
+
+  * `{"request": "authenticate", "token": "..."}` authenticates the connection
+  * `{"request": "tree"}` returns the elements of all the windows
+  * `{"request": "element", "handle": "0/1"}` returns the properties of an element
+  * `{"request": "set-property", "handle": "0/1", "name": "x", "value": "10px"}` changes a property
+
+ An element handle is the index of its window followed by the index of the element among the
+ children of its parent, at each level of the tree.
+
+ The ids of the elements, the properties declared in `.slint` files and the bindings are only known
+ for the components run by the interpreter, which registers an [`ElementInfoProvider`]. For the
+ components generated by the Rust and C++ code generators, only the type, the geometry and the
+ properties of the native items are available.
+*/
+
+#![allow(clippy::result_unit_err)] // We have nothing better to report
+
+use crate::item_tree::ItemRc;
+use crate::items::ItemVTable;
+use crate::lengths::LogicalLength;
+use crate::rtti::{BuiltinItem, PropertyInfo};
+use crate::window::{WindowAdapter, WindowInner};
+use crate::{Brush, Color, SharedString};
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::rc::{Rc, Weak};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use core::convert::TryFrom;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::Arc;
+use vtable::HasStaticVTable;
+
+/// The address the inspector listens on when `SLINT_INSPECTOR_ADDRESS` is not set
+pub const DEFAULT_ADDRESS: &str = "127.0.0.1:7979";
+
+/// A property of an element, as shown in the inspector
+#[derive(Clone, Debug, Default, PartialEq, serde::Serialize)]
+pub struct InspectorProperty {
+    pub name: String,
+    /// The value, written as in a `.slint` file when possible
+    pub value: String,
+    /// Whether the value can be changed with a `set-property` request
+    pub editable: bool,
+    /// The source of the binding of the property, if it has one
+    pub binding: Option<String>,
+    /// The properties read by the binding, as `id.property`
+    pub dependencies: Vec<String>,
+}
+
+/// Provides what the item tree doesn't know about the elements, such as their ids and the bindings
+/// of their properties. The interpreter registers one for the components it runs.
+pub trait ElementInfoProvider {
+    /// The id and the type name of the element of the item, None if the item is not known
+    fn element_names(&self, item: &ItemRc) -> Option<(String, String)>;
+    /// The properties of the element that are not properties of the native item, and the
+    /// bindings of the properties of the native item
+    fn properties(&self, item: &ItemRc) -> Vec<InspectorProperty>;
+    /// Change a property that is not a property of the native item. Returns None if the provider
+    /// doesn't know the property.
+    fn set_property(&self, item: &ItemRc, name: &str, value: &str) -> Option<Result<(), String>>;
+}
+
+std::thread_local! {
+    static WINDOWS: RefCell<Vec<Weak<dyn WindowAdapter>>> = Default::default();
+    static PROVIDERS: RefCell<Vec<Box<dyn ElementInfoProvider>>> = Default::default();
+}
+
+/// Add information about the elements to the inspector
+pub fn register_element_info_provider(provider: Box<dyn ElementInfoProvider>) {
+    PROVIDERS.with(|p| p.borrow_mut().push(provider));
+}
+
+/// Make the window visible to the inspector. The server is started with the first window.
+pub(crate) fn register_window(window_adapter: &Rc<dyn WindowAdapter>) {
+    static START: std::sync::Once = std::sync::Once::new();
+    START.call_once(start_server);
+
+    WINDOWS.with(|windows| {
+        let mut windows = windows.borrow_mut();
+        windows.retain(|w| w.strong_count() > 0);
+        let weak = Rc::downgrade(window_adapter);
+        if !windows.iter().any(|w| w.ptr_eq(&weak)) {
+            windows.push(weak);
+        }
+    });
+}
+
+fn start_server() {
+    let address =
+        std::env::var("SLINT_INSPECTOR_ADDRESS").unwrap_or_else(|_| DEFAULT_ADDRESS.into());
+    let listener = match TcpListener::bind(&address) {
+        Ok(listener) => listener,
+        Err(err) => {
+            crate::debug_log!("Slint inspector: cannot listen on {address}: {err}");
+            return;
+        }
+    };
+    let token = match std::env::var("SLINT_INSPECTOR_TOKEN") {
+        Ok(token) if !token.is_empty() => token,
+        _ => {
+            let token = generate_token();
+            eprintln!("Slint inspector: listening on {address} with the token {token}");
+            token
+        }
+    };
+    let token = Arc::new(token);
+    std::thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            let token = token.clone();
+            std::thread::spawn(move || serve(stream, &token));
+        }
+    });
+}
+
+/// A random token for the session, from the random number generator of the system
+fn generate_token() -> String {
+    let mut bytes = [0u8; 16];
+    getrandom::getrandom(&mut bytes).expect("Slint inspector: cannot generate a random token");
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Whether the line is the start of an HTTP request, that a web page could send to the server
+fn is_http_request(line: &str) -> bool {
+    const METHODS: [&str; 9] =
+        ["GET", "HEAD", "POST", "PUT", "DELETE", "CONNECT", "OPTIONS", "TRACE", "PATCH"];
+    let method = line.split(' ').next().unwrap_or_default();
+    METHODS.contains(&method) || line.contains(" HTTP/")
+}
+
+/// Whether the line is an `authenticate` request with the token of the session
+fn is_authenticated(line: &str, token: &str) -> bool {
+    matches!(
+        serde_json::from_str::<Request>(line),
+        Ok(Request::Authenticate { token: t }) if constant_time_eq(t.as_bytes(), token.as_bytes())
+    )
+}
+
+/// Compare the tokens in a time that doesn't depend on where they differ, so that it cannot be
+/// guessed one character at a time
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// The longest line accepted before the connection is authenticated, which is more than enough
+/// for an `authenticate` request
+const MAX_UNAUTHENTICATED_LINE_LENGTH: u64 = 1024;
+
+/// Answer the requests of one client, until it disconnects
+fn serve(stream: TcpStream, token: &str) {
+    let Ok(mut writer) = stream.try_clone() else {
+        return;
+    };
+    let mut reader = BufReader::new(stream);
+    let mut authenticated = false;
+    loop {
+        let mut line = String::new();
+        let read = if authenticated {
+            reader.read_line(&mut line)
+        } else {
+            // Don't let a client that is not authenticated make the server buffer a huge line
+            (&mut reader).take(MAX_UNAUTHENTICATED_LINE_LENGTH).read_line(&mut line)
+        };
+        match read {
+            Ok(0) | Err(_) => return,
+            Ok(_) if !line.ends_with('\n') && !authenticated => return,
+            Ok(_) => {}
+        }
+        let line = line.trim_end_matches(['\r', '\n']).to_string();
+        if is_http_request(&line) {
+            return;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !authenticated {
+            authenticated = is_authenticated(&line, token);
+            let response = if authenticated {
+                serde_json::json!({ "ok": true })
+            } else {
+                serde_json::json!({ "error": "The connection is not authenticated" })
+            };
+            if writeln!(writer, "{response}").is_err() || !authenticated {
+                return;
+            }
+            continue;
+        }
+        // The item tree can only be accessed from the UI thread
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let handled = crate::api::invoke_from_event_loop(move || {
+            let _ = sender.send(handle_request(&line));
+        });
+        if handled.is_err() {
+            return;
+        }
+        let Ok(response) = receiver.recv() else {
+            return;
+        };
+        if writeln!(writer, "{response}").is_err() {
+            return;
+        }
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(tag = "request", rename_all = "kebab-case")]
+enum Request {
+    Authenticate { token: String },
+    Tree,
+    Element { handle: String },
+    SetProperty { handle: String, name: String, value: String },
+}
+
+/// An element of the tree, with its geometry in window coordinates
+#[derive(serde::Serialize)]
+struct ElementNode {
+    handle: String,
+    #[serde(rename = "type")]
+    type_name: String,
+    id: String,
+    x: f32,
+    y: f32,
+    width: f32,
+    height: f32,
+    children: Vec<ElementNode>,
+}
+
+fn handle_request(request: &str) -> String {
+    let response = match serde_json::from_str::<Request>(request) {
+        Ok(Request::Authenticate { .. }) => serde_json::json!({ "ok": true }),
+        Ok(Request::Tree) => serde_json::json!({ "windows": tree() }),
+        Ok(Request::Element { handle }) => match find_item(&handle) {
+            Some(item) => serde_json::json!({ "properties": properties(&item) }),
+            None => serde_json::json!({ "error": format!("No element {handle}") }),
+        },
+        Ok(Request::SetProperty { handle, name, value }) => {
+            match find_item(&handle)
+                .ok_or_else(|| format!("No element {handle}"))
+                .and_then(|item| set_property(&item, &name, &value))
+            {
+                Ok(()) => serde_json::json!({ "ok": true }),
+                Err(err) => serde_json::json!({ "error": err }),
+            }
+        }
+        Err(err) => serde_json::json!({ "error": format!("Invalid request: {err}") }),
+    };
+    response.to_string()
+}
+
+fn windows() -> Vec<Rc<dyn WindowAdapter>> {
+    WINDOWS.with(|windows| windows.borrow().iter().filter_map(|w| w.upgrade()).collect())
+}
+
+fn root_item(window_adapter: &Rc<dyn WindowAdapter>) -> Option<ItemRc> {
+    let component = WindowInner::from_pub(window_adapter.window()).try_component()?;
+    Some(ItemRc::new(component, 0))
+}
+
+fn tree() -> Vec<ElementNode> {
+    windows()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, w)| Some(element_node(&root_item(w)?, index.to_string())))
+        .collect()
+}
+
+fn element_node(item: &ItemRc, handle: String) -> ElementNode {
+    let (id, type_name) = element_names(item);
+    let geometry = item.geometry();
+    let origin = item.map_to_window(geometry.origin);
+
+    let mut children = Vec::new();
+    let mut child = item.first_child();
+    while let Some(c) = child {
+        children.push(element_node(&c, format!("{handle}/{}", children.len())));
+        child = c.next_sibling();
+    }
+
+    ElementNode {
+        handle,
+        type_name,
+        id,
+        x: origin.x as _,
+        y: origin.y as _,
+        width: geometry.width() as _,
+        height: geometry.height() as _,
+        children,
+    }
+}
+
+/// Find the item of a handle returned in the tree
+fn find_item(handle: &str) -> Option<ItemRc> {
+    let mut indices = handle.split('/').map(|i| i.parse::<usize>().ok());
+    let window = windows().get(indices.next()??)?.clone();
+    let mut item = root_item(&window)?;
+    for index in indices {
+        let mut child = item.first_child();
+        for _ in 0..index? {
+            child = child?.next_sibling();
+        }
+        item = child?;
+    }
+    Some(item)
+}
+
+fn element_names(item: &ItemRc) -> (String, String) {
+    PROVIDERS.with(|p| p.borrow().iter().find_map(|p| p.element_names(item))).unwrap_or_else(|| {
+        let type_name = native_properties(item).map_or("", |(name, _)| name);
+        (String::new(), type_name.into())
+    })
+}
+
+fn properties(item: &ItemRc) -> Vec<InspectorProperty> {
+    let mut properties = native_properties(item).map(|(_, p)| p).unwrap_or_default();
+    let extra = PROVIDERS.with(|p| {
+        p.borrow().iter().flat_map(|p| p.properties(item)).collect::<Vec<InspectorProperty>>()
+    });
+    for property in extra {
+        match properties.iter_mut().find(|p| p.name == property.name) {
+            Some(native) => {
+                native.binding = property.binding;
+                native.dependencies = property.dependencies;
+            }
+            None => properties.push(property),
+        }
+    }
+    properties
+}
+
+fn set_property(item: &ItemRc, name: &str, value: &str) -> Result<(), String> {
+    if let Some(result) = set_native_property(item, name, value) {
+        return result;
+    }
+    PROVIDERS
+        .with(|p| p.borrow().iter().find_map(|p| p.set_property(item, name, value)))
+        .unwrap_or_else(|| Err(format!("No property {name}")))
+}
+
+/// Calls `$m!` with all the native items whose properties can be inspected
+macro_rules! for_each_inspected_item {
+    ($m:ident, $($args:tt)*) => {
+        $m!(
+            $($args)*;
+            crate::items::ComponentContainer,
+            crate::items::Empty,
+            crate::items::ImageItem,
+            crate::items::ClippedImage,
+            crate::items::Text,
+            crate::items::Rectangle,
+            crate::items::BorderRectangle,
+            crate::items::TouchArea,
+            crate::items::FocusScope,
+            crate::items::Path,
+            crate::items::Flickable,
+            crate::items::WindowItem,
+            crate::items::TextInput,
+            crate::items::TimerItem,
+            crate::items::Clip,
+            crate::items::BoxShadow,
+            crate::items::Rotate,
+            crate::items::Opacity,
+            crate::items::Layer
+        )
+    };
+}
+
+macro_rules! first_of {
+    ($item:ident, $f:ident; $($ty:ty),*) => {
+        None $(.or_else(|| $f::<$ty>($item)))*
+    };
+}
+
+/// The type name and the properties of the native item
+fn native_properties(item: &ItemRc) -> Option<(&'static str, Vec<InspectorProperty>)> {
+    fn properties_of<T: BuiltinItem + HasStaticVTable<ItemVTable>>(
+        item: &ItemRc,
+    ) -> Option<(&'static str, Vec<InspectorProperty>)> {
+        let item = item.downcast::<T>()?;
+        let properties = T::properties::<InspectorValue>()
+            .into_iter()
+            .map(|(name, info)| {
+                let value = info.get(item.as_pin_ref()).unwrap_or_default();
+                InspectorProperty {
+                    name: name.into(),
+                    value: value.text,
+                    editable: value.editable,
+                    ..Default::default()
+                }
+            })
+            .collect();
+        Some((T::name(), properties))
+    }
+    for_each_inspected_item!(first_of, item, properties_of)
+}
+
+fn set_native_property(item: &ItemRc, name: &str, value: &str) -> Option<Result<(), String>> {
+    fn set_on<T: BuiltinItem + HasStaticVTable<ItemVTable>>(
+        (item, name, value): (&ItemRc, &str, &str),
+    ) -> Option<Result<(), String>> {
+        let item = item.downcast::<T>()?;
+        let (_, info) = T::properties::<InspectorValue>().into_iter().find(|(n, _)| *n == name)?;
+        Some(
+            info.set(item.as_pin_ref(), InspectorValue::editable(value.into()), None)
+                .map_err(|()| format!("Invalid value for {name}: {value}")),
+        )
+    }
+    let args = (item, name, value);
+    for_each_inspected_item!(first_of, args, set_on)
+}
+
+/// The value of a property of a native item, as text
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InspectorValue {
+    pub text: String,
+    /// Whether the text can be converted back to the value
+    pub editable: bool,
+}
+
+impl InspectorValue {
+    fn editable(text: String) -> Self {
+        Self { text, editable: true }
+    }
+    fn read_only(text: String) -> Self {
+        Self { text, editable: false }
+    }
+}
+
+macro_rules! parsed_values {
+    ($($ty:ty),*) => {$(
+        impl From<$ty> for InspectorValue {
+            fn from(value: $ty) -> Self {
+                Self::editable(value.to_string())
+            }
+        }
+        impl TryFrom<InspectorValue> for $ty {
+            type Error = ();
+            fn try_from(value: InspectorValue) -> Result<Self, ()> {
+                value.text.trim().parse().map_err(|_| ())
+            }
+        }
+    )*};
+}
+parsed_values!(bool, u32, u64, i32, i64, f32, f64);
+
+macro_rules! read_only_values {
+    ($($ty:ty => |$value:ident| $text:expr),* $(,)?) => {$(
+        impl From<$ty> for InspectorValue {
+            fn from($value: $ty) -> Self {
+                Self::read_only($text)
+            }
+        }
+        impl TryFrom<InspectorValue> for $ty {
+            type Error = ();
+            fn try_from(_: InspectorValue) -> Result<Self, ()> {
+                Err(())
+            }
+        }
+    )*};
+}
+read_only_values!(
+    () => |_value| String::new(),
+    crate::graphics::Image => |value| {
+        let size = value.size();
+        format!("image {}×{}", size.width, size.height)
+    },
+    crate::PathData => |_value| "path".into(),
+    crate::animations::EasingCurve => |value| format!("{value:?}"),
+    crate::model::StandardListViewItem => |value| value.text.to_string(),
+    crate::model::TableColumn => |value| value.title.to_string(),
+    crate::input::KeyEvent => |value| value.text.to_string(),
+    crate::graphics::Point => |value| format!("({}, {})", value.x, value.y),
+    crate::items::PointerEvent => |value| format!("{value:?}"),
+    crate::items::PointerScrollEvent => |value| format!("{value:?}"),
+    crate::component_factory::ComponentFactory => |_value| "component factory".into(),
+);
+
+impl From<SharedString> for InspectorValue {
+    fn from(value: SharedString) -> Self {
+        Self::editable(value.into())
+    }
+}
+impl TryFrom<InspectorValue> for SharedString {
+    type Error = ();
+    fn try_from(value: InspectorValue) -> Result<Self, ()> {
+        Ok(value.text.into())
+    }
+}
+
+/// Format the color as `#rrggbbaa`
+pub fn format_color(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}{:02x}", color.red(), color.green(), color.blue(), color.alpha())
+}
+
+/// Parse `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`
+pub fn parse_color(text: &str) -> Result<Color, ()> {
+    let hex = text.trim().strip_prefix('#').ok_or(())?;
+    let digits = hex
+        .chars()
+        .map(|c| c.to_digit(16).map(|d| d as u8))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(())?;
+    let channels = match digits.len() {
+        3 | 4 => digits.iter().map(|d| d * 0x11).collect::<Vec<_>>(),
+        6 | 8 => digits.chunks(2).map(|d| d[0] * 16 + d[1]).collect(),
+        _ => return Err(()),
+    };
+    let alpha = channels.get(3).copied().unwrap_or(0xff);
+    Ok(Color::from_argb_u8(alpha, channels[0], channels[1], channels[2]))
+}
+
+impl From<Color> for InspectorValue {
+    fn from(value: Color) -> Self {
+        Self::editable(format_color(value))
+    }
+}
+impl TryFrom<InspectorValue> for Color {
+    type Error = ();
+    fn try_from(value: InspectorValue) -> Result<Self, ()> {
+        parse_color(&value.text)
+    }
+}
+
+impl From<Brush> for InspectorValue {
+    fn from(value: Brush) -> Self {
+        match value {
+            Brush::SolidColor(color) => Self::editable(format_color(color)),
+            gradient => Self::read_only(format!("{gradient:?}")),
+        }
+    }
+}
+impl TryFrom<InspectorValue> for Brush {
+    type Error = ();
+    fn try_from(value: InspectorValue) -> Result<Self, ()> {
+        parse_color(&value.text).map(Brush::SolidColor)
+    }
+}
+
+impl From<LogicalLength> for InspectorValue {
+    fn from(value: LogicalLength) -> Self {
+        Self::editable(format!("{}px", value.get()))
+    }
+}
+impl TryFrom<InspectorValue> for LogicalLength {
+    type Error = ();
+    fn try_from(value: InspectorValue) -> Result<Self, ()> {
+        let text = value.text.trim();
+        let text = text.strip_suffix("px").unwrap_or(text);
+        Ok(LogicalLength::new(text.trim().parse().map_err(|_| ())?))
+    }
+}
+
+macro_rules! enum_values {
+    ($( $(#[$enum_doc:meta])* enum $Name:ident { $($body:tt)* })*) => {$(
+        impl From<crate::items::$Name> for InspectorValue {
+            fn from(value: crate::items::$Name) -> Self {
+                Self::editable(value.to_string())
+            }
+        }
+        impl TryFrom<InspectorValue> for crate::items::$Name {
+            type Error = ();
+            fn try_from(value: InspectorValue) -> Result<Self, ()> {
+                value.text.trim().parse().map_err(|_| ())
+            }
+        }
+    )*};
+}
+i_slint_common::for_each_enums!(enum_values);
+
+impl crate::rtti::ValueType for InspectorValue {}
+
+#[test]
+fn test_parse_color() {
+    assert_eq!(parse_color("#ff8000"), Ok(Color::from_argb_u8(0xff, 0xff, 0x80, 0x00)));
+    assert_eq!(parse_color("#ff800040"), Ok(Color::from_argb_u8(0x40, 0xff, 0x80, 0x00)));
+    assert_eq!(parse_color(" #f80 "), Ok(Color::from_argb_u8(0xff, 0xff, 0x88, 0x00)));
+    assert_eq!(parse_color("ff8000"), Err(()));
+    assert_eq!(parse_color("#ff80"), Ok(Color::from_argb_u8(0x00, 0xff, 0xff, 0x88)));
+    assert_eq!(format_color(parse_color("#12345678").unwrap()), "#12345678");
+}
+
+#[test]
+fn test_authentication() {
+    assert!(is_authenticated(r#"{"request": "authenticate", "token": "abc"}"#, "abc"));
+    assert!(!is_authenticated(r#"{"request": "authenticate", "token": "abd"}"#, "abc"));
+    assert!(!is_authenticated(r#"{"request": "tree"}"#, "abc"));
+    assert!(!is_authenticated(r#"{"request": "authenticate", "token": "abcd"}"#, "abc"));
+    assert!(constant_time_eq(b"abc", b"abc"));
+    assert!(!constant_time_eq(b"abc", b"abC"));
+    assert!(!constant_time_eq(b"ab", b"abc"));
+    assert!(is_http_request("POST / HTTP/1.1"));
+    assert!(is_http_request("GET /favicon.ico HTTP/1.0"));
+    assert!(!is_http_request(r#"{"request": "tree"}"#));
+    let token = generate_token();
+    assert_eq!(token.len(), 32);
+    assert_ne!(token, generate_token());
+}
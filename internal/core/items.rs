@@ -48,6 +48,8 @@
 pub use flickable::*;
 mod text;
 pub use text::*;
+mod timer;
+pub use timer::*;
 mod image;
 pub use self::image::*;
 #[cfg(feature = "std")]
@@ -1120,6 +1122,10 @@ pub struct WindowItem {
     pub title: Property<SharedString>,
     pub no_frame: Property<bool>,
     pub always_on_top: Property<bool>,
+    pub resizable: Property<bool>,
+    pub minimized: Property<bool>,
+    pub maximized: Property<bool>,
+    pub window_state_changed: Callback<VoidArg>,
     pub icon: Property<crate::graphics::Image>,
     pub default_font_family: Property<SharedString>,
     pub default_font_size: Property<LogicalLength>,
@@ -1315,6 +1321,10 @@ fn slint_get_TextVTable() -> TextVTable for Text
     fn slint_get_TextInputVTable() -> TextInputVTable for TextInput
 }
 
+declare_item_vtable! {
+    fn slint_get_TimerItemVTable() -> TimerItemVTable for TimerItem
+}
+
 declare_item_vtable! {
     fn slint_get_ImageItemVTable() -> ImageItemVTable for ImageItem
 }
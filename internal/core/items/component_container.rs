@@ -22,6 +22,7 @@
 #[cfg(feature = "rtti")]
 use crate::rtti::*;
 use crate::window::WindowAdapter;
+use crate::SharedString;
 use alloc::boxed::Box;
 use alloc::rc::Rc;
 use const_field_offset::FieldOffsets;
@@ -38,6 +39,7 @@ pub struct ComponentContainer {
     pub width: Property<LogicalLength>,
     pub height: Property<LogicalLength>,
     pub component_factory: Property<ComponentFactory>,
+    pub interface: Property<SharedString>,
     pub has_component: Property<bool>,
 
     pub cached_rendering_data: CachedRenderingData,
@@ -57,12 +59,21 @@ pub fn ensure_updated(self: Pin<&Self>) {
             .get()
             .unwrap()
             .as_ref()
-            .evaluate_if_dirty(|| self.component_factory());
+            .evaluate_if_dirty(|| (self.component_factory(), self.interface()));
 
-        let Some(factory) = factory else {
+        let Some((factory, interface)) = factory else {
             return;
         };
 
+        if let Some(member) = factory.missing_member(&interface) {
+            crate::debug_log!(
+                "ComponentContainer: the component factory does not declare '{member}' required by its interface"
+            );
+            self.has_component.set(false);
+            self.item_tree.replace(None);
+            return;
+        }
+
         let mut window = None;
         if let Some(parent) = self.my_component.get().and_then(|x| x.upgrade()) {
             vtable::VRc::borrow_pin(&parent).as_ref().window_adapter(false, &mut window);
@@ -351,22 +351,47 @@ fn mouse_released(
                 let speed = dist / (millis as f32);
 
                 let duration = 250;
-                let final_pos = ensure_in_bound(
-                    flick,
-                    (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast(),
-                    flick_rc,
-                );
-                let anim = PropertyAnimation {
-                    duration,
-                    easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
-                    ..PropertyAnimation::default()
-                };
-                (Flickable::FIELD_OFFSETS.viewport_x)
-                    .apply_pin(flick)
-                    .set_animated_value(final_pos.x_length(), anim.clone());
-                (Flickable::FIELD_OFFSETS.viewport_y)
-                    .apply_pin(flick)
-                    .set_animated_value(final_pos.y_length(), anim);
+                let target: LogicalPoint =
+                    (inner.pressed_viewport_pos.cast() + dist + speed * (duration as f32)).cast();
+                let final_pos = ensure_in_bound(flick, target, flick_rc);
+                if final_pos == target {
+                    let anim = PropertyAnimation {
+                        duration,
+                        easing: EasingCurve::CubicBezier([0.0, 0.0, 0.58, 1.0]),
+                        ..PropertyAnimation::default()
+                    };
+                    (Flickable::FIELD_OFFSETS.viewport_x)
+                        .apply_pin(flick)
+                        .set_animated_value(final_pos.x_length(), anim.clone());
+                    (Flickable::FIELD_OFFSETS.viewport_y)
+                        .apply_pin(flick)
+                        .set_animated_value(final_pos.y_length(), anim);
+                } else {
+                    // The flick hits the bounds: a critically damped spring that starts with the
+                    // velocity of the gesture snaps back to the bound
+                    let anim = PropertyAnimation {
+                        easing: EasingCurve::Spring([100., 20., 1.]),
+                        ..PropertyAnimation::default()
+                    };
+                    // Limit the velocity so that the spring overshoots by no more than about 15%
+                    // of the remaining distance before it comes back
+                    let viewport_pos = LogicalPoint::from_lengths(
+                        (Flickable::FIELD_OFFSETS.viewport_x).apply_pin(flick).get(),
+                        (Flickable::FIELD_OFFSETS.viewport_y).apply_pin(flick).get(),
+                    );
+                    let max_velocity = (final_pos - viewport_pos).cast::<f32>().abs() * 20.;
+                    let velocity = (speed * 1000.).clamp(-max_velocity, max_velocity);
+                    (Flickable::FIELD_OFFSETS.viewport_x)
+                        .apply_pin(flick)
+                        .set_animated_value_with_velocity(
+                            final_pos.x_length(),
+                            anim.clone(),
+                            velocity.x,
+                        );
+                    (Flickable::FIELD_OFFSETS.viewport_y)
+                        .apply_pin(flick)
+                        .set_animated_value_with_velocity(final_pos.y_length(), anim, velocity.y);
+                }
             }
         }
         inner.capture_events = false; // FIXME: should only be set to false once the flick animation is over
@@ -0,0 +1,219 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+/*!
+This module contains the builtin `Timer` item
+
+When adding an item or a property, it needs to be kept in sync with different place.
+Lookup the [`crate::items`] module documentation.
+*/
+use super::{Item, ItemConsts, ItemRc, ItemRef, ItemRendererRef, RenderingResult, VoidArg};
+use crate::input::{
+    FocusEvent, FocusEventResult, InputEventFilterResult, InputEventResult, KeyEvent,
+    KeyEventResult, MouseEvent,
+};
+use crate::item_rendering::CachedRenderingData;
+use crate::item_tree::ItemWeak;
+use crate::layout::{LayoutInfo, Orientation};
+use crate::lengths::LogicalSize;
+use crate::properties::{PropertyDirtyHandler, PropertyTracker};
+#[cfg(feature = "rtti")]
+use crate::rtti::*;
+use crate::timers::{Timer, TimerMode};
+use crate::window::WindowAdapter;
+use crate::{Callback, Property};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use const_field_offset::FieldOffsets;
+use core::cell::Cell;
+use core::pin::Pin;
+use i_slint_core_macros::*;
+use once_cell::unsync::OnceCell;
+
+/// The implementation of the `Timer` element
+#[repr(C)]
+#[derive(FieldOffsets, Default, SlintElement)]
+#[pin]
+pub struct TimerItem {
+    pub interval: Property<i64>,
+    pub running: Property<bool>,
+    pub triggered: Callback<VoidArg>,
+    data: TimerItemDataBox,
+    pub cached_rendering_data: CachedRenderingData,
+}
+
+impl TimerItem {
+    /// Starts, restarts or stops the timer if `running` or `interval` changed since the last call.
+    fn update(self: Pin<&Self>, self_rc: &ItemRc) {
+        let Some(tracker) = self.data.tracker.get() else { return };
+        let Some((running, interval)) =
+            tracker.as_ref().evaluate_if_dirty(|| (self.running(), self.interval()))
+        else {
+            return;
+        };
+        if !running {
+            self.data.timer.stop();
+            self.data.interval.set(None);
+            return;
+        }
+        if self.data.interval.get() == Some(interval) && self.data.timer.running() {
+            return;
+        }
+        self.data.interval.set(Some(interval));
+        let self_weak = self_rc.downgrade();
+        self.data.timer.start(
+            TimerMode::Repeated,
+            core::time::Duration::from_millis(interval.max(0) as u64),
+            move || {
+                if let Some(self_rc) = self_weak.upgrade() {
+                    if let Some(timer) = ItemRef::downcast_pin::<TimerItem>(self_rc.borrow()) {
+                        Self::FIELD_OFFSETS.triggered.apply_pin(timer).call(&());
+                    }
+                }
+            },
+        );
+    }
+}
+
+impl Item for TimerItem {
+    fn init(self: Pin<&Self>, self_rc: &ItemRc) {
+        let handler = TimerItemTracker { self_weak: self_rc.downgrade() };
+        // The bindings are not set yet when init is called: evaluate them later.
+        handler.notify();
+        self.data
+            .tracker
+            .set(Box::pin(PropertyTracker::new_with_dirty_handler(handler)))
+            .ok()
+            .unwrap();
+    }
+
+    fn layout_info(
+        self: Pin<&Self>,
+        _orientation: Orientation,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+    ) -> LayoutInfo {
+        LayoutInfo::default()
+    }
+
+    fn input_event_filter_before_children(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventFilterResult {
+        InputEventFilterResult::ForwardAndIgnore
+    }
+
+    fn input_event(
+        self: Pin<&Self>,
+        _: MouseEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> InputEventResult {
+        InputEventResult::EventIgnored
+    }
+
+    fn key_event(
+        self: Pin<&Self>,
+        _: &KeyEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> KeyEventResult {
+        KeyEventResult::EventIgnored
+    }
+
+    fn focus_event(
+        self: Pin<&Self>,
+        _: &FocusEvent,
+        _window_adapter: &Rc<dyn WindowAdapter>,
+        _self_rc: &ItemRc,
+    ) -> FocusEventResult {
+        FocusEventResult::FocusIgnored
+    }
+
+    fn render(
+        self: Pin<&Self>,
+        _backend: &mut ItemRendererRef,
+        _self_rc: &ItemRc,
+        _size: LogicalSize,
+    ) -> RenderingResult {
+        RenderingResult::ContinueRenderingChildren
+    }
+}
+
+impl ItemConsts for TimerItem {
+    const cached_rendering_data_offset: const_field_offset::FieldOffset<
+        TimerItem,
+        CachedRenderingData,
+    > = TimerItem::FIELD_OFFSETS.cached_rendering_data.as_unpinned_projection();
+}
+
+/// Updates the timer when `running` or `interval` changed.
+///
+/// The handler is called while the properties are locked, so the update is done from a
+/// single shot timer, like for the window properties.
+struct TimerItemTracker {
+    self_weak: ItemWeak,
+}
+
+impl PropertyDirtyHandler for TimerItemTracker {
+    fn notify(&self) {
+        let self_weak = self.self_weak.clone();
+        Timer::single_shot(Default::default(), move || {
+            if let Some(self_rc) = self_weak.upgrade() {
+                if let Some(timer) = ItemRef::downcast_pin::<TimerItem>(self_rc.borrow()) {
+                    timer.update(&self_rc);
+                }
+            }
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct TimerItemData {
+    timer: Timer,
+    /// The interval the timer was started with, None if it's not running
+    interval: Cell<Option<i64>>,
+    tracker: OnceCell<Pin<Box<PropertyTracker<TimerItemTracker>>>>,
+}
+
+#[repr(C)]
+/// Wraps the internal data structure for the Timer
+pub struct TimerItemDataBox(core::ptr::NonNull<TimerItemData>);
+
+impl Default for TimerItemDataBox {
+    fn default() -> Self {
+        TimerItemDataBox(Box::leak(Box::<TimerItemData>::default()).into())
+    }
+}
+impl Drop for TimerItemDataBox {
+    fn drop(&mut self) {
+        // Safety: the self.0 was constructed from a Box::leak in TimerItemDataBox::default
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+impl core::ops::Deref for TimerItemDataBox {
+    type Target = TimerItemData;
+    fn deref(&self) -> &Self::Target {
+        // Safety: initialized in TimerItemDataBox::default
+        unsafe { self.0.as_ref() }
+    }
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to a chunk of memory big enough to
+/// hold a TimerItemDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_timer_item_data_init(data: *mut TimerItemDataBox) {
+    core::ptr::write(data, TimerItemDataBox::default());
+}
+
+/// # Safety
+/// This must be called using a non-null pointer pointing to an initialized TimerItemDataBox
+#[cfg(feature = "ffi")]
+#[no_mangle]
+pub unsafe extern "C" fn slint_timer_item_data_free(data: *mut TimerItemDataBox) {
+    core::ptr::drop_in_place(data);
+}
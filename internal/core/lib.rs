@@ -24,9 +24,12 @@
 pub mod callbacks;
 pub mod component_factory;
 pub mod context;
+pub mod formatting;
 pub mod future;
 pub mod graphics;
 pub mod input;
+#[cfg(feature = "inspector")]
+pub mod inspector;
 pub mod item_focus;
 pub mod item_rendering;
 pub mod item_tree;
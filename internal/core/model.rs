@@ -701,6 +701,8 @@ fn init(&self) {}
     ///
     /// offset_y is the `y` position where this item should be placed.
     /// it should be updated to be to the y position of the next item.
+    /// In a horizontal list, `x` and `width` are used instead of `y` and `height`, and the
+    /// other way around.
     fn listview_layout(
         self: Pin<&Self>,
         _offset_y: &mut LogicalLength,
@@ -708,6 +710,12 @@ fn listview_layout(
     ) {
     }
 
+    /// Returns the height of this item in the listview, without changing anything.
+    /// In a horizontal list, this is the width.
+    fn listview_item_height(self: Pin<&Self>) -> LogicalLength {
+        LogicalLength::zero()
+    }
+
     /// Returns what's needed to perform the layout if this ItemTrees is in a box layout
     fn box_layout_data(
         self: Pin<&Self>,
@@ -730,8 +738,19 @@ struct RepeaterInner<C: RepeatedItemTree> {
     // The remaining properties only make sense for ListView
     /// The model row (index) of the first ItemTree in the `instances` vector.
     offset: usize,
-    /// The average visible item height.
+    /// The average height of the items that were measured so far.
+    /// Used as an estimation for the height of the rows in `item_heights` that are `None`.
     cached_item_height: LogicalLength,
+    /// The height of every row of the model, as measured the last time it was laid out,
+    /// or `None` if the row was never instantiated.
+    item_heights: Vec<Option<LogicalLength>>,
+    /// The sum of all the `Some` values in `item_heights`
+    measured_height: LogicalLength,
+    /// The amount of `Some` values in `item_heights`
+    measured_count: usize,
+    /// For each row, the sum of the measured heights of the rows before it, and their amount.
+    /// Truncated when the height of a row changes, and computed again when needed.
+    measured_prefix: Vec<(LogicalLength, usize)>,
     /// The viewport_y last time the layout of the ListView was done
     previous_viewport_y: LogicalLength,
     /// the position of the item in the row `offset` (which corresponds to `instances[0]`).
@@ -745,12 +764,124 @@ fn default() -> Self {
             instances: Default::default(),
             offset: 0,
             cached_item_height: Default::default(),
+            item_heights: Default::default(),
+            measured_height: Default::default(),
+            measured_count: 0,
+            measured_prefix: Default::default(),
             previous_viewport_y: Default::default(),
             anchor_y: Default::default(),
         }
     }
 }
 
+impl<C: RepeatedItemTree> RepeaterInner<C> {
+    /// Remember the height of the item at the given row, and update the estimation of
+    /// the height of the rows that were not measured yet.
+    fn set_item_height(&mut self, row: usize, height: LogicalLength) {
+        if row >= self.item_heights.len() {
+            self.item_heights.resize(row + 1, None);
+        }
+        match self.item_heights[row].replace(height) {
+            Some(old) if old == height => return,
+            Some(old) => self.measured_height -= old,
+            None => self.measured_count += 1,
+        }
+        self.measured_height += height;
+        self.cached_item_height = self.measured_height / self.measured_count as Coord;
+        self.measured_prefix.truncate(row + 1);
+    }
+
+    /// Insert `count` rows with an unknown height at the given row
+    fn insert_item_heights(&mut self, row: usize, count: usize) {
+        // item_heights is only populated for the ListView
+        if !self.item_heights.is_empty() && row <= self.item_heights.len() {
+            self.item_heights.splice(row..row, core::iter::repeat(None).take(count));
+            self.measured_prefix.truncate(row + 1);
+        }
+    }
+
+    /// Forget the height of the rows in the range
+    fn remove_item_heights(&mut self, row: usize, count: usize) {
+        let end = (row + count).min(self.item_heights.len());
+        if row >= end {
+            return;
+        }
+        for h in self.item_heights.drain(row..end).flatten() {
+            self.measured_height -= h;
+            self.measured_count -= 1;
+        }
+        if self.measured_count == 0 {
+            self.measured_height = LogicalLength::zero();
+        }
+        self.measured_prefix.truncate(row + 1);
+    }
+
+    /// Make `item_heights` cover the `row_count` rows of the model
+    fn resize_item_heights(&mut self, row_count: usize) {
+        let len = self.item_heights.len();
+        if len > row_count {
+            self.remove_item_heights(row_count, len - row_count);
+        } else {
+            self.item_heights.resize(row_count, None);
+        }
+    }
+
+    fn clear_item_heights(&mut self) {
+        self.item_heights.clear();
+        self.measured_height = LogicalLength::zero();
+        self.measured_count = 0;
+        self.measured_prefix.clear();
+    }
+
+    /// The measured height of the row, or the estimation if it was never measured
+    fn item_height(&self, row: usize) -> LogicalLength {
+        self.item_heights.get(row).copied().flatten().unwrap_or(self.cached_item_height)
+    }
+
+    /// The sum of the measured heights of the rows before `row`, and their amount
+    fn measured_before(&mut self, row: usize) -> (LogicalLength, usize) {
+        if self.measured_prefix.is_empty() {
+            self.measured_prefix.push((LogicalLength::zero(), 0));
+        }
+        while self.measured_prefix.len() <= row {
+            let i = self.measured_prefix.len() - 1;
+            let (sum, count) = self.measured_prefix[i];
+            self.measured_prefix.push(match self.item_heights.get(i).copied().flatten() {
+                Some(h) => (sum + h, count + 1),
+                None => (sum, count),
+            });
+        }
+        self.measured_prefix[row]
+    }
+
+    /// The y position of the given row in the viewport
+    fn item_y(&mut self, row: usize) -> LogicalLength {
+        let (sum, count) = self.measured_before(row);
+        sum + self.cached_item_height * (row - count) as Coord
+    }
+
+    /// Returns the row that is at the position `y` in the viewport, as well as the y position
+    /// of that row.
+    fn item_at_y(&mut self, y: LogicalLength, row_count: usize) -> (usize, LogicalLength) {
+        // Binary search of the last row that starts before y
+        let (mut first, mut last) = (0, row_count.saturating_sub(1));
+        while first < last {
+            let middle = (first + last + 1) / 2;
+            if self.item_y(middle) <= y {
+                first = middle;
+            } else {
+                last = middle - 1;
+            }
+        }
+        (first, self.item_y(first))
+    }
+}
+
+/// The size of the item along the axis of the list
+fn listview_item_size<C: RepeatedItemTree>(item: &ItemTreeRc<C>) -> LogicalLength {
+    item.as_pin_ref().listview_item_height()
+}
+
 /// This struct is put in a component when using the `for` syntax
 /// It helps instantiating the ItemTree `T`
 #[pin_project]
@@ -783,17 +914,23 @@ fn row_changed(self: Pin<&Self>, row: usize) {
         }
     }
     /// Notify the peers that rows were added
-    fn row_added(self: Pin<&Self>, mut index: usize, mut count: usize) {
+    fn row_added(self: Pin<&Self>, index: usize, count: usize) {
         let mut inner = self.inner.borrow_mut();
+        inner.insert_item_heights(index, count);
         if index < inner.offset {
-            if index + count < inner.offset {
-                return;
+            // The rows were inserted before the instantiated items. Shift the offset so that
+            // the same items stay visible, only their index changes.
+            inner.offset += count;
+            if !inner.instances.is_empty() {
+                self.is_dirty.set(true);
+                for c in inner.instances.iter_mut() {
+                    // Because all the indexes are dirty
+                    c.0 = RepeatedInstanceState::Dirty;
+                }
             }
-            count -= inner.offset - index;
-            index = 0;
-        } else {
-            index -= inner.offset;
+            return;
         }
+        let index = index - inner.offset;
         if count == 0 || index > inner.instances.len() {
             return;
         }
@@ -810,12 +947,20 @@ fn row_added(self: Pin<&Self>, mut index: usize, mut count: usize) {
     /// Notify the peers that rows were removed
     fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
         let mut inner = self.inner.borrow_mut();
+        inner.remove_item_heights(index, count);
         if index < inner.offset {
-            if index + count < inner.offset {
-                return;
-            }
-            count -= inner.offset - index;
+            // The rows before the instantiated items are gone, shift the offset accordingly
+            let removed_before = count.min(inner.offset - index);
+            inner.offset -= removed_before;
+            count -= removed_before;
             index = 0;
+            if !inner.instances.is_empty() {
+                self.is_dirty.set(true);
+                for c in inner.instances.iter_mut() {
+                    // Because all the indexes are dirty
+                    c.0 = RepeatedInstanceState::Dirty;
+                }
+            }
         } else {
             index -= inner.offset;
         }
@@ -835,7 +980,9 @@ fn row_removed(self: Pin<&Self>, mut index: usize, mut count: usize) {
 
     fn reset(self: Pin<&Self>) {
         self.is_dirty.set(true);
-        self.inner.borrow_mut().instances.clear();
+        let mut inner = self.inner.borrow_mut();
+        inner.instances.clear();
+        inner.clear_item_heights();
     }
 }
 
@@ -921,6 +1068,12 @@ fn ensure_updated_impl(
     }
 
     /// Same as `Self::ensure_updated` but for a ListView
+    ///
+    /// For a horizontal list, the x position and the widths are passed instead of the
+    /// y position and the heights, and the other way around.
+    /// If `scroll_to_row` is not negative, the viewport is moved so that this row is at the
+    /// beginning of the list.
+    #[allow(clippy::too_many_arguments)]
     pub fn ensure_updated_listview(
         self: Pin<&Self>,
         init: impl Fn() -> ItemTreeRc<C>,
@@ -929,6 +1082,7 @@ pub fn ensure_updated_listview(
         viewport_y: Pin<&Property<LogicalLength>>,
         listview_width: LogicalLength,
         listview_height: Pin<&Property<LogicalLength>>,
+        scroll_to_row: i32,
     ) {
         // Query is_dirty to track model changes
         self.data().project_ref().is_dirty.get();
@@ -955,7 +1109,7 @@ pub fn ensure_updated_listview(
             let total_height = Cell::new(LogicalLength::zero());
             let count = Cell::new(0);
             let get_height_visitor = |x: &ItemTreeRc<C>| {
-                let height = x.as_pin_ref().item_geometry(0).height_length();
+                let height = listview_item_size(x);
                 count.set(count.get() + 1);
                 total_height.set(total_height.get() + height);
             };
@@ -991,18 +1145,33 @@ pub fn ensure_updated_listview(
         if inner.offset >= row_count {
             inner.offset = row_count - 1;
         }
+        if inner.item_heights.len() != row_count {
+            // The rows added or removed since are already accounted for, so this only happens
+            // for the first layout after the model was set or reset.
+            inner.resize_item_heights(row_count);
+        }
+        if inner.measured_count == 0 {
+            inner.cached_item_height = element_height;
+        }
+        if let Ok(row) = usize::try_from(scroll_to_row) {
+            // The position of the rows that were never laid out is an estimation, which is
+            // corrected by the layout below while keeping the row at the beginning.
+            vp_y = -inner.item_y(row.min(row_count - 1));
+        }
 
         let one_and_a_half_screen = listview_height * 3 as Coord / 2 as Coord;
         let first_item_y = inner.anchor_y;
-        let last_item_bottom = first_item_y + element_height * inner.instances.len() as Coord;
+        let last_item_bottom = (inner.offset..inner.offset + inner.instances.len())
+            .fold(first_item_y, |y, row| y + inner.item_height(row));
 
         let (mut new_offset, mut new_offset_y) = if first_item_y > -vp_y + one_and_a_half_screen
             || last_item_bottom + element_height < -vp_y
         {
             // We are jumping more than 1.5 screens, consider this as a random seek.
             inner.instances.clear();
-            inner.offset = ((-vp_y / element_height).get().floor() as usize).min(row_count - 1);
-            (inner.offset, -vp_y)
+            let (offset, offset_y) = inner.item_at_y(-vp_y, row_count);
+            inner.offset = offset;
+            (offset, offset_y)
         } else if vp_y < inner.previous_viewport_y {
             // we scrolled down, try to find out the new offset.
             let mut it_y = first_item_y;
@@ -1016,7 +1185,7 @@ pub fn ensure_updated_listview(
                     c.1.as_ref().unwrap().update(new_offset, model.row_data(new_offset).unwrap());
                     c.0 = RepeatedInstanceState::Clean;
                 }
-                let h = c.1.as_ref().unwrap().as_pin_ref().item_geometry(0).height_length();
+                let h = listview_item_size(c.1.as_ref().unwrap());
                 if it_y + h >= -vp_y || new_offset + 1 >= row_count {
                     break;
                 }
@@ -1035,13 +1204,9 @@ pub fn ensure_updated_listview(
             // inner.instances, if any.
             while new_offset > inner.offset && new_offset_y > -vp_y {
                 new_offset -= 1;
-                new_offset_y -= inner.instances[new_offset - inner.offset]
-                    .1
-                    .as_ref()
-                    .unwrap()
-                    .as_pin_ref()
-                    .item_geometry(0)
-                    .height_length();
+                new_offset_y -= listview_item_size(
+                    inner.instances[new_offset - inner.offset].1.as_ref().unwrap(),
+                );
             }
             // If there is still a gap, fill it with new instances before
             let mut new_instances = Vec::new();
@@ -1049,7 +1214,9 @@ pub fn ensure_updated_listview(
                 new_offset -= 1;
                 let new_instance = init();
                 new_instance.update(new_offset, model.row_data(new_offset).unwrap());
-                new_offset_y -= new_instance.as_pin_ref().item_geometry(0).height_length();
+                let h = listview_item_size(&new_instance);
+                inner.set_item_height(new_offset, h);
+                new_offset_y -= h;
                 new_instances.push(new_instance);
             }
             if !new_instances.is_empty() {
@@ -1070,10 +1237,11 @@ pub fn ensure_updated_listview(
             let mut y = new_offset_y;
             let mut idx = new_offset;
             let instances_begin = new_offset - inner.offset;
-            for c in &mut inner.instances[instances_begin..] {
+            for i in instances_begin..inner.instances.len() {
                 if idx >= row_count {
                     break;
                 }
+                let c = &mut inner.instances[i];
                 if c.0 == RepeatedInstanceState::Dirty {
                     if c.1.is_none() {
                         c.1 = Some(init());
@@ -1081,8 +1249,10 @@ pub fn ensure_updated_listview(
                     c.1.as_ref().unwrap().update(idx, model.row_data(idx).unwrap());
                     c.0 = RepeatedInstanceState::Clean;
                 }
-                if let Some(x) = c.1.as_ref() {
+                if let Some(x) = c.1.clone() {
+                    let item_y = y;
                     x.as_pin_ref().listview_layout(&mut y, viewport_width);
+                    inner.set_item_height(idx, y - item_y);
                 }
                 idx += 1;
                 if y >= -vp_y + listview_height {
@@ -1094,7 +1264,9 @@ pub fn ensure_updated_listview(
             while y < -vp_y + listview_height && idx < row_count {
                 let new_instance = init();
                 new_instance.update(idx, model.row_data(idx).unwrap());
+                let item_y = y;
                 new_instance.as_pin_ref().listview_layout(&mut y, viewport_width);
+                inner.set_item_height(idx, y - item_y);
                 inner.instances.push((RepeatedInstanceState::Clean, Some(new_instance)));
                 idx += 1;
             }
@@ -1120,10 +1292,23 @@ pub fn ensure_updated_listview(
             }
 
             // Now re-compute some coordinate such a way that the scrollbar are adjusted.
-            inner.cached_item_height = (y - new_offset_y) / inner.instances.len() as Coord;
-            inner.anchor_y = inner.cached_item_height * inner.offset as Coord;
-            viewport_height.set(inner.cached_item_height * row_count as Coord);
+            // The rows that were never instantiated are assumed to have the average height.
+            inner.anchor_y = inner.item_y(inner.offset);
+            viewport_height.set(
+                inner.measured_height
+                    + inner.cached_item_height * (row_count - inner.measured_count) as Coord,
+            );
             let new_viewport_y = -inner.anchor_y + vp_y + new_offset_y;
+            if inner.anchor_y != new_offset_y {
+                // Move the items to the anchor. The viewport moves by the same amount, so they
+                // stay at the same position on screen.
+                let mut y = inner.anchor_y;
+                for c in inner.instances.iter() {
+                    if let Some(x) = c.1.as_ref() {
+                        x.as_pin_ref().listview_layout(&mut y, viewport_width);
+                    }
+                }
+            }
             viewport_y.set(new_viewport_y);
             inner.previous_viewport_y = new_viewport_y;
             break;
@@ -346,6 +346,17 @@ pub enum WindowEvent {
     /// The backend should dispatch this event with true when the window gains focus
     /// and false when the window loses focus.
     WindowActiveChanged(bool),
+
+    /// The window was minimized, maximized or restored by the windowing system.
+    ///
+    /// The backend should dispatch this event when the state of the window changes, so that the
+    /// `minimized` and `maximized` properties of the root Window element are kept up-to-date.
+    WindowStateChanged {
+        /// True if the window is minimized.
+        minimized: bool,
+        /// True if the window is maximized.
+        maximized: bool,
+    },
 }
 
 impl WindowEvent {
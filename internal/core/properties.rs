@@ -344,6 +344,49 @@ pub fn is_currently_tracking() -> bool {
     CURRENT_BINDING.is_set() && CURRENT_BINDING.with(|x| x.is_some())
 }
 
+#[cfg(all(debug_assertions, feature = "std"))]
+std::thread_local! {
+    /// The bindings currently being evaluated, innermost last.
+    /// Only used in debug builds to report the properties involved in a binding loop.
+    static EVALUATION_STACK: RefCell<alloc::vec::Vec<*const BindingHolder>> = Default::default();
+}
+
+/// Panics with the chain of bindings that lead back to `binding`, which is currently being evaluated
+#[cfg(all(debug_assertions, feature = "std"))]
+fn report_binding_loop(binding: *const BindingHolder) -> ! {
+    let mut unnamed = false;
+    let mut describe = |b: *const BindingHolder| -> alloc::string::String {
+        // Safety: the bindings in the evaluation stack are alive while they are being evaluated
+        let name = unsafe { &(*b).debug_binding_name };
+        if !name.is_empty() {
+            return name.clone().into_owned();
+        }
+        #[cfg(slint_debug_property)]
+        {
+            let name = unsafe { &(*b).debug_name };
+            if !name.is_empty() {
+                return name.clone();
+            }
+        }
+        unnamed = true;
+        alloc::format!("{b:p}")
+    };
+    let chain = EVALUATION_STACK.with(|stack| {
+        let stack = stack.borrow();
+        let start = stack.iter().position(|b| core::ptr::eq(*b, binding)).unwrap_or(0);
+        stack[start..]
+            .iter()
+            .chain(core::iter::once(&binding))
+            .map(|b| describe(*b))
+            .collect::<alloc::vec::Vec<_>>()
+            .join(" -> ")
+    });
+    if unnamed && cfg!(not(slint_debug_property)) {
+        panic!("Binding loop detected: {chain} (compile with RUSTFLAGS='--cfg slint_debug_property' to see the names of the properties set from native code)");
+    }
+    panic!("Binding loop detected: {chain}");
+}
+
 /// This structure erase the `B` type with a vtable.
 #[repr(C)]
 struct BindingHolder<B = ()> {
@@ -360,6 +403,9 @@ struct BindingHolder<B = ()> {
     pinned: PhantomPinned,
     #[cfg(slint_debug_property)]
     pub debug_name: String,
+    /// The name of the property of the binding, set by the generated code in debug builds
+    #[cfg(all(debug_assertions, feature = "std"))]
+    debug_binding_name: alloc::borrow::Cow<'static, str>,
 
     binding: B,
 }
@@ -439,6 +485,8 @@ impl<B: BindingCallable> HasBindingVTable for B {
         pinned: PhantomPinned,
         #[cfg(slint_debug_property)]
         debug_name: Default::default(),
+        #[cfg(all(debug_assertions, feature = "std"))]
+        debug_binding_name: Default::default(),
         binding,
     };
     Box::into_raw(Box::new(holder)) as *mut BindingHolder
@@ -534,6 +582,17 @@ unsafe fn set_binding<B: BindingCallable + 'static>(
         self.set_binding_impl(binding);
     }
 
+    /// Names the current binding, for the report of binding loops
+    #[cfg(all(debug_assertions, feature = "std"))]
+    fn set_binding_debug_name(&self, name: alloc::borrow::Cow<'static, str>) {
+        self.access(|binding| {
+            if let Some(binding) = binding {
+                // Safety: the name is not structurally pinned
+                unsafe { binding.get_unchecked_mut().debug_binding_name = name }
+            }
+        })
+    }
+
     /// Implementation of Self::set_binding.
     fn set_binding_impl(&self, binding: *mut BindingHolder) {
         let previous_binding_intercepted = self.access(|b| {
@@ -583,11 +642,23 @@ fn dependencies(&self) -> *mut DependencyListHead {
     // `value` is the content of the unsafe cell and will be only dereferenced if the
     // handle is not locked. (Upholding the requirements of UnsafeCell)
     unsafe fn update<T>(&self, value: *mut T) {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        if self.lock_flag() && self.handle.get() & 0b10 == 0b10 {
+            report_binding_loop((self.handle.get() & !0b11) as *const BindingHolder);
+        }
         let remove = self.access(|binding| {
             if let Some(mut binding) = binding {
                 if binding.dirty.get() {
                     // clear all the nodes so that we can start from scratch
                     binding.dep_nodes.set(Default::default());
+                    #[cfg(all(debug_assertions, feature = "std"))]
+                    let _stack_guard = {
+                        let ptr = binding.as_ref().get_ref() as *const BindingHolder;
+                        EVALUATION_STACK.with(|stack| stack.borrow_mut().push(ptr));
+                        scopeguard::guard((), |_| {
+                            EVALUATION_STACK.with(|stack| stack.borrow_mut().pop());
+                        })
+                    };
                     let r = (binding.vtable.evaluate)(
                         binding.as_mut().get_unchecked_mut() as *mut BindingHolder,
                         value as *mut (),
@@ -911,12 +982,28 @@ pub fn set_binding(&self, binding: impl Binding<T> + 'static) {
         );
     }
 
+    /// Names the current binding of this property, so that a binding loop detected at run-time
+    /// in a debug build reports which properties are involved. Does nothing in release builds.
+    #[doc(hidden)]
+    pub fn set_binding_debug_name(&self, name: impl Into<alloc::borrow::Cow<'static, str>>) {
+        #[cfg(all(debug_assertions, feature = "std"))]
+        self.handle.set_binding_debug_name(name.into());
+        #[cfg(not(all(debug_assertions, feature = "std")))]
+        let _ = name;
+    }
+
     /// Any of the properties accessed during the last evaluation of the closure called
     /// from the last call to evaluate is potentially dirty.
     pub fn is_dirty(&self) -> bool {
         self.handle.access(|binding| binding.map_or(false, |b| b.dirty.get()))
     }
 
+    /// Returns true if the property has a binding that [`Self::set()`] would remove, that is any
+    /// binding but a two-way binding.
+    pub(crate) fn has_one_way_binding(&self) -> bool {
+        self.handle.access(|binding| binding.map_or(false, |b| !b.is_two_way_binding))
+    }
+
     /// Internal function to mark the property as dirty and notify dependencies, regardless of
     /// whether the property value has actually changed or not.
     pub fn mark_dirty(&self) {
@@ -1627,5 +1714,41 @@ fn test_nested_property_tracker_evaluate_if_dirty() {
     assert_eq!(r, 12);
 }
 
+#[test]
+#[cfg(all(debug_assertions, feature = "std"))]
+#[should_panic(expected = "Binding loop detected")]
+fn test_binding_loop_detected() {
+    let prop1 = Rc::pin(Property::new(1));
+    let prop2 = Rc::pin(Property::new(2));
+    prop1.set_binding({
+        let prop2 = prop2.clone();
+        move || prop2.as_ref().get() + 1
+    });
+    prop2.set_binding({
+        let prop1 = prop1.clone();
+        move || prop1.as_ref().get() + 1
+    });
+    prop1.as_ref().get();
+}
+
+#[test]
+#[cfg(all(debug_assertions, feature = "std"))]
+#[should_panic(expected = "Binding loop detected: prop1 -> prop2 -> prop1")]
+fn test_binding_loop_names() {
+    let prop1 = Rc::pin(Property::new(1));
+    let prop2 = Rc::pin(Property::new(2));
+    prop1.set_binding({
+        let prop2 = prop2.clone();
+        move || prop2.as_ref().get() + 1
+    });
+    prop1.set_binding_debug_name("prop1");
+    prop2.set_binding({
+        let prop1 = prop1.clone();
+        move || prop1.as_ref().get() + 1
+    });
+    prop2.set_binding_debug_name(alloc::string::String::from("prop2"));
+    prop1.as_ref().get();
+}
+
 #[cfg(feature = "ffi")]
 pub(crate) mod ffi;
@@ -141,6 +141,21 @@ unsafe fn intercept_set_binding(self: Pin<&Self>, new_binding: *mut BindingHolde
     handle.0.set_binding_impl(binding.cast());
 }
 
+/// Names the current binding of the property, to report binding loops in debug builds.
+/// `name` is a null terminated UTF-8 string.
+#[no_mangle]
+pub unsafe extern "C" fn slint_property_set_binding_debug_name(
+    handle: &PropertyHandleOpaque,
+    name: *const core::ffi::c_char,
+) {
+    #[cfg(all(debug_assertions, feature = "std"))]
+    handle.0.set_binding_debug_name(
+        core::ffi::CStr::from_ptr(name).to_string_lossy().into_owned().into(),
+    );
+    #[cfg(not(all(debug_assertions, feature = "std")))]
+    let _ = (handle, name);
+}
+
 /// Returns whether the property behind this handle is marked as dirty
 #[no_mangle]
 pub extern "C" fn slint_property_is_dirty(handle: &PropertyHandleOpaque) -> bool {
@@ -456,3 +471,15 @@ pub struct PropertyTrackerOpaque {
 pub extern "C" fn slint_animation_tick() -> u64 {
     crate::animations::animation_tick()
 }
+
+/// return the current animation tick, without requesting the next animation frame
+#[no_mangle]
+pub extern "C" fn slint_current_tick() -> u64 {
+    crate::animations::current_tick().0
+}
+
+/// map a value between 0 and 1 to another value between 0 and 1 according to the curve
+#[no_mangle]
+pub extern "C" fn slint_easing_curve(curve: &crate::animations::EasingCurve, value: f32) -> f32 {
+    crate::animations::easing_curve(curve, value)
+}
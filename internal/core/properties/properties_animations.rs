@@ -18,13 +18,40 @@ pub(super) struct PropertyValueAnimationData<T> {
     details: PropertyAnimation,
     start_time: crate::animations::Instant,
     state: AnimationState,
+    /// For spring animations: the velocity (in progress per second) at which the spring starts
+    initial_velocity: f32,
+    /// The velocity (in value units per second) that the spring had when the animation
+    /// was interrupted, to be carried over to the next animation.
+    carried_velocity: Option<f32>,
 }
 
 impl<T: InterpolatedPropertyValue + Clone> PropertyValueAnimationData<T> {
     pub fn new(from_value: T, to_value: T, details: PropertyAnimation) -> Self {
         let start_time = crate::animations::current_tick();
 
-        Self { from_value, to_value, details, start_time, state: AnimationState::Delaying }
+        Self {
+            from_value,
+            to_value,
+            details,
+            start_time,
+            state: AnimationState::Delaying,
+            initial_velocity: 0.,
+            carried_velocity: None,
+        }
+    }
+
+    /// Set the initial velocity of a spring animation, in value units per second
+    pub fn set_velocity(&mut self, velocity: f32) {
+        self.initial_velocity = self
+            .from_value
+            .distance_to(&self.to_value)
+            .filter(|distance| distance.abs() > f32::EPSILON)
+            .map_or(0., |distance| velocity / distance);
+    }
+
+    /// Returns the progress and its velocity of a spring animation at the given time
+    fn spring_state(&self, params: &[f32; 3], time_progress: u64) -> (f32, f32) {
+        crate::animations::spring_curve(params, self.initial_velocity, time_progress as f32 / 1000.)
     }
 
     pub fn compute_interpolated_value(&mut self) -> (T, bool) {
@@ -52,6 +79,17 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
                 }
             }
             AnimationState::Animating { mut current_iteration } => {
+                if let crate::animations::EasingCurve::Spring(params) = self.details.easing {
+                    // Springs ignore the duration and run until they come to rest, that is when
+                    // they are closer than 0.1% of the distance and barely moving.
+                    let (progress, velocity) = self.spring_state(&params, time_progress);
+                    if (1. - progress).abs() < 0.001 && velocity.abs() < 0.01 {
+                        self.state = AnimationState::Done;
+                        return self.compute_interpolated_value();
+                    }
+                    return (self.from_value.interpolate(&self.to_value, progress), false);
+                }
+
                 if self.details.duration <= 0 || self.details.iteration_count == 0. {
                     self.state = AnimationState::Done;
                     return self.compute_interpolated_value();
@@ -87,8 +125,17 @@ pub fn compute_interpolated_value(&mut self) -> (T, bool) {
     }
 
     fn reset(&mut self) {
+        let new_tick = crate::animations::current_tick();
+        self.carried_velocity = match (&self.state, self.details.easing) {
+            (AnimationState::Animating { .. }, crate::animations::EasingCurve::Spring(params)) => {
+                let time_progress = new_tick.duration_since(self.start_time).as_millis() as u64;
+                let (_, velocity) = self.spring_state(&params, time_progress);
+                self.from_value.distance_to(&self.to_value).map(|distance| velocity * distance)
+            }
+            _ => None,
+        };
         self.state = AnimationState::Delaying;
-        self.start_time = crate::animations::current_tick();
+        self.start_time = new_tick;
     }
 }
 
@@ -142,6 +189,9 @@ unsafe fn evaluate(self: Pin<&Self>, value: *mut ()) -> BindingResult {
                     animation_data.start_time = start_time;
                     animation_data.details = details;
                 }
+                // A spring that gets a new target keeps its momentum
+                let velocity = animation_data.carried_velocity.take().unwrap_or_default();
+                animation_data.set_velocity(velocity);
                 let (val, finished) = animation_data.compute_interpolated_value();
                 *value = val;
                 if finished {
@@ -175,24 +225,40 @@ pub trait InterpolatedPropertyValue: PartialEq + Default + 'static {
     /// easing curves it may over- or undershoot though.
     #[must_use]
     fn interpolate(&self, target_value: &Self, t: f32) -> Self;
+
+    /// Returns the signed distance between self and target_value, for types that can be
+    /// represented as a single number. Spring animations use it to carry over their velocity
+    /// when the target value changes while they are running.
+    fn distance_to(&self, _target_value: &Self) -> Option<f32> {
+        None
+    }
 }
 
 impl InterpolatedPropertyValue for f32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + t * (target_value - self)
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some(target_value - self)
+    }
 }
 
 impl InterpolatedPropertyValue for i32 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as i32
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for i64 {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         self + (t * (target_value - self) as f32).round() as Self
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some((target_value - self) as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for u8 {
@@ -200,12 +266,18 @@ fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         ((*self as f32) + (t * ((*target_value as f32) - (*self as f32)))).round().min(255.).max(0.)
             as u8
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        Some(*target_value as f32 - *self as f32)
+    }
 }
 
 impl InterpolatedPropertyValue for LogicalLength {
     fn interpolate(&self, target_value: &Self, t: f32) -> Self {
         LogicalLength::new(self.get().interpolate(&target_value.get(), t))
     }
+    fn distance_to(&self, target_value: &Self) -> Option<f32> {
+        self.get().distance_to(&target_value.get())
+    }
 }
 
 impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
@@ -216,12 +288,25 @@ impl<T: Clone + InterpolatedPropertyValue + 'static> Property<T> {
     /// If other properties have binding depending of this property, these properties will
     /// be marked as dirty.
     pub fn set_animated_value(&self, value: T, animation_data: PropertyAnimation) {
+        self.set_animated_value_with_velocity(value, animation_data, 0.)
+    }
+
+    /// Same as [`Self::set_animated_value`], but when the animation uses a spring easing curve,
+    /// the spring starts with the given velocity, in value units per second.
+    pub(crate) fn set_animated_value_with_velocity(
+        &self,
+        value: T,
+        animation_data: PropertyAnimation,
+        velocity: f32,
+    ) {
         // FIXME if the current value is a dirty binding, we must run it, but we do not have the context
-        let d = RefCell::new(properties_animations::PropertyValueAnimationData::new(
+        let mut d = properties_animations::PropertyValueAnimationData::new(
             self.get_internal(),
             value,
             animation_data,
-        ));
+        );
+        d.set_velocity(velocity);
+        let d = RefCell::new(d);
         // Safety: the BindingCallable will cast its argument to T
         unsafe {
             self.handle.set_binding(
@@ -967,4 +1052,81 @@ fn test_loop_via_binding() {
 
         assert_eq!(get_prop_value(&compo.width), 300);
     }
+
+    #[test]
+    fn test_spring_ignores_duration() {
+        let compo = Component::new_test_component();
+
+        // critically damped spring
+        let animation_details = PropertyAnimation {
+            duration: DURATION.as_millis() as _,
+            easing: crate::animations::EasingCurve::Spring([100., 20., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        compo.width.set(100);
+
+        let start_time = crate::animations::current_tick();
+
+        compo.width.set_animated_value(200, animation_details);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 100));
+        // 1 - (1 + 10 * 0.1) * e^-1 = 0.264
+        assert_eq!(get_prop_value(&compo.width), 126);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + DURATION / 5));
+        assert_eq!(get_prop_value(&compo.width), 200);
+
+        // the binding should be removed
+        compo.width.handle.access(|binding| assert!(binding.is_none()));
+    }
+
+    #[test]
+    fn test_spring_keeps_velocity_via_binding() {
+        let compo = Component::new_test_component();
+
+        let start_time = crate::animations::current_tick();
+
+        let animation_details = PropertyAnimation {
+            easing: crate::animations::EasingCurve::Spring([100., 20., 1.]),
+            ..PropertyAnimation::default()
+        };
+
+        let w = Rc::downgrade(&compo);
+        compo.width.set_animated_binding(
+            move || {
+                let compo = w.upgrade().unwrap();
+                get_prop_value(&compo.feed_property)
+            },
+            animation_details,
+        );
+
+        compo.feed_property.set(100);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        compo.feed_property.set(200);
+        assert_eq!(get_prop_value(&compo.width), 100);
+
+        let step = std::time::Duration::from_millis(100);
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step));
+        assert_eq!(get_prop_value(&compo.width), 126);
+
+        // Change the target while the spring is moving: it continues from the current value
+        // with its current velocity (about 368px/s) instead of starting from rest.
+        compo.feed_property.set(300);
+        assert_eq!(get_prop_value(&compo.width), 126);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step + step / 2));
+        // A spring starting from rest would only be at 142
+        assert_eq!(get_prop_value(&compo.width), 153);
+
+        crate::animations::CURRENT_ANIMATION_DRIVER
+            .with(|driver| driver.update_animations(start_time + step * 20));
+        assert_eq!(get_prop_value(&compo.width), 300);
+    }
 }
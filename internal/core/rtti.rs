@@ -88,6 +88,10 @@ fn set_binding(
         animation: AnimatedBindingKind,
     ) -> Result<(), ()>;
 
+    /// Names the current binding of the property, to report binding loops in debug builds.
+    /// See [`crate::Property::set_binding_debug_name`]
+    fn set_binding_debug_name(&self, _item: Pin<&Item>, _name: alloc::string::String) {}
+
     /// The offset of the property in the item.
     /// The use of this is unsafe
     fn offset(&self) -> usize;
@@ -146,6 +150,9 @@ fn set_binding(
             Ok(())
         }
     }
+    fn set_binding_debug_name(&self, item: Pin<&Item>, name: alloc::string::String) {
+        self.apply_pin(item).set_binding_debug_name(name);
+    }
     fn offset(&self) -> usize {
         self.get_byte_offset()
     }
@@ -239,6 +246,9 @@ fn set_binding_impl<T: Clone + 'static, Value: 'static>(
         }
         set_binding_impl(self.apply_pin(item), binding, animation)
     }
+    fn set_binding_debug_name(&self, item: Pin<&Item>, name: alloc::string::String) {
+        self.apply_pin(item).set_binding_debug_name(name);
+    }
     fn offset(&self) -> usize {
         self.get_byte_offset()
     }
@@ -432,6 +432,35 @@ fn test_slint_shared_string_append() {
         append("!");
         assert_eq!(s.as_str(), "Hello, world!");
     }
+
+    /// Set `out` to the uppercase version of the string `ss`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_to_uppercase(out: &mut SharedString, ss: &SharedString) {
+        *out = SharedString::from(ss.as_str().to_uppercase());
+    }
+
+    /// Set `out` to the lowercase version of the string `ss`
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_to_lowercase(out: &mut SharedString, ss: &SharedString) {
+        *out = SharedString::from(ss.as_str().to_lowercase());
+    }
+
+    /// Set `out` to the string `ss` with leading and trailing whitespace removed
+    #[no_mangle]
+    pub extern "C" fn slint_shared_string_trim(out: &mut SharedString, ss: &SharedString) {
+        *out = SharedString::from(ss.as_str().trim());
+    }
+
+    #[test]
+    fn test_slint_shared_string_case_and_trim() {
+        let mut out = SharedString::default();
+        slint_shared_string_to_uppercase(&mut out, &SharedString::from("Grüße"));
+        assert_eq!(out.as_str(), "GRÜSSE");
+        slint_shared_string_to_lowercase(&mut out, &SharedString::from("ÀB"));
+        assert_eq!(out.as_str(), "àb");
+        slint_shared_string_trim(&mut out, &SharedString::from(" \t hello \n"));
+        assert_eq!(out.as_str(), "hello");
+    }
 }
 
 #[cfg(feature = "serde")]
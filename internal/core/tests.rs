@@ -87,6 +87,46 @@ pub extern "C" fn send_keyboard_string_sequence(
     }
 }
 
+#[cfg(all(not(feature = "std"), feature = "unsafe-single-threaded"))]
+use crate::unsafe_single_threaded::thread_local;
+
+/// A function that is called with the message of a failed `assert()` from a .slint file
+pub type AssertionFailureHandler = alloc::boxed::Box<dyn Fn(&str)>;
+
+thread_local!(
+    static ASSERTION_FAILURE_HANDLER: core::cell::RefCell<Option<AssertionFailureHandler>> =
+        Default::default()
+);
+
+/// Install a handler that is called when an `assert()` or `assert-eq()` from a .slint file fails,
+/// instead of panicking. Returns the previously installed handler.
+pub fn set_assertion_failure_handler(
+    handler: Option<AssertionFailureHandler>,
+) -> Option<AssertionFailureHandler> {
+    ASSERTION_FAILURE_HANDLER.with(|h| h.replace(handler))
+}
+
+/// Called when an `assert()` or `assert-eq()` from a .slint file fails.
+/// Calls the handler installed with [`set_assertion_failure_handler`], or panics if there is none.
+pub fn assertion_failed(message: &str) {
+    let handled = ASSERTION_FAILURE_HANDLER.with(|h| match h.borrow().as_ref() {
+        Some(handler) => {
+            handler(message);
+            true
+        }
+        None => false,
+    });
+    if !handled {
+        panic!("{message}");
+    }
+}
+
+/// Same as [`assertion_failed`], for the C++ generated code
+#[no_mangle]
+pub extern "C" fn slint_assertion_failed(message: &crate::SharedString) {
+    assertion_failed(message)
+}
+
 /// implementation details for debug_log()
 #[doc(hidden)]
 pub fn debug_log_impl(args: core::fmt::Arguments) {
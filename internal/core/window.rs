@@ -166,6 +166,17 @@ fn create_popup(&self, _geometry: LogicalRect) -> Option<Rc<dyn WindowAdapter>>
         None
     }
 
+    /// Create a top-level window for a `ChildWindow` element, that is owned by this window.
+    ///
+    /// The windowing system typically keeps such a window above its owner.
+    ///
+    /// If this function return None (the default implementation), then the window is created
+    /// with [`Platform::create_window_adapter()`](crate::platform::Platform::create_window_adapter)
+    /// and isn't known to be owned by this window.
+    fn create_child_window(&self) -> Option<Rc<dyn WindowAdapter>> {
+        None
+    }
+
     /// Set the mouse cursor
     // TODO: Make the enum public and make public
     fn set_mouse_cursor(&self, _cursor: MouseCursor) {}
@@ -267,7 +278,14 @@ pub fn layout_constraints(&self) -> LayoutConstraints {
         let component = ItemTreeRc::borrow_pin(&component);
         let h = component.as_ref().layout_info(crate::layout::Orientation::Horizontal);
         let v = component.as_ref().layout_info(crate::layout::Orientation::Vertical);
-        let (min, max) = crate::layout::min_max_size_for_layout_constraints(h, v);
+        let (mut min, mut max) = crate::layout::min_max_size_for_layout_constraints(h, v);
+        // The sizes set with Window::set_minimum_size() and Window::set_maximum_size() take precedence
+        if let Some(minimum_size) = self.0.minimum_size.get() {
+            min = Some(minimum_size);
+        }
+        if let Some(maximum_size) = self.0.maximum_size.get() {
+            max = Some(maximum_size);
+        }
         LayoutConstraints {
             min,
             max,
@@ -282,6 +300,21 @@ pub fn layout_constraints(&self) -> LayoutConstraints {
     pub fn fullscreen(&self) -> bool {
         self.0.fullscreen.get()
     }
+
+    /// Returns true if the window should be minimized; false otherwise.
+    pub fn is_minimized(&self) -> bool {
+        self.0.is_minimized()
+    }
+
+    /// Returns true if the window should be maximized; false otherwise.
+    pub fn is_maximized(&self) -> bool {
+        self.0.is_maximized()
+    }
+
+    /// Returns true if the user may resize the window; false otherwise.
+    pub fn is_resizable(&self) -> bool {
+        self.0.window_item().map_or(true, |w| w.as_pin_ref().resizable())
+    }
 }
 
 struct WindowPropertiesTracker {
@@ -329,6 +362,19 @@ struct PopupWindow {
     /// If true, Slint will close the popup after any mouse click within the popup.
     /// Set to false and call close() on the PopupWindow to close it manually.
     close_on_click: bool,
+    /// If true, the rest of the window doesn't receive pointer and key events while the popup is shown.
+    modal: bool,
+}
+
+/// A top-level window shown by a `ChildWindow` element. It's owned by the window of the element:
+/// it is centered on that window when shown, and closed with it.
+struct ChildWindow {
+    /// The index of the ChildWindow element in the popup windows of the parent component.
+    index: usize,
+    /// The item that contains the ChildWindow element.
+    parent_item: crate::item_tree::ItemWeak,
+    /// The window that shows the component of the ChildWindow element.
+    window_adapter: Rc<dyn WindowAdapter>,
 }
 
 #[pin_project::pin_project]
@@ -368,9 +414,15 @@ pub struct WindowInner {
 
     pinned_fields: Pin<Box<WindowPinnedFields>>,
     fullscreen: Cell<bool>,
+    minimum_size: Cell<Option<crate::api::LogicalSize>>,
+    maximum_size: Cell<Option<crate::api::LogicalSize>>,
     active_popup: RefCell<Option<PopupWindow>>,
+    child_windows: RefCell<Vec<ChildWindow>>,
     had_popup_on_press: Cell<bool>,
     close_requested: Callback<(), CloseRequestResponse>,
+    window_state_changed: Callback<()>,
+    /// The minimized and maximized state last reported by the windowing system
+    window_state: Cell<(bool, bool)>,
     click_state: ClickState,
     pub(crate) ctx: once_cell::unsync::Lazy<crate::SlintContext>,
 }
@@ -424,12 +476,17 @@ pub fn new(window_adapter_weak: Weak<dyn WindowAdapter>) -> Self {
             fullscreen: Cell::new(std::env::var("SLINT_FULLSCREEN").is_ok()),
             #[cfg(not(feature = "std"))]
             fullscreen: Cell::new(false),
+            minimum_size: Default::default(),
+            maximum_size: Default::default(),
             focus_item: Default::default(),
             last_ime_text: Default::default(),
             cursor_blinker: Default::default(),
             active_popup: Default::default(),
+            child_windows: Default::default(),
             had_popup_on_press: Default::default(),
             close_requested: Default::default(),
+            window_state_changed: Default::default(),
+            window_state: Default::default(),
             click_state: ClickState::default(),
             prevent_focus_change: Default::default(),
             // The ctx is lazy so that a Window can be initialized before the backend.
@@ -466,6 +523,8 @@ pub fn set_component(&self, component: &ItemTreeRc) {
             window_adapter.size().to_logical(self.scale_factor()).to_euclid(),
         );
         window_adapter.request_redraw();
+        #[cfg(feature = "inspector")]
+        crate::inspector::register_window(&window_adapter);
         let weak = Rc::downgrade(&window_adapter);
         crate::timers::Timer::single_shot(Default::default(), move || {
             if let Some(window_adapter) = weak.upgrade() {
@@ -519,25 +578,32 @@ pub fn process_mouse_input(&self, mut event: MouseEvent) {
         mouse_input_state = if let Some(mut event) =
             crate::input::handle_mouse_grab(event, &window_adapter, &mut mouse_input_state)
         {
-            let (item_tree, offset) = if let Some(PopupWindow {
-                location: PopupWindowLocation::ChildWindow(coordinates),
-                component,
-                ..
-            }) = self.active_popup.borrow().as_ref()
-            {
-                let geom = ItemTreeRc::borrow_pin(component).as_ref().item_geometry(0);
-
-                mouse_inside_popup = event
-                    .position()
-                    .map_or(true, |pos| geom.contains(pos - coordinates.to_vector()));
-
-                if mouse_inside_popup {
-                    (Some(component.clone()), *coordinates)
-                } else {
-                    (None, LogicalPoint::default())
+            let (item_tree, offset) = match self.active_popup.borrow().as_ref() {
+                Some(PopupWindow {
+                    location: PopupWindowLocation::ChildWindow(coordinates),
+                    component,
+                    ..
+                }) => {
+                    let geom = ItemTreeRc::borrow_pin(component).as_ref().item_geometry(0);
+
+                    mouse_inside_popup = event
+                        .position()
+                        .map_or(true, |pos| geom.contains(pos - coordinates.to_vector()));
+
+                    if mouse_inside_popup {
+                        (Some(component.clone()), *coordinates)
+                    } else {
+                        (None, LogicalPoint::default())
+                    }
                 }
-            } else {
-                (self.component.borrow().upgrade(), LogicalPoint::default())
+                // A modal popup in its own window gets its events from that window, and the
+                // items of this window don't get any while it's shown.
+                Some(PopupWindow {
+                    location: PopupWindowLocation::TopLevel { .. },
+                    modal: true,
+                    ..
+                }) => (None, LogicalPoint::default()),
+                _ => (self.component.borrow().upgrade(), LogicalPoint::default()),
             };
 
             if let Some(item_tree) = item_tree {
@@ -607,6 +673,11 @@ pub fn process_key_input(&self, mut event: KeyEvent) {
         event.modifiers = self.modifiers.get().into();
 
         let mut item = self.focus_item.borrow().clone().upgrade();
+
+        if self.is_blocked_by_modal_popup(item.as_ref()) {
+            // A modal popup is shown: the items behind it, and the focus chain, don't see the event.
+            return;
+        }
         while let Some(focus_item) = item {
             if !focus_item.is_visible() {
                 // Reset the focus... not great, but better than keeping it.
@@ -853,6 +924,7 @@ pub fn show(&self) -> Result<(), PlatformError> {
 
     /// De-registers the window with the windowing system.
     pub fn hide(&self) -> Result<(), PlatformError> {
+        self.close_child_windows();
         let result = self.window_adapter().set_visible(false);
         let was_visible = self.strong_component_ref.borrow_mut().take().is_some();
         if was_visible {
@@ -879,6 +951,7 @@ pub fn show_popup(
         popup_componentrc: &ItemTreeRc,
         position: Point,
         close_on_click: bool,
+        modal: bool,
         parent_item: &ItemRc,
     ) {
         let position = parent_item.map_to_window(
@@ -940,6 +1013,7 @@ pub fn show_popup(
             location,
             component: popup_componentrc.clone(),
             close_on_click,
+            modal,
         }));
     }
 
@@ -964,11 +1038,107 @@ pub fn close_popup(&self) {
         }
     }
 
+    /// Returns true if the window of the `ChildWindow` element identified by `index` and `parent_item`
+    /// is shown.
+    pub fn is_child_window_shown(&self, index: usize, parent_item: &ItemRc) -> bool {
+        let parent_item = parent_item.downgrade();
+        let mut child_windows = self.child_windows.borrow_mut();
+        // Forget the windows that the user closed, so that they can be shown again.
+        child_windows.retain(|child| child.window_adapter.window().is_visible());
+        child_windows.iter().any(|child| child.index == index && child.parent_item == parent_item)
+    }
+
+    /// Shows the component of a `ChildWindow` element in a new top-level window, centered on this window.
+    ///
+    /// `index` and `parent_item` identify the ChildWindow element: nothing happens if the window of
+    /// that element is already shown.
+    pub fn show_child_window(&self, index: usize, component: &ItemTreeRc, parent_item: &ItemRc) {
+        if self.is_child_window_shown(index, parent_item) {
+            return;
+        }
+        let parent_item = parent_item.downgrade();
+
+        let parent_window_adapter = self.window_adapter();
+        let window_adapter = match parent_window_adapter
+            .internal(crate::InternalToken)
+            .and_then(|x| x.create_child_window())
+        {
+            Some(window_adapter) => window_adapter,
+            None => match self.ctx.0.platform.create_window_adapter() {
+                Ok(window_adapter) => window_adapter,
+                Err(err) => {
+                    crate::debug_log!("Could not create the window of a ChildWindow: {err}");
+                    return;
+                }
+            },
+        };
+
+        let window = WindowInner::from_pub(window_adapter.window());
+        window.set_component(component);
+        if let Err(err) = window.show() {
+            crate::debug_log!("Could not show the window of a ChildWindow: {err}");
+            return;
+        }
+
+        if let Some(parent_position) = parent_window_adapter.position() {
+            let parent_size = parent_window_adapter.size();
+            let size = window_adapter.size();
+            window_adapter.set_position(
+                PhysicalPosition::new(
+                    parent_position.x + (parent_size.width as i32 - size.width as i32) / 2,
+                    parent_position.y + (parent_size.height as i32 - size.height as i32) / 2,
+                )
+                .into(),
+            );
+        }
+
+        self.child_windows.borrow_mut().push(ChildWindow { index, parent_item, window_adapter });
+    }
+
+    /// Closes the window shown by the `ChildWindow` element identified by `index` and `parent_item`.
+    pub fn close_child_window(&self, index: usize, parent_item: &ItemRc) {
+        let parent_item = parent_item.downgrade();
+        let position = self
+            .child_windows
+            .borrow()
+            .iter()
+            .position(|child| child.index == index && child.parent_item == parent_item);
+        if let Some(position) = position {
+            let child = self.child_windows.borrow_mut().remove(position);
+            child.window_adapter.window().hide().ok();
+        }
+    }
+
+    /// Closes all the windows shown by `ChildWindow` elements of this window.
+    fn close_child_windows(&self) {
+        let child_windows = self.child_windows.take();
+        for child in child_windows {
+            child.window_adapter.window().hide().ok();
+        }
+    }
+
     /// Returns true if the currently active popup is configured to close on click. None if there is no active popup.
     pub fn close_popup_on_click(&self) -> bool {
         self.active_popup.borrow().as_ref().map_or(false, |popup| popup.close_on_click)
     }
 
+    /// Returns true if the active popup is modal and the item is not part of it.
+    /// When the popup is in its own window, none of the items of this window are part of it.
+    pub fn is_blocked_by_modal_popup(&self, item: Option<&ItemRc>) -> bool {
+        let popup = self.active_popup.borrow();
+        let Some(popup) = popup.as_ref().filter(|popup| popup.modal) else {
+            return false;
+        };
+        let mut item = item.cloned();
+        while let Some(current) = item {
+            if ItemTreeRc::ptr_eq(current.item_tree(), &popup.component) {
+                return false;
+            }
+            item = current.parent_item();
+        }
+        true
+    }
+
     /// Returns the scale factor set on the window, as provided by the windowing system.
     pub fn scale_factor(&self) -> f32 {
         self.pinned_fields.as_ref().project_ref().scale_factor.get()
@@ -1036,6 +1206,72 @@ pub fn set_fullscreen(&self, enabled: bool) {
         self.update_window_properties()
     }
 
+    /// Returns true if the root Window element is minimized.
+    pub fn is_minimized(&self) -> bool {
+        self.window_item().map_or(false, |w| w.as_pin_ref().minimized())
+    }
+
+    /// Minimizes the window, or restores it if `minimized` is false.
+    pub fn set_minimized(&self, minimized: bool) {
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().minimized.set(minimized);
+        }
+    }
+
+    /// Returns true if the root Window element is maximized.
+    pub fn is_maximized(&self) -> bool {
+        self.window_item().map_or(false, |w| w.as_pin_ref().maximized())
+    }
+
+    /// Maximizes the window, or restores it if `maximized` is false.
+    pub fn set_maximized(&self, maximized: bool) {
+        if let Some(window_item) = self.window_item() {
+            window_item.as_pin_ref().maximized.set(maximized);
+        }
+    }
+
+    /// Overrides the minimum size computed from the layout of the window. None removes the override.
+    pub fn set_minimum_size(&self, size: Option<crate::api::LogicalSize>) {
+        self.minimum_size.set(size);
+        self.update_window_properties()
+    }
+
+    /// Overrides the maximum size computed from the layout of the window. None removes the override.
+    pub fn set_maximum_size(&self, size: Option<crate::api::LogicalSize>) {
+        self.maximum_size.set(size);
+        self.update_window_properties()
+    }
+
+    /// Sets the window_state_changed callback. The callback will be run when the windowing system
+    /// minimized, maximized or restored the window.
+    pub fn on_window_state_changed(&self, mut callback: impl FnMut() + 'static) {
+        self.window_state_changed.set_handler(move |()| callback());
+    }
+
+    /// Updates the `minimized` and `maximized` properties of the window item with the state reported
+    /// by the windowing system, and invokes the `window-state-changed` callback of the window item and
+    /// the window_state_changed callback if it changed.
+    ///
+    /// A property with a binding keeps it, and so the value of the binding: Only two-way bindings
+    /// are updated.
+    pub(crate) fn set_window_state(&self, minimized: bool, maximized: bool) {
+        if self.window_state.replace((minimized, maximized)) == (minimized, maximized) {
+            return;
+        }
+        if let Some(window_item) = self.window_item() {
+            let window_item = window_item.as_pin_ref();
+            for (property, value) in
+                [(&window_item.minimized, minimized), (&window_item.maximized, maximized)]
+            {
+                if !property.has_one_way_binding() {
+                    property.set(value);
+                }
+            }
+            window_item.window_state_changed.call(&());
+        }
+        self.window_state_changed.call(&());
+    }
+
     /// Returns the upgraded window adapter
     pub fn window_adapter(&self) -> Rc<dyn WindowAdapter> {
         self.window_adapter_weak.upgrade().unwrap()
@@ -1199,6 +1435,7 @@ pub enum GraphicsAPI {
         popup: &ItemTreeRc,
         position: crate::graphics::Point,
         close_on_click: bool,
+        modal: bool,
         parent_item: &ItemRc,
     ) {
         let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
@@ -1206,6 +1443,7 @@ pub enum GraphicsAPI {
             popup,
             position,
             close_on_click,
+            modal,
             parent_item,
         );
     }
@@ -1216,6 +1454,44 @@ pub enum GraphicsAPI {
         WindowInner::from_pub(window_adapter.window()).close_popup();
     }
 
+    /// Show the component of a ChildWindow element in its own window
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_show_child_window(
+        handle: *const WindowAdapterRcOpaque,
+        index: usize,
+        component: &ItemTreeRc,
+        parent_item: &ItemRc,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).show_child_window(
+            index,
+            component,
+            parent_item,
+        );
+    }
+
+    /// Returns true if the window of a ChildWindow element is shown
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_child_window_shown(
+        handle: *const WindowAdapterRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).is_child_window_shown(index, parent_item)
+    }
+
+    /// Close the window of a ChildWindow element
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_close_child_window(
+        handle: *const WindowAdapterRcOpaque,
+        index: usize,
+        parent_item: &ItemRc,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        WindowInner::from_pub(window_adapter.window()).close_child_window(index, parent_item);
+    }
+
     /// C binding to the set_rendering_notifier() API of Window
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_set_rendering_notifier(
@@ -1301,6 +1577,72 @@ fn call(&self) -> CloseRequestResponse {
         window_adapter.window().on_close_requested(move || with_user_data.call());
     }
 
+    /// Returns true if the window is minimized
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_minimized(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_minimized()
+    }
+
+    /// Minimizes the window, or restores it from the minimized state
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_minimized(
+        handle: *const WindowAdapterRcOpaque,
+        minimized: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_minimized(minimized)
+    }
+
+    /// Returns true if the window is maximized
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_is_maximized(
+        handle: *const WindowAdapterRcOpaque,
+    ) -> bool {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().is_maximized()
+    }
+
+    /// Maximizes the window, or restores it from the maximized state
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_set_maximized(
+        handle: *const WindowAdapterRcOpaque,
+        maximized: bool,
+    ) {
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter.window().set_maximized(maximized)
+    }
+
+    /// C binding to the on_window_state_changed() API of Window
+    #[no_mangle]
+    pub unsafe extern "C" fn slint_windowrc_on_window_state_changed(
+        handle: *const WindowAdapterRcOpaque,
+        callback: extern "C" fn(user_data: *mut c_void),
+        drop_user_data: extern "C" fn(user_data: *mut c_void),
+        user_data: *mut c_void,
+    ) {
+        struct WithUserData {
+            callback: extern "C" fn(user_data: *mut c_void),
+            drop_user_data: extern "C" fn(*mut c_void),
+            user_data: *mut c_void,
+        }
+
+        impl Drop for WithUserData {
+            fn drop(&mut self) {
+                (self.drop_user_data)(self.user_data)
+            }
+        }
+
+        let with_user_data = WithUserData { callback, drop_user_data, user_data };
+
+        let window_adapter = &*(handle as *const Rc<dyn WindowAdapter>);
+        window_adapter
+            .window()
+            .on_window_state_changed(move || (with_user_data.callback)(with_user_data.user_data));
+    }
+
     /// This function issues a request to the windowing system to redraw the contents of the window.
     #[no_mangle]
     pub unsafe extern "C" fn slint_windowrc_request_redraw(handle: *const WindowAdapterRcOpaque) {
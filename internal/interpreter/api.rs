@@ -872,6 +872,18 @@ pub fn global_callbacks(&self, global_name: &str) -> Option<impl Iterator<Item =
         })
     }
 
+    /// Returns the signatures of the public properties, callbacks, and functions of this component.
+    ///
+    /// Pass them to [`ComponentFactory::with_public_api()`] so that a `ComponentContainer` whose
+    /// `interface` property is set checks that the components created from this definition
+    /// implement that interface.
+    pub fn public_api(&self) -> impl Iterator<Item = SharedString> + '_ {
+        // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
+        // which is not required, but this is safe because there is only one instance of the unerased type
+        let guard = unsafe { generativity::Guard::new(generativity::Id::new()) };
+        self.inner.unerase(guard).original.public_api_signature().into_iter().map(Into::into)
+    }
+
     /// The name of this Component as written in the .slint file
     pub fn name(&self) -> &str {
         // We create here a 'static guard, because unfortunately the returned type would be restricted to the guard lifetime
@@ -1206,6 +1218,15 @@ pub fn element_position(
     ) -> Vec<i_slint_core::lengths::LogicalRect> {
         crate::highlight::element_position(&self.inner, element)
     }
+
+    /// The geometry and the layout constraints of all the visible elements, with the padding and
+    /// spacing of the layouts, for the layout debugging overlay.
+    ///
+    /// WARNING: this is not part of the public API
+    #[cfg(feature = "highlight")]
+    pub fn element_layouts(&self) -> Vec<crate::highlight::ElementLayout> {
+        crate::highlight::element_layouts(&self.inner)
+    }
 }
 
 impl ComponentHandle for ComponentInstance {
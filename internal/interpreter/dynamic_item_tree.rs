@@ -129,33 +129,27 @@ fn listview_layout(
         generativity::make_guard!(guard);
         let s = self.unerase(guard);
 
-        let geom = s.description.original.root_element.borrow().geometry_props.clone().unwrap();
+        let (y, width, height) = listview_geometry(&s.description.original);
 
         crate::eval::store_property(
             s.borrow_instance(),
-            &geom.y.element(),
-            geom.y.name(),
+            &y.element(),
+            y.name(),
             Value::Number(offset_y.get() as f64),
         )
         .expect("cannot set y");
 
-        let h: f32 = crate::eval::load_property(
-            s.borrow_instance(),
-            &geom.height.element(),
-            geom.height.name(),
-        )
-        .expect("missing height")
-        .try_into()
-        .expect("height not the right type");
+        let h: f32 =
+            crate::eval::load_property(s.borrow_instance(), &height.element(), height.name())
+                .expect("missing height")
+                .try_into()
+                .expect("height not the right type");
 
-        let w: f32 = crate::eval::load_property(
-            s.borrow_instance(),
-            &geom.width.element(),
-            geom.width.name(),
-        )
-        .expect("missing width")
-        .try_into()
-        .expect("width not the right type");
+        let w: f32 =
+            crate::eval::load_property(s.borrow_instance(), &width.element(), width.name())
+                .expect("missing width")
+                .try_into()
+                .expect("width not the right type");
 
         let h = LogicalLength::new(h);
         let w = LogicalLength::new(w);
@@ -166,11 +160,44 @@ fn listview_layout(
         }
     }
 
+    fn listview_item_height(self: Pin<&Self>) -> LogicalLength {
+        generativity::make_guard!(guard);
+        let s = self.unerase(guard);
+
+        let (_, _, height) = listview_geometry(&s.description.original);
+        let h: f32 =
+            crate::eval::load_property(s.borrow_instance(), &height.element(), height.name())
+                .expect("missing height")
+                .try_into()
+                .expect("height not the right type");
+        LogicalLength::new(h)
+    }
+
     fn box_layout_data(self: Pin<&Self>, o: Orientation) -> BoxLayoutCellData {
         BoxLayoutCellData { constraint: self.borrow().as_ref().layout_info(o) }
     }
 }
 
+/// The `y`, `width` and `height` properties of the root element of an item of a list view, or
+/// its `x`, `height` and `width` in a horizontal list view.
+fn listview_geometry(
+    component: &object_tree::Component,
+) -> (NamedReference, NamedReference, NamedReference) {
+    let geom = component.root_element.borrow().geometry_props.clone().unwrap();
+    let horizontal = component.parent_element.upgrade().map_or(false, |e| {
+        e.borrow()
+            .repeated
+            .as_ref()
+            .and_then(|r| r.is_listview.as_ref())
+            .map_or(false, |lv| lv.horizontal)
+    });
+    if horizontal {
+        (geom.x, geom.height, geom.width)
+    } else {
+        (geom.y, geom.width, geom.height)
+    }
+}
+
 impl ItemTree for ErasedItemTreeBox {
     fn visit_children_item(
         self: Pin<&Self>,
@@ -722,6 +749,20 @@ fn ensure_repeater_updated<'id>(
         let get_prop = |nr: &NamedReference| -> LogicalLength {
             eval::load_property(instance_ref, &nr.element(), nr.name()).unwrap().try_into().unwrap()
         };
+        let scroll_to_row: i32 =
+            eval::load_property(instance_ref, &lv.scroll_to_row.element(), lv.scroll_to_row.name())
+                .unwrap()
+                .try_into()
+                .unwrap();
+        if scroll_to_row >= 0 {
+            eval::store_property(
+                instance_ref,
+                &lv.scroll_to_row.element(),
+                lv.scroll_to_row.name(),
+                Value::Number(-1.),
+            )
+            .unwrap();
+        }
         repeater.ensure_updated_listview(
             init,
             assume_property_logical_length(get_property_ptr(&lv.viewport_width, instance_ref)),
@@ -729,6 +770,7 @@ fn ensure_repeater_updated<'id>(
             assume_property_logical_length(get_property_ptr(&lv.viewport_y, instance_ref)),
             get_prop(&lv.listview_width),
             assume_property_logical_length(get_property_ptr(&lv.listview_height, instance_ref)),
+            scroll_to_row,
         );
     } else {
         repeater.ensure_updated(init);
@@ -825,6 +867,7 @@ pub(crate) fn generate_item_tree<'id>(
                 rtti_for::<Flickable>(),
                 rtti_for::<WindowItem>(),
                 rtti_for::<TextInput>(),
+                rtti_for::<TimerItem>(),
                 rtti_for::<Clip>(),
                 rtti_for::<BoxShadow>(),
                 rtti_for::<Rotate>(),
@@ -1268,6 +1311,9 @@ pub fn instantiate(
     window_options: Option<&WindowOptions>,
     mut globals: crate::global_component::GlobalStorage,
 ) -> DynamicComponentVRc {
+    #[cfg(feature = "inspector")]
+    crate::inspector::register();
+
     let instance = description.dynamic_type.clone().create_instance();
 
     let component_box = ItemTreeBox { instance, description: description.clone() };
@@ -1386,6 +1432,9 @@ pub fn instantiate(
             );
             let elem = elem.borrow();
             let is_const = binding.analysis.as_ref().map_or(false, |a| a.is_const);
+            // The name of the property, as shown when a binding loop is detected at run-time
+            #[cfg(debug_assertions)]
+            let debug_name = || format!("{}::{}.{}", description.original.id, elem.id, prop_name);
 
             let property_type = elem.lookup_property(prop_name).property_type;
             if let Type::Function { .. } = property_type {
@@ -1427,6 +1476,8 @@ pub fn instantiate(
                     i_slint_core::properties::set_state_binding(prop, move || {
                         state_binding().try_into().unwrap()
                     });
+                    #[cfg(debug_assertions)]
+                    prop.set_binding_debug_name(debug_name());
                     return;
                 }
 
@@ -1449,6 +1500,8 @@ pub fn instantiate(
                                 maybe_animation,
                             )
                             .unwrap();
+                        #[cfg(debug_assertions)]
+                        prop_info.set_binding_debug_name(item, debug_name());
                     }
                 }
                 for nr in &binding.two_way_bindings {
@@ -1485,6 +1538,8 @@ pub fn instantiate(
                                 Box::new(make_binding_eval_closure(e, &self_weak)),
                                 maybe_animation,
                             );
+                            #[cfg(debug_assertions)]
+                            prop_rtti.set_binding_debug_name(item, debug_name());
                         }
                     }
                 } else {
@@ -1796,6 +1851,13 @@ extern "C" fn subtree_index(component: ItemTreeRefPin) -> usize {
     extra_data.embedding_position.set((parent_component.clone(), parent_item_tree_index)).is_ok()
 }
 
+/// Whether the item tree was created by the interpreter, so that it can be used with
+/// [`InstanceRef::from_pin_ref`]
+#[cfg(feature = "inspector")]
+pub(crate) fn is_dynamic_item_tree(component: ItemTreeRefPin) -> bool {
+    component.get_vtable().item_geometry as usize == item_geometry as usize
+}
+
 extern "C" fn item_geometry(component: ItemTreeRefPin, item_index: u32) -> LogicalRect {
     generativity::make_guard!(guard);
     let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
@@ -2055,6 +2117,7 @@ pub fn show_popup(
     popup: &object_tree::PopupWindow,
     pos: i_slint_core::graphics::Point,
     close_on_click: bool,
+    modal: bool,
     parent_comp: ErasedItemTreeBoxWeak,
     parent_window_adapter: WindowAdapterRc,
     parent_item: &ItemRc,
@@ -2074,6 +2137,31 @@ pub fn show_popup(
         &vtable::VRc::into_dyn(inst),
         pos,
         close_on_click,
+        modal,
         parent_item,
     );
 }
+
+pub fn show_child_window(
+    index: usize,
+    child_window: &object_tree::PopupWindow,
+    parent_comp: ErasedItemTreeBoxWeak,
+    parent_window_adapter: WindowAdapterRc,
+    parent_item: &ItemRc,
+) {
+    let window = WindowInner::from_pub(parent_window_adapter.window());
+    if window.is_child_window_shown(index, parent_item) {
+        return;
+    }
+    generativity::make_guard!(guard);
+    let compiled = generate_item_tree(&child_window.component, guard);
+    let inst = instantiate(
+        compiled,
+        Some(parent_comp),
+        None,
+        Some(&WindowOptions::UseExistingWindow(parent_window_adapter.clone())),
+        Default::default(),
+    );
+    inst.run_setup_code();
+    window.show_child_window(index, &vtable::VRc::into_dyn(inst), parent_item);
+}
@@ -7,7 +7,7 @@
 use core::pin::Pin;
 use corelib::graphics::{GradientStop, LinearGradientBrush, PathElement, RadialGradientBrush};
 use corelib::items::{ItemRef, PropertyAnimation};
-use corelib::model::{Model, ModelRc};
+use corelib::model::{Model, ModelExt, ModelRc};
 use corelib::rtti::AnimatedBindingKind;
 use corelib::{Brush, Color, PathData, SharedString, SharedVector};
 use i_slint_compiler::expression_tree::{
@@ -353,6 +353,9 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
             EasingCurve::CubicBezier(a, b, c, d) => {
                 corelib::animations::EasingCurve::CubicBezier([*a, *b, *c, *d])
             }
+            EasingCurve::Spring(stiffness, damping, mass) => {
+                corelib::animations::EasingCurve::Spring([*stiffness, *damping, *mass])
+            }
         }),
         Expression::LinearGradient{angle, stops} => {
             let angle = eval_expression(angle, local_context);
@@ -406,6 +409,30 @@ pub fn eval_expression(expression: &Expression, local_context: &mut EvalLocalCon
                 MinMaxOp::Max => Value::Number(lhs.max(rhs)),
             }
         }
+        Expression::ArrayMap { array, variable, expression, filter } => {
+            let Value::Model(model) = eval_expression(array, local_context) else {
+                panic!("First argument not an array");
+            };
+            model.model_tracker().track_row_count_changes();
+            // The lambda may shadow a variable of the enclosing expression
+            let shadowed = local_context.local_variables.remove(variable);
+            let mut result = SharedVector::default();
+            for i in 0..model.row_count() {
+                let value = model.row_data_tracked(i).unwrap_or_default();
+                local_context.local_variables.insert(variable.clone(), value.clone());
+                let mapped = eval_expression(expression, local_context);
+                if !*filter {
+                    result.push(mapped);
+                } else if mapped == Value::Bool(true) {
+                    result.push(value);
+                }
+            }
+            local_context.local_variables.remove(variable);
+            if let Some(shadowed) = shadowed {
+                local_context.local_variables.insert(variable.clone(), shadowed);
+            }
+            Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(result)))
+        }
     }
 }
 
@@ -436,12 +463,30 @@ fn call_builtin_function(
         BuiltinFunction::AnimationTick => {
             Value::Number(i_slint_core::animations::animation_tick() as f64)
         }
+        BuiltinFunction::CurrentTick => {
+            Value::Number(i_slint_core::animations::current_tick().0 as f64)
+        }
+        BuiltinFunction::ApplyEasingCurve => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to ApplyEasingCurve")
+            }
+            let curve: i_slint_core::animations::EasingCurve =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let value: f32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Number(i_slint_core::animations::easing_curve(&curve, value) as f64)
+        }
         BuiltinFunction::Debug => {
             let to_print: SharedString =
                 eval_expression(&arguments[0], local_context).try_into().unwrap();
             corelib::debug_log!("{}", to_print);
             Value::Void
         }
+        BuiltinFunction::AssertionFailed => {
+            let message: SharedString =
+                eval_expression(&arguments[0], local_context).try_into().unwrap();
+            corelib::tests::assertion_failed(&message);
+            Value::Void
+        }
         BuiltinFunction::Mod => {
             let mut to_num = |e| -> f64 { eval_expression(e, local_context).try_into().unwrap() };
             Value::Number(to_num(&arguments[0]) % to_num(&arguments[1]))
@@ -590,6 +635,7 @@ fn call_builtin_function(
                         y.try_into().unwrap(),
                     ),
                     popup.close_on_click,
+                    popup.modal,
                     component.self_weak().get().unwrap().clone(),
                     component.window_adapter(),
                     &parent_item,
@@ -611,6 +657,63 @@ fn call_builtin_function(
 
             Value::Void
         }
+        f @ (BuiltinFunction::ShowChildWindow | BuiltinFunction::CloseChildWindow) => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to {f:?}")
+            }
+            let component = match local_context.component_instance {
+                ComponentInstance::InstanceRef(c) => c,
+                ComponentInstance::GlobalComponent(_) => {
+                    panic!("Cannot show a child window from a global component")
+                }
+            };
+            if let Expression::ElementReference(child_window) = &arguments[0] {
+                let child_window = child_window.upgrade().unwrap();
+                let child_comp = child_window.borrow().enclosing_component.upgrade().unwrap();
+                let parent_component = child_comp
+                    .parent_element
+                    .upgrade()
+                    .unwrap()
+                    .borrow()
+                    .enclosing_component
+                    .upgrade()
+                    .unwrap();
+                let popup_list = parent_component.popup_windows.borrow();
+                let (index, popup) = popup_list
+                    .iter()
+                    .enumerate()
+                    .find(|(_, p)| Rc::ptr_eq(&p.component, &child_comp))
+                    .unwrap();
+
+                generativity::make_guard!(guard);
+                let enclosing_component =
+                    enclosing_component_for_element(&popup.parent_element, component, guard);
+                let parent_item_info = &enclosing_component.description.items
+                    [popup.parent_element.borrow().id.as_str()];
+                let parent_item_comp =
+                    enclosing_component.self_weak().get().unwrap().upgrade().unwrap();
+                let parent_item = corelib::items::ItemRc::new(
+                    vtable::VRc::into_dyn(parent_item_comp),
+                    parent_item_info.item_index(),
+                );
+
+                if f == BuiltinFunction::ShowChildWindow {
+                    crate::dynamic_item_tree::show_child_window(
+                        index,
+                        popup,
+                        component.self_weak().get().unwrap().clone(),
+                        component.window_adapter(),
+                        &parent_item,
+                    );
+                } else {
+                    component
+                        .access_window(|window| window.close_child_window(index, &parent_item));
+                }
+                Value::Void
+            } else {
+                panic!("internal error: argument to {f:?} must be an element")
+            }
+        }
         BuiltinFunction::SetSelectionOffsets => {
             if arguments.len() != 3 {
                 panic!("internal error: incorrect argument count to select range function call")
@@ -736,6 +839,98 @@ fn call_builtin_function(
                 panic!("Argument not a string");
             }
         }
+        BuiltinFunction::StringToUppercase
+        | BuiltinFunction::StringToLowercase
+        | BuiltinFunction::StringTrim => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to {:?}", f)
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(match f {
+                BuiltinFunction::StringToUppercase => s.as_str().to_uppercase().into(),
+                BuiltinFunction::StringToLowercase => s.as_str().to_lowercase().into(),
+                _ => s.as_str().trim().into(),
+            })
+        }
+        BuiltinFunction::StringContains => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to StringContains")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let pattern: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::Bool(s.as_str().contains(pattern.as_str()))
+        }
+        BuiltinFunction::StringSplit => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to StringSplit")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let separator: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let parts: SharedVector<Value> = if separator.is_empty() {
+                core::iter::once(Value::String(s)).collect()
+            } else {
+                s.as_str().split(separator.as_str()).map(|p| Value::String(p.into())).collect()
+            };
+            Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(parts)))
+        }
+        BuiltinFunction::StringReplace => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to StringReplace")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let from: SharedString =
+                eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let to: SharedString =
+                eval_expression(&arguments[2], local_context).try_into().unwrap();
+            if from.is_empty() {
+                Value::String(s)
+            } else {
+                Value::String(s.as_str().replace(from.as_str(), to.as_str()).into())
+            }
+        }
+        BuiltinFunction::StringCharacterCount => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to StringCharacterCount")
+            }
+            let s: SharedString = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::Number(s.as_str().chars().count() as f64)
+        }
+        BuiltinFunction::FormatNumber
+        | BuiltinFunction::FormatPercent
+        | BuiltinFunction::FormatByteSize => {
+            if arguments.len() != 2 {
+                panic!("internal error: incorrect argument count to {:?}", f)
+            }
+            let value: f64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let decimals: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            Value::String(match f {
+                BuiltinFunction::FormatNumber => {
+                    corelib::formatting::format_number(value, decimals)
+                }
+                BuiltinFunction::FormatPercent => {
+                    corelib::formatting::format_percent(value, decimals)
+                }
+                _ => corelib::formatting::format_byte_size(value, decimals),
+            })
+        }
+        BuiltinFunction::FormatDuration => {
+            if arguments.len() != 1 {
+                panic!("internal error: incorrect argument count to FormatDuration")
+            }
+            let ms: i64 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            Value::String(corelib::formatting::format_duration(ms))
+        }
+        BuiltinFunction::FormatDate => {
+            if arguments.len() != 3 {
+                panic!("internal error: incorrect argument count to FormatDate")
+            }
+            let year: i32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
+            let month: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
+            let day: i32 = eval_expression(&arguments[2], local_context).try_into().unwrap();
+            Value::String(corelib::formatting::format_date(year, month, day))
+        }
         BuiltinFunction::ColorBrighter => {
             if arguments.len() != 2 {
                 panic!("internal error: incorrect argument count to ColorBrighter")
@@ -852,6 +1047,66 @@ fn call_builtin_function(
                 }
             }
         }
+        BuiltinFunction::ArraySum
+        | BuiltinFunction::ArrayContains
+        | BuiltinFunction::ArrayIndexOf
+        | BuiltinFunction::ArraySlice
+        | BuiltinFunction::ArrayJoin => {
+            let Value::Model(model) = eval_expression(&arguments[0], local_context) else {
+                panic!("First argument not an array");
+            };
+            model.model_tracker().track_row_count_changes();
+            let mut rows =
+                (0..model.row_count()).map(|i| model.row_data_tracked(i).unwrap_or_default());
+            match f {
+                BuiltinFunction::ArraySum => Value::Number(
+                    rows.map(|v| f64::try_from(v).expect("Array element is not a number")).sum(),
+                ),
+                BuiltinFunction::ArrayContains | BuiltinFunction::ArrayIndexOf => {
+                    if arguments.len() != 2 {
+                        panic!("internal error: incorrect argument count to {:?}", f)
+                    }
+                    let value = eval_expression(&arguments[1], local_context);
+                    let position = rows.position(|v| v == value);
+                    if f == BuiltinFunction::ArrayContains {
+                        Value::Bool(position.is_some())
+                    } else {
+                        Value::Number(position.map_or(-1., |i| i as f64))
+                    }
+                }
+                BuiltinFunction::ArraySlice => {
+                    if arguments.len() != 3 {
+                        panic!("internal error: incorrect argument count to ArraySlice")
+                    }
+                    let start: i32 =
+                        eval_expression(&arguments[1], local_context).try_into().unwrap();
+                    let end: i32 =
+                        eval_expression(&arguments[2], local_context).try_into().unwrap();
+                    let len = model.row_count();
+                    let start = (start.max(0) as usize).min(len);
+                    let end = (end.max(0) as usize).min(len).max(start);
+                    Value::Model(ModelRc::new(corelib::model::SharedVectorModel::from(
+                        rows.skip(start).take(end - start).collect::<SharedVector<_>>(),
+                    )))
+                }
+                _ => {
+                    if arguments.len() != 2 {
+                        panic!("internal error: incorrect argument count to ArrayJoin")
+                    }
+                    let separator: SharedString =
+                        eval_expression(&arguments[1], local_context).try_into().unwrap();
+                    let mut result = SharedString::default();
+                    for (i, v) in rows.enumerate() {
+                        if i > 0 {
+                            result.push_str(separator.as_str());
+                        }
+                        let v: SharedString = v.try_into().expect("Array element is not a string");
+                        result.push_str(v.as_str());
+                    }
+                    Value::String(result)
+                }
+            }
+        }
         BuiltinFunction::Rgb => {
             let r: i32 = eval_expression(&arguments[0], local_context).try_into().unwrap();
             let g: i32 = eval_expression(&arguments[1], local_context).try_into().unwrap();
@@ -4,10 +4,16 @@
 //! This module contains the code for the highlight of some elements
 
 use crate::dynamic_item_tree::{DynamicComponentVRc, ItemTreeBox};
+use i_slint_compiler::layout::Orientation;
+use i_slint_compiler::namedreference::NamedReference;
 use i_slint_compiler::object_tree::{Component, Element, ElementRc};
+use i_slint_compiler::parser::SyntaxKind;
 use i_slint_core::items::ItemRc;
-use i_slint_core::lengths::LogicalRect;
+use i_slint_core::layout::LayoutInfo;
+use i_slint_core::lengths::{LogicalPoint, LogicalRect, LogicalSize};
+use i_slint_core::Coord;
 use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::path::PathBuf;
 use std::rc::Rc;
 use vtable::VRc;
@@ -29,6 +35,92 @@ pub struct ComponentPositions {
     pub geometries: Vec<i_slint_core::lengths::LogicalRect>,
 }
 
+/// The geometry and the layout constraints of an element, as shown by the layout debugging overlay
+#[derive(Clone, Debug)]
+pub struct ElementLayout {
+    /// The type of the element as written in the source, for example `HorizontalLayout` or `Text`
+    pub type_name: String,
+    /// The id of the element as written in the source, empty if it has none
+    pub id: String,
+    /// The geometry of the element, relative to the root of the component
+    pub geometry: LogicalRect,
+    /// The horizontal constraints of the element
+    pub horizontal: LayoutInfo,
+    /// The vertical constraints of the element
+    pub vertical: LayoutInfo,
+    /// The padding and the spacing, if the element is a `GridLayout`, a `HorizontalLayout` or a
+    /// `VerticalLayout`
+    pub layout: Option<LayoutSpacing>,
+}
+
+impl ElementLayout {
+    /// A description of the element, its geometry and its constraints, as shown when hovering
+    /// the element in the overlay
+    pub fn describe(&self) -> String {
+        let mut result = if self.id.is_empty() {
+            self.type_name.clone()
+        } else {
+            format!("{} := {}", self.id, self.type_name)
+        };
+        result += &format!(
+            "\nx: {}, y: {}, width: {}, height: {}",
+            format_length(self.geometry.origin.x),
+            format_length(self.geometry.origin.y),
+            format_length(self.geometry.size.width),
+            format_length(self.geometry.size.height),
+        );
+        result += &format!("\nhorizontal: {}", format_layout_info(&self.horizontal));
+        result += &format!("\nvertical: {}", format_layout_info(&self.vertical));
+        if let Some(layout) = &self.layout {
+            result += &format!(
+                "\npadding: left {}, right {}, top {}, bottom {}",
+                format_length(layout.padding_left as _),
+                format_length(layout.padding_right as _),
+                format_length(layout.padding_top as _),
+                format_length(layout.padding_bottom as _),
+            );
+            result += &format!(
+                "\nspacing: horizontal {}, vertical {}",
+                format_length(layout.spacing_horizontal as _),
+                format_length(layout.spacing_vertical as _),
+            );
+        }
+        result
+    }
+}
+
+fn format_layout_info(info: &LayoutInfo) -> String {
+    format!(
+        "min {}, preferred {}, max {}, stretch {}",
+        format_length(info.min),
+        format_length(info.preferred),
+        format_length(info.max),
+        info.stretch
+    )
+}
+
+fn format_length(length: Coord) -> String {
+    // An unconstrained maximum is Coord::MAX, or a sum of several of them
+    if length >= Coord::MAX / 2 as Coord {
+        "∞".into()
+    } else {
+        format!("{}px", (length as f64 * 100.).round() / 100.)
+    }
+}
+
+/// The padding and the spacing of a layout
+#[derive(Clone, Debug, Default)]
+pub struct LayoutSpacing {
+    pub padding_left: f32,
+    pub padding_right: f32,
+    pub padding_top: f32,
+    pub padding_bottom: f32,
+    pub spacing_horizontal: f32,
+    pub spacing_vertical: f32,
+    /// The gaps between the cells of the layout, relative to the root of the component
+    pub gaps: Vec<LogicalRect>,
+}
+
 fn collect_highlight_data(
     component: &DynamicComponentVRc,
     elements: &[std::rc::Weak<RefCell<Element>>],
@@ -75,6 +167,185 @@ pub(crate) fn element_position(
     values.geometries
 }
 
+/// The layout of all the visible elements of the component, parents before their children
+pub(crate) fn element_layouts(component_instance: &DynamicComponentVRc) -> Vec<ElementLayout> {
+    generativity::make_guard!(guard);
+    let c = component_instance.unerase(guard);
+
+    let root_element = c.description().original.root_element.clone();
+    let mut result = Vec::new();
+    collect_element_layouts(&root_element, &c, &c, &mut result);
+    result
+}
+
+/// Append the layout of `element` and of its children to `result`. Returns the geometry of the
+/// element, or of all its instances if it is repeated.
+fn collect_element_layouts(
+    element: &ElementRc,
+    component_instance: &ItemTreeBox,
+    root_component_instance: &ItemTreeBox,
+    result: &mut Vec<ElementLayout>,
+) -> Vec<LogicalRect> {
+    if element.borrow().repeated.is_some() {
+        generativity::make_guard!(guard);
+        let rep = crate::dynamic_item_tree::get_repeater_by_name(
+            component_instance.borrow_instance(),
+            element.borrow().id.as_str(),
+            guard,
+        );
+        let mut geometries = Vec::new();
+        for idx in rep.0.range() {
+            if let Some(c) = rep.0.instance_at(idx) {
+                generativity::make_guard!(guard);
+                let c = c.unerase(guard);
+                let root_element = c.description().original.root_element.clone();
+                geometries.extend(collect_element_layouts(
+                    &root_element,
+                    &c,
+                    root_component_instance,
+                    result,
+                ));
+            }
+        }
+        return geometries;
+    }
+
+    // The content of a ComponentContainer is not part of this component
+    if element.borrow().is_component_placeholder {
+        return Vec::new();
+    }
+    let Some(index) = element.borrow().item_index.get().copied() else {
+        return Vec::new();
+    };
+
+    let instance_ref = component_instance.borrow_instance();
+    let vrc = VRc::into_dyn(instance_ref.self_weak().get().unwrap().upgrade().unwrap());
+    let root_vrc = VRc::into_dyn(
+        root_component_instance.borrow_instance().self_weak().get().unwrap().upgrade().unwrap(),
+    );
+    let item_rc = ItemRc::new(vrc, index);
+    if !item_rc.is_visible() {
+        return Vec::new();
+    }
+    let geometry = item_rc.geometry();
+    let geometry = LogicalRect {
+        origin: item_rc.map_to_item_tree(geometry.origin, &root_vrc),
+        size: geometry.size,
+    };
+
+    let window_adapter = instance_ref.window_adapter();
+    let (type_name, id) = source_names(element);
+    let position = result.len();
+    result.push(ElementLayout {
+        type_name,
+        id,
+        geometry,
+        horizontal: crate::eval_layout::get_layout_info(
+            element,
+            instance_ref,
+            &window_adapter,
+            Orientation::Horizontal,
+        ),
+        vertical: crate::eval_layout::get_layout_info(
+            element,
+            instance_ref,
+            &window_adapter,
+            Orientation::Vertical,
+        ),
+        layout: None,
+    });
+
+    let children = element.borrow().children.clone();
+    let cells = children
+        .iter()
+        .flat_map(|child| {
+            collect_element_layouts(child, component_instance, root_component_instance, result)
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(layout) = &element.borrow().layout {
+        let load = |nr: &Option<NamedReference>| -> f32 {
+            nr.as_ref()
+                .and_then(|nr| {
+                    crate::eval::load_property(instance_ref, &nr.element(), nr.name()).ok()
+                })
+                .and_then(|v| v.try_into().ok())
+                .unwrap_or(0.)
+        };
+        let geometry = layout.geometry();
+        result[position].layout = Some(LayoutSpacing {
+            padding_left: load(&geometry.padding.left),
+            padding_right: load(&geometry.padding.right),
+            padding_top: load(&geometry.padding.top),
+            padding_bottom: load(&geometry.padding.bottom),
+            spacing_horizontal: load(&geometry.spacing.horizontal),
+            spacing_vertical: load(&geometry.spacing.vertical),
+            gaps: layout_gaps(&cells),
+        });
+    }
+
+    vec![geometry]
+}
+
+/// The type name and the id of the element, as written in the source
+pub(crate) fn source_names(element: &ElementRc) -> (String, String) {
+    let e = element.borrow();
+    let node = e.node.first();
+    let type_name = node
+        .and_then(|n| n.child_node(SyntaxKind::QualifiedName))
+        .map(|n| n.text().to_string().trim().to_string())
+        .unwrap_or_else(|| e.base_type.to_string());
+    let id = node
+        .and_then(|n| n.parent())
+        .filter(|p| p.kind() == SyntaxKind::SubElement)
+        .and_then(|p| p.child_node(SyntaxKind::DeclaredIdentifier))
+        .map(|n| n.text().to_string().trim().to_string())
+        .unwrap_or_default();
+    (type_name, id)
+}
+
+/// The gaps between each cell and the closest cells on its right and below it
+fn layout_gaps(cells: &[LogicalRect]) -> Vec<LogicalRect> {
+    let closest = |a: &&LogicalRect, b: &&LogicalRect| {
+        (a.min_x() + a.min_y()).partial_cmp(&(b.min_x() + b.min_y())).unwrap_or(Ordering::Equal)
+    };
+
+    let mut gaps = Vec::new();
+    for cell in cells {
+        let right = cells
+            .iter()
+            .filter(|c| {
+                c.min_x() >= cell.max_x() && c.min_y() < cell.max_y() && c.max_y() > cell.min_y()
+            })
+            .min_by(closest);
+        if let Some(right) = right {
+            let top = cell.min_y().max(right.min_y());
+            let bottom = cell.max_y().min(right.max_y());
+            gaps.push(LogicalRect::new(
+                LogicalPoint::new(cell.max_x(), top),
+                LogicalSize::new(right.min_x() - cell.max_x(), bottom - top),
+            ));
+        }
+
+        let below = cells
+            .iter()
+            .filter(|c| {
+                c.min_y() >= cell.max_y() && c.min_x() < cell.max_x() && c.max_x() > cell.min_x()
+            })
+            .min_by(closest);
+        if let Some(below) = below {
+            let left = cell.min_x().max(below.min_x());
+            let right = cell.max_x().min(below.max_x());
+            gaps.push(LogicalRect::new(
+                LogicalPoint::new(left, cell.max_y()),
+                LogicalSize::new(right - left, below.min_y() - cell.max_y()),
+            ));
+        }
+    }
+    gaps.retain(|g| !g.is_empty());
+    gaps
+}
+
 fn fill_highlight_data(
     repeater_path: &[String],
     element: &ElementRc,
@@ -0,0 +1,215 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Provides the ids of the elements, their declared properties and the bindings of their
+//! properties to the element tree inspector of i-slint-core.
+
+use crate::api::Value;
+use crate::dynamic_item_tree::InstanceRef;
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::object_tree::ElementRc;
+use i_slint_compiler::parser::{SyntaxKind, SyntaxNode};
+use i_slint_core::inspector::{ElementInfoProvider, InspectorProperty};
+use i_slint_core::item_tree::ItemTreeRc;
+use i_slint_core::items::ItemRc;
+use std::cell::Cell;
+
+struct InterpreterElementInfo;
+
+/// Call `f` with the element of the item, if the item belongs to a component of the interpreter
+fn with_element<R>(item: &ItemRc, f: impl FnOnce(InstanceRef, &ElementRc) -> R) -> Option<R> {
+    let component = ItemTreeRc::borrow_pin(item.item_tree());
+    if !crate::dynamic_item_tree::is_dynamic_item_tree(component) {
+        return None;
+    }
+    generativity::make_guard!(guard);
+    // Safety: the item tree was created by the interpreter
+    let instance_ref = unsafe { InstanceRef::from_pin_ref(component, guard) };
+    let element = instance_ref.description.original_elements.get(item.index() as usize)?.clone();
+    Some(f(instance_ref, &element))
+}
+
+impl ElementInfoProvider for InterpreterElementInfo {
+    fn element_names(&self, item: &ItemRc) -> Option<(String, String)> {
+        with_element(item, |_, element| {
+            let (type_name, id) = crate::highlight::source_names(element);
+            (id, type_name)
+        })
+    }
+
+    fn properties(&self, item: &ItemRc) -> Vec<InspectorProperty> {
+        with_element(item, |instance_ref, element| {
+            let sources = binding_sources(element);
+            let e = element.borrow();
+
+            let declared = e
+                .property_declarations
+                .iter()
+                .filter(|(_, d)| d.property_type.is_property_type())
+                .map(|(name, declaration)| {
+                    let value = crate::eval::load_property(instance_ref, element, name).ok();
+                    InspectorProperty {
+                        name: name.clone(),
+                        value: value
+                            .as_ref()
+                            .map(|v| format_value(v, &declaration.property_type))
+                            .unwrap_or_default(),
+                        editable: value.is_some() && is_editable(&declaration.property_type),
+                        ..Default::default()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            let mut properties = declared;
+            for (name, binding) in &e.bindings {
+                let binding = binding.borrow();
+                // Bindings with priority 0 were added by the compiler
+                if binding.priority == 0 && binding.two_way_bindings.is_empty() {
+                    continue;
+                }
+                let mut dependencies = Vec::new();
+                binding.expression.visit_recursive(&mut |expression| {
+                    if let Expression::PropertyReference(nr) = expression {
+                        dependencies.push(property_name(&nr.element(), nr.name()));
+                    }
+                });
+                dependencies.extend(
+                    binding
+                        .two_way_bindings
+                        .iter()
+                        .map(|nr| property_name(&nr.element(), nr.name())),
+                );
+                dependencies.sort();
+                dependencies.dedup();
+
+                // Bindings that are not written in the element come from its base component
+                let source = sources
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map_or_else(|| "(inherited)".to_string(), |(_, s)| s.clone());
+                match properties.iter_mut().find(|p| &p.name == name) {
+                    Some(p) => {
+                        p.binding = Some(source);
+                        p.dependencies = dependencies;
+                    }
+                    // A property of the native item: The value is read by the inspector
+                    None => properties.push(InspectorProperty {
+                        name: name.clone(),
+                        binding: Some(source),
+                        dependencies,
+                        ..Default::default()
+                    }),
+                }
+            }
+            properties
+        })
+        .unwrap_or_default()
+    }
+
+    fn set_property(&self, item: &ItemRc, name: &str, value: &str) -> Option<Result<(), String>> {
+        with_element(item, |instance_ref, element| {
+            let ty = element.borrow().property_declarations.get(name)?.property_type.clone();
+            let result = parse_value(&ty, value)
+                .ok_or_else(|| format!("Invalid value for {name}: {value}"))
+                .and_then(|value| {
+                    crate::eval::store_property(instance_ref, element, name, value)
+                        .map_err(|err| format!("Cannot set {name}: {err}"))
+                });
+            Some(result)
+        })
+        .flatten()
+    }
+}
+
+/// Register the provider once per thread, when the first component is instantiated
+pub(crate) fn register() {
+    thread_local! { static REGISTERED: Cell<bool> = Cell::new(false); }
+    if !REGISTERED.with(|r| r.replace(true)) {
+        i_slint_core::inspector::register_element_info_provider(Box::new(InterpreterElementInfo));
+    }
+}
+
+/// The property as written in the source: `id.name`, or just `name` for elements without id
+fn property_name(element: &ElementRc, name: &str) -> String {
+    let (_, id) = crate::highlight::source_names(element);
+    if id.is_empty() {
+        name.to_string()
+    } else {
+        format!("{id}.{name}")
+    }
+}
+
+/// The source of the bindings written in the element, by property name
+fn binding_sources(element: &ElementRc) -> Vec<(String, String)> {
+    let e = element.borrow();
+    let Some(node) = e.node.first() else {
+        return Vec::new();
+    };
+    let text_of = |node: &SyntaxNode, kind: SyntaxKind| {
+        node.child_node(kind).map(|n| n.text().to_string().trim().to_string())
+    };
+    node.children()
+        .filter_map(|child| match child.kind() {
+            SyntaxKind::Binding => Some((
+                child.child_text(SyntaxKind::Identifier)?,
+                text_of(&child, SyntaxKind::BindingExpression)?,
+            )),
+            SyntaxKind::TwoWayBinding => Some((
+                child.child_text(SyntaxKind::Identifier)?,
+                format!("<=> {}", text_of(&child, SyntaxKind::Expression)?),
+            )),
+            SyntaxKind::PropertyDeclaration => Some((
+                text_of(&child, SyntaxKind::DeclaredIdentifier)?,
+                text_of(&child, SyntaxKind::BindingExpression).or_else(|| {
+                    let two_way = child.child_node(SyntaxKind::TwoWayBinding)?;
+                    Some(format!("<=> {}", text_of(&two_way, SyntaxKind::Expression)?))
+                })?,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn is_editable(ty: &Type) -> bool {
+    matches!(
+        ty,
+        Type::String | Type::Bool | Type::Color | Type::Brush | Type::Float32 | Type::Int32
+    ) || ty.default_unit().is_some()
+}
+
+fn format_value(value: &Value, ty: &Type) -> String {
+    match value {
+        Value::Number(n) => match ty.default_unit() {
+            Some(unit) => format!("{n}{unit}"),
+            None => n.to_string(),
+        },
+        Value::String(s) => s.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Brush(i_slint_core::Brush::SolidColor(c)) => {
+            i_slint_core::inspector::format_color(*c)
+        }
+        Value::EnumerationValue(_, v) => v.clone(),
+        v => format!("{v:?}"),
+    }
+}
+
+/// Convert the text entered in the inspector to a value of the type
+fn parse_value(ty: &Type, text: &str) -> Option<Value> {
+    let text = text.trim();
+    match ty {
+        Type::String => Some(Value::String(text.into())),
+        Type::Bool => text.parse().ok().map(Value::Bool),
+        Type::Color | Type::Brush => {
+            let color = i_slint_core::inspector::parse_color(text).ok()?;
+            Some(Value::Brush(i_slint_core::Brush::SolidColor(color)))
+        }
+        ty if is_editable(ty) => {
+            // Accept the default unit of the type after the number, such as `px` for lengths
+            let unit = ty.default_unit().map(|u| u.to_string()).unwrap_or_default();
+            let text = text.strip_suffix(unit.as_str()).unwrap_or(text);
+            text.trim().parse().ok().map(Value::Number)
+        }
+        _ => None,
+    }
+}
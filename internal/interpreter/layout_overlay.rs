@@ -0,0 +1,151 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The layout debugging overlay of the live preview and of `slint-viewer --layout-debug`: the
+//! bounds of all the elements of a component, the padding and spacing of its layouts, and the
+//! layout constraints of the element under the mouse.
+//!
+//! The overlay is a component of its own, embedded with a `ComponentContainer` on top of the
+//! inspected component, so the inspected component is not changed.
+
+use crate::highlight::ElementLayout;
+use crate::{ComponentCompiler, ComponentDefinition, ComponentHandle, ComponentInstance, Value};
+use i_slint_core::component_factory::{ComponentFactory, FactoryContext};
+use i_slint_core::lengths::{LogicalPoint, LogicalRect};
+use i_slint_core::model::{ModelRc, VecModel};
+use i_slint_core::timers::{Timer, TimerMode};
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// How often the overlay follows the changes of the geometry, for example when resizing
+const REFRESH_INTERVAL: Duration = Duration::from_millis(300);
+
+thread_local! {
+    static OVERLAY_DEFINITION: once_cell::unsync::OnceCell<Option<ComponentDefinition>> =
+        Default::default();
+}
+
+/// Compile the overlay, once per thread
+fn overlay_definition() -> Option<ComponentDefinition> {
+    OVERLAY_DEFINITION.with(|definition| {
+        definition
+            .get_or_init(|| {
+                let mut compiler = ComponentCompiler::default();
+                // The source has no import, so nothing is loaded asynchronously
+                let definition = spin_on::spin_on(compiler.build_from_source(
+                    include_str!("layout-overlay.slint").into(),
+                    "layout-overlay.slint".into(),
+                ));
+                debug_assert!(compiler.diagnostics().is_empty(), "{:?}", compiler.diagnostics());
+                definition
+            })
+            .clone()
+    })
+}
+
+/// A factory for a `ComponentContainer` placed on top of a component, with the same position and
+/// size, that shows the layout of the elements of that component. `target` returns the component,
+/// which can change over time, for example when it is reloaded.
+pub fn layout_overlay_factory(
+    target: impl Fn() -> Option<ComponentInstance> + 'static,
+) -> ComponentFactory {
+    let target = Rc::new(target);
+    ComponentFactory::new(move |ctx: FactoryContext| {
+        let overlay = overlay_definition()?.create_embedded(ctx).ok()?;
+        install(&overlay, target.clone());
+        Some(overlay)
+    })
+}
+
+fn install(overlay: &ComponentInstance, target: Rc<dyn Fn() -> Option<ComponentInstance>>) {
+    let geometries = Rc::new(RefCell::new(Vec::<LogicalRect>::new()));
+    let timer = Rc::new(Timer::default());
+
+    let g = geometries.clone();
+    // The callback owns the timer, so that the updates stop when the overlay is destroyed
+    let t = timer.clone();
+    overlay
+        .set_callback("element-at", move |args| {
+            let _keep_alive = &t;
+            let [Value::Number(x), Value::Number(y)] = args else {
+                return Value::Number(-1.);
+            };
+            Value::Number(element_at(&g.borrow(), LogicalPoint::new(*x as _, *y as _)) as f64)
+        })
+        .unwrap();
+
+    let weak = overlay.as_weak();
+    let last_elements = RefCell::new(Vec::new());
+    let update = move || {
+        let Some(overlay) = weak.upgrade() else {
+            return;
+        };
+        let layouts = target().map(|t| t.element_layouts()).unwrap_or_default();
+        let elements = layouts.iter().map(element_value).collect::<Vec<_>>();
+        if *last_elements.borrow() == elements {
+            // Avoid re-creating the overlay while nothing changes
+            return;
+        }
+
+        *geometries.borrow_mut() = layouts.iter().map(|l| l.geometry).collect();
+        overlay
+            .set_property("elements", Value::Model(ModelRc::new(VecModel::from(elements.clone()))))
+            .unwrap();
+        overlay
+            .set_property("gaps", Value::Model(ModelRc::new(VecModel::from(gap_values(&layouts)))))
+            .unwrap();
+        *last_elements.borrow_mut() = elements;
+    };
+
+    // The components are not laid out yet, so the first update is done by the timer
+    timer.start(TimerMode::Repeated, REFRESH_INTERVAL, update);
+}
+
+/// The index of the innermost element at the position, or -1: Children come after their parents.
+pub(crate) fn element_at(geometries: &[LogicalRect], position: LogicalPoint) -> i32 {
+    geometries.iter().rposition(|g| g.contains(position)).map_or(-1, |index| index as i32)
+}
+
+fn rect_fields(rect: &LogicalRect) -> [(&'static str, Value); 4] {
+    [
+        ("x", Value::Number(rect.origin.x as _)),
+        ("y", Value::Number(rect.origin.y as _)),
+        ("width", Value::Number(rect.size.width as _)),
+        ("height", Value::Number(rect.size.height as _)),
+    ]
+}
+
+/// The `LayoutOverlayGap` values of the gaps between the cells of all the layouts
+pub(crate) fn gap_values(layouts: &[ElementLayout]) -> Vec<Value> {
+    layouts
+        .iter()
+        .filter_map(|l| l.layout.as_ref())
+        .flat_map(|l| l.gaps.iter())
+        .map(|gap| {
+            rect_fields(gap)
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect::<crate::Struct>()
+                .into()
+        })
+        .collect()
+}
+
+/// The `LayoutOverlayElement` value of the element
+pub(crate) fn element_value(layout: &ElementLayout) -> Value {
+    let padding = layout.layout.clone().unwrap_or_default();
+    rect_fields(&layout.geometry)
+        .into_iter()
+        .chain([
+            ("is-layout", Value::Bool(layout.layout.is_some())),
+            ("padding-left", Value::Number(padding.padding_left as _)),
+            ("padding-right", Value::Number(padding.padding_right as _)),
+            ("padding-top", Value::Number(padding.padding_top as _)),
+            ("padding-bottom", Value::Number(padding.padding_bottom as _)),
+            ("description", Value::String(layout.describe().into())),
+        ])
+        .map(|(name, value)| (name.to_string(), value))
+        .collect::<crate::Struct>()
+        .into()
+}
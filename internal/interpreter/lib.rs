@@ -81,6 +81,10 @@
 mod global_component;
 #[cfg(feature = "highlight")]
 pub mod highlight;
+#[cfg(feature = "inspector")]
+mod inspector;
+#[cfg(all(feature = "highlight", feature = "internal"))]
+pub mod layout_overlay;
 mod value_model;
 
 #[doc(inline)]
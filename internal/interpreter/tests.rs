@@ -43,3 +43,69 @@ fn reuse_window() {
         instance
     };
 }
+
+#[cfg(all(feature = "highlight", feature = "internal"))]
+#[test]
+fn layout_overlay() {
+    i_slint_backend_testing::init();
+    use crate::layout_overlay::{element_at, element_value, gap_values};
+    use crate::{ComponentCompiler, ComponentHandle, LogicalSize, Value};
+    use i_slint_compiler::langtype::Type;
+    use i_slint_core::lengths::LogicalPoint;
+
+    // The root of the component is a layout
+    let code = r#"
+        export component Root inherits VerticalLayout {
+            padding: 5px;
+            spacing: 10px;
+            Rectangle { height: 20px; }
+            Rectangle { height: 30px; }
+        }
+    "#;
+    let mut compiler = ComponentCompiler::default();
+    let definition = spin_on::spin_on(compiler.build_from_source(code.into(), Default::default()));
+    assert!(compiler.diagnostics().is_empty(), "{:?}", compiler.diagnostics());
+    let instance = definition.unwrap().create().unwrap();
+    // The preferred size, so the layout has no extra space to distribute
+    instance.window().set_size(LogicalSize::new(100., 70.));
+
+    let layouts = instance.element_layouts();
+    assert_eq!(layouts.len(), 3);
+    assert_eq!(layouts[0].layout.as_ref().map(|l| l.padding_left), Some(5.));
+    assert_eq!(layouts[1].geometry.origin, LogicalPoint::new(5., 5.));
+    assert_eq!(layouts[1].geometry.size.width, 90.);
+    assert!(layouts[1].layout.is_none());
+
+    let geometries = layouts.iter().map(|l| l.geometry).collect::<Vec<_>>();
+    assert_eq!(element_at(&geometries, LogicalPoint::new(50., 10.)), 1);
+    assert_eq!(element_at(&geometries, LogicalPoint::new(1., 1.)), 0);
+    assert_eq!(element_at(&geometries, LogicalPoint::new(200., 200.)), -1);
+
+    let gaps = gap_values(&layouts);
+    assert_eq!(gaps.len(), 1);
+    let Value::Struct(gap) = &gaps[0] else { panic!("{gaps:?}") };
+    assert_eq!(gap.get_field("y"), Some(&Value::Number(25.)));
+    assert_eq!(gap.get_field("height"), Some(&Value::Number(10.)));
+
+    // The values match the structs of the overlay
+    let mut compiler = ComponentCompiler::default();
+    let overlay = spin_on::spin_on(
+        compiler.build_from_source(include_str!("layout-overlay.slint").into(), Default::default()),
+    );
+    assert!(compiler.diagnostics().is_empty(), "{:?}", compiler.diagnostics());
+    let overlay = overlay.unwrap();
+    let field_names = |name: &str| {
+        let ty = overlay.properties_and_callbacks().find(|(n, _)| n == name).unwrap().1;
+        let Type::Array(ty) = ty else { panic!("{name}: {ty:?}") };
+        let Type::Struct { fields, .. } = &*ty else { panic!("{name}: {ty:?}") };
+        fields.keys().cloned().collect::<Vec<_>>()
+    };
+    let value_names = |value: &Value| {
+        let Value::Struct(s) = value else { panic!("{value:?}") };
+        let mut names = s.iter().map(|(name, _)| name.to_string()).collect::<Vec<_>>();
+        names.sort();
+        names
+    };
+    assert_eq!(value_names(&element_value(&layouts[0])), field_names("elements"));
+    assert_eq!(value_names(&gaps[0]), field_names("gaps"));
+}
@@ -28,7 +28,7 @@ fn main() -> std::io::Result<()> {
             continue;
         }
         #[cfg(feature = "build-time")]
-        generate_source(&source, &mut output, testcase)?;
+        generate_source(&source, &mut output, &module_name, testcase)?;
 
         for (i, x) in test_driver_lib::extract_test_functions(&source)
             .filter(|x| x.language_id == "rust")
@@ -114,6 +114,7 @@ fn generate_macro(
 fn generate_source(
     source: &str,
     output: &mut impl Write,
+    module_name: &str,
     testcase: test_driver_lib::TestCase,
 ) -> Result<(), std::io::Error> {
     use i_slint_compiler::{diagnostics::BuildDiagnostics, *};
@@ -133,6 +134,7 @@ fn generate_source(
     compiler_config.include_paths = include_paths;
     compiler_config.library_paths = library_paths;
     compiler_config.style = Some("fluent".to_string());
+    compiler_config.split_modules = cfg!(feature = "split-modules");
     let (root_component, diag) =
         spin_on::spin_on(compile_syntax_node(syntax_node, diag, compiler_config));
 
@@ -146,6 +148,23 @@ fn generate_source(
         diag.print();
     }
 
-    generator::generate(generator::OutputFormat::Rust, output, &root_component)?;
+    if cfg!(feature = "split-modules") {
+        // Each global and component goes in its own file, like slint-build does with_split_modules
+        let mut module_error = None;
+        let generated = generator::rust::generate_with_modules(&root_component, |name, _, code| {
+            let module_path = Path::new(&std::env::var_os("OUT_DIR").unwrap())
+                .join(format!("{module_name}_{name}.rs"));
+            if let Err(e) = std::fs::write(&module_path, code.to_string()) {
+                module_error.get_or_insert(e);
+            }
+            module_path.display().to_string()
+        });
+        if let Some(e) = module_error {
+            return Err(e);
+        }
+        write!(output, "{}", generated)?;
+    } else {
+        generator::generate(generator::OutputFormat::Rust, output, &root_component)?;
+    }
     Ok(())
 }
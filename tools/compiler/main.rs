@@ -62,6 +62,11 @@ struct Cli {
     /// Translation domain
     #[arg(long = "translation-domain", action)]
     translation_domain: Option<String>,
+
+    /// Warn about private properties, callbacks, functions, element ids, imports
+    /// and components that are never used
+    #[arg(long, action)]
+    warn_unused: bool,
 }
 
 fn main() -> std::io::Result<()> {
@@ -76,6 +81,7 @@ fn main() -> std::io::Result<()> {
     }
     let mut compiler_config = CompilerConfiguration::new(args.format);
     compiler_config.translation_domain = args.translation_domain;
+    compiler_config.warn_unused = args.warn_unused;
 
     // Override defaults from command line:
     if let Some(embed) = args.embed_resources {
@@ -4,16 +4,61 @@
 use crate::writer::TokenWriter;
 use i_slint_compiler::parser::{syntax_nodes, NodeOrToken, SyntaxKind, SyntaxNode};
 
+/// Where the opening brace of elements and states is placed
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BraceStyle {
+    /// On the same line as the element type: `Text {`
+    #[default]
+    SameLine,
+    /// On its own line, below the element type
+    NextLine,
+}
+
+/// The options of the formatter
+#[derive(Clone, Debug)]
+pub struct FormatConfig {
+    /// The number of spaces per level of indentation, when not using tabs
+    pub indent_width: u32,
+    /// Indent with one tab per level instead of spaces
+    pub use_tabs: bool,
+    /// Arrays that don't fit within that many columns are written with one element per line.
+    /// Only arrays are wrapped, and they are kept on one line when this is `None`.
+    pub max_line_length: Option<usize>,
+    /// Where the opening brace of elements and states is placed
+    pub brace_style: BraceStyle,
+}
+
+impl Default for FormatConfig {
+    fn default() -> Self {
+        Self {
+            indent_width: 4,
+            use_tabs: false,
+            max_line_length: None,
+            brace_style: BraceStyle::SameLine,
+        }
+    }
+}
+
 pub fn format_document(
     doc: syntax_nodes::Document,
     writer: &mut impl TokenWriter,
 ) -> Result<(), std::io::Error> {
-    let mut state = FormatState::default();
+    format_document_with_config(doc, FormatConfig::default(), writer)
+}
+
+pub fn format_document_with_config(
+    doc: syntax_nodes::Document,
+    config: FormatConfig,
+    writer: &mut impl TokenWriter,
+) -> Result<(), std::io::Error> {
+    let mut state = FormatState { config, ..Default::default() };
     format_node(&doc, writer, &mut state)
 }
 
 #[derive(Default)]
 struct FormatState {
+    config: FormatConfig,
+
     /// The whitespace have been written, all further whitespace can be skipped
     skip_all_whitespace: bool,
     /// The whitespace to add before the next token
@@ -38,11 +83,23 @@ fn new_line(&mut self) {
             x.insert(0, '\n');
             return;
         }
-        let mut new_line = String::from("\n");
-        for _ in 0..self.indentation_level {
-            new_line += "    ";
+        self.whitespace_to_add = Some(format!("\n{}", self.indentation()));
+    }
+
+    fn indentation(&self) -> String {
+        if self.config.use_tabs {
+            "\t".repeat(self.indentation_level as usize)
+        } else {
+            " ".repeat((self.indentation_level * self.config.indent_width) as usize)
+        }
+    }
+
+    /// The whitespace to insert before the opening brace of an element or a state
+    fn brace_prefix(&self) -> String {
+        match self.config.brace_style {
+            BraceStyle::SameLine => " ".into(),
+            BraceStyle::NextLine => format!("\n{}", self.indentation()),
         }
-        self.whitespace_to_add = Some(new_line);
     }
 
     fn insert_whitespace(&mut self, arg: &str) {
@@ -242,12 +299,21 @@ fn format_component(
         let mut sub = node.children_with_tokens();
         let _ok = whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, "")?
             && whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, " ")?;
+        // Without a base type, the element starts with its opening brace
+        let element_prefix = if node
+            .child_node(SyntaxKind::Element)
+            .map_or(false, |e| e.child_node(SyntaxKind::QualifiedName).is_none())
+        {
+            state.brace_prefix()
+        } else {
+            " ".into()
+        };
         let r = whitespace_to_one_of(
             &mut sub,
             &[SyntaxKind::Identifier, SyntaxKind::Element],
             writer,
             state,
-            " ",
+            &element_prefix,
         )?;
         if r == SyntaxMatch::Found(SyntaxKind::Identifier) {
             whitespace_to(&mut sub, SyntaxKind::Element, writer, state, " ")?;
@@ -267,9 +333,10 @@ fn format_element(
 ) -> Result<(), std::io::Error> {
     let mut sub = node.children_with_tokens();
 
+    let brace_prefix = state.brace_prefix();
     let ok = if node.child_node(SyntaxKind::QualifiedName).is_some() {
         whitespace_to(&mut sub, SyntaxKind::QualifiedName, writer, state, "")?
-            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?
+            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, &brace_prefix)?
     } else {
         whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, "")?
     };
@@ -707,6 +774,39 @@ fn format_array(
     let mut sub = node.children_with_tokens();
     whitespace_to(&mut sub, SyntaxKind::LBracket, writer, state, "")?;
 
+    // The column is not known, assume that the array starts after the indentation
+    let indentation_width = if state.config.use_tabs {
+        state.indentation_level as usize
+    } else {
+        (state.indentation_level * state.config.indent_width) as usize
+    };
+    if state
+        .config
+        .max_line_length
+        .map_or(false, |max| indentation_width + single_line_length(node) > max)
+    {
+        // One element per line
+        state.indentation_level += 1;
+        state.new_line();
+        for n in sub {
+            state.skip_all_whitespace = true;
+            match n.kind() {
+                SyntaxKind::Comma => {
+                    fold(n, writer, state)?;
+                    state.new_line();
+                }
+                SyntaxKind::RBracket => {
+                    state.indentation_level -= 1;
+                    state.whitespace_to_add = None;
+                    state.new_line();
+                    fold(n, writer, state)?;
+                }
+                _ => fold(n, writer, state)?,
+            }
+        }
+        return Ok(());
+    }
+
     loop {
         whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, "")?;
         let el = whitespace_to_one_of(
@@ -732,6 +832,18 @@ fn format_array(
     Ok(())
 }
 
+/// The length of the node when written on a single line, with one space after the commas
+fn single_line_length(node: &SyntaxNode) -> usize {
+    node.descendants_with_tokens()
+        .filter_map(|n| n.into_token())
+        .map(|t| match t.kind() {
+            SyntaxKind::Whitespace => 0,
+            SyntaxKind::Comma => 2,
+            _ => t.text().len(),
+        })
+        .sum()
+}
+
 fn format_state(
     node: &SyntaxNode,
     writer: &mut impl TokenWriter,
@@ -739,16 +851,17 @@ fn format_state(
 ) -> Result<(), std::io::Error> {
     let has_when = node.child_text(SyntaxKind::Identifier).map_or(false, |x| x == "when");
     let mut sub = node.children_with_tokens();
+    let brace_prefix = state.brace_prefix();
     let ok = if has_when {
         whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, "")?
             && whitespace_to(&mut sub, SyntaxKind::Identifier, writer, state, " ")?
             && whitespace_to(&mut sub, SyntaxKind::Expression, writer, state, " ")?
             && whitespace_to(&mut sub, SyntaxKind::Colon, writer, state, "")?
-            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?
+            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, &brace_prefix)?
     } else {
         whitespace_to(&mut sub, SyntaxKind::DeclaredIdentifier, writer, state, "")?
             && whitespace_to(&mut sub, SyntaxKind::Colon, writer, state, "")?
-            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, " ")?
+            && whitespace_to(&mut sub, SyntaxKind::LBrace, writer, state, &brace_prefix)?
     };
     if !ok {
         finish_node(sub, writer, state)?;
@@ -786,6 +899,10 @@ mod tests {
 
     // FIXME more descriptive errors when an assertion fails
     fn assert_formatting(unformatted: &str, formatted: &str) {
+        assert_formatting_with_config(unformatted, formatted, FormatConfig::default())
+    }
+
+    fn assert_formatting_with_config(unformatted: &str, formatted: &str, config: FormatConfig) {
         // Parse the unformatted string
         let syntax_node = i_slint_compiler::parser::parse(
             String::from(unformatted),
@@ -796,7 +913,7 @@ fn assert_formatting(unformatted: &str, formatted: &str) {
         // Turn the syntax node into a document
         let doc = syntax_nodes::Document::new(syntax_node).unwrap();
         let mut file = Vec::new();
-        format_document(doc, &mut FileWriter { file: &mut file }).unwrap();
+        format_document_with_config(doc, config, &mut FileWriter { file: &mut file }).unwrap();
         assert_eq!(String::from_utf8(file).unwrap(), formatted);
     }
 
@@ -1163,4 +1280,32 @@ fn if_else() {
 "#,
         );
     }
+
+    #[test]
+    fn configuration() {
+        let config = FormatConfig {
+            indent_width: 2,
+            brace_style: BraceStyle::NextLine,
+            ..Default::default()
+        };
+        assert_formatting_with_config(
+            "export component A {Rectangle{x:1px;}}",
+            "export component A\n{\n  Rectangle\n  {\n    x: 1px;\n  }\n}\n",
+            config,
+        );
+
+        let config =
+            FormatConfig { use_tabs: true, max_line_length: Some(20), ..Default::default() };
+        assert_formatting_with_config(
+            "A := B { c: [1,2]; d: [1000,2000,3000,4000,5000]; }",
+            "A := B {\n\tc: [1, 2];\n\td: [\n\t\t1000,\n\t\t2000,\n\t\t3000,\n\t\t4000,\n\t\t5000\n\t];\n}\n",
+            config,
+        );
+
+        // Arrays are not wrapped by default
+        assert_formatting(
+            "A := B { d: [1000,2000,3000,4000,5000,6000,7000,8000,9000,10000,11000,12000,13000,14000,15000]; }",
+            "A := B {\n    d: [1000, 2000, 3000, 4000, 5000, 6000, 7000, 8000, 9000, 10000, 11000, 12000, 13000, 14000, 15000];\n}\n",
+        );
+    }
 }
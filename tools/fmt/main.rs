@@ -34,26 +34,56 @@ struct Cli {
     /// modify the file inline instead of printing to stdout
     #[arg(short, long, action)]
     inline: bool,
+
+    /// number of spaces per level of indentation
+    #[arg(long, default_value_t = 4)]
+    indent_width: u32,
+
+    /// indent with tabs instead of spaces
+    #[arg(long, action)]
+    use_tabs: bool,
+
+    /// write the arrays longer than this with one element per line (by default, arrays are kept on one line)
+    #[arg(long)]
+    max_line_length: Option<usize>,
+
+    /// put the opening brace of elements and states on its own line
+    #[arg(long, action)]
+    brace_on_next_line: bool,
 }
 
 fn main() -> std::io::Result<()> {
     let args = Cli::parse();
+    let config = fmt::FormatConfig {
+        indent_width: args.indent_width,
+        use_tabs: args.use_tabs,
+        max_line_length: args.max_line_length,
+        brace_style: if args.brace_on_next_line {
+            fmt::BraceStyle::NextLine
+        } else {
+            fmt::BraceStyle::SameLine
+        },
+    };
 
     for path in args.paths {
         let source = std::fs::read_to_string(&path)?;
 
         if args.inline {
             let file = BufWriter::new(std::fs::File::create(&path)?);
-            process_file(source, path, file)?
+            process_file(source, path, file, &config)?
         } else {
-            process_file(source, path, std::io::stdout())?
+            process_file(source, path, std::io::stdout(), &config)?
         }
     }
     Ok(())
 }
 
 /// FIXME! this is duplicated with the updater
-fn process_rust_file(source: String, mut file: impl Write) -> std::io::Result<()> {
+fn process_rust_file(
+    source: String,
+    mut file: impl Write,
+    config: &fmt::FormatConfig,
+) -> std::io::Result<()> {
     let mut last = 0;
     for range in i_slint_compiler::lexer::locate_slint_macro(&source) {
         file.write_all(source[last..=range.start].as_bytes())?;
@@ -63,7 +93,7 @@ fn process_rust_file(source: String, mut file: impl Write) -> std::io::Result<()
         let mut diag = BuildDiagnostics::default();
         let syntax_node = i_slint_compiler::parser::parse(code.to_owned(), None, None, &mut diag);
         let len = syntax_node.text_range().end().into();
-        visit_node(syntax_node, &mut file)?;
+        visit_node(syntax_node, &mut file, config)?;
         if diag.has_error() {
             file.write_all(&code.as_bytes()[len..])?;
             diag.print();
@@ -73,7 +103,11 @@ fn process_rust_file(source: String, mut file: impl Write) -> std::io::Result<()
 }
 
 /// FIXME! this is duplicated with the updater
-fn process_markdown_file(source: String, mut file: impl Write) -> std::io::Result<()> {
+fn process_markdown_file(
+    source: String,
+    mut file: impl Write,
+    config: &fmt::FormatConfig,
+) -> std::io::Result<()> {
     let mut source_slice = &source[..];
     const CODE_FENCE_START: &str = "```slint\n";
     const CODE_FENCE_END: &str = "```\n";
@@ -93,7 +127,7 @@ fn process_markdown_file(source: String, mut file: impl Write) -> std::io::Resul
         let mut diag = BuildDiagnostics::default();
         let syntax_node = i_slint_compiler::parser::parse(code.to_owned(), None, None, &mut diag);
         let len = syntax_node.text_range().end().into();
-        visit_node(syntax_node, &mut file)?;
+        visit_node(syntax_node, &mut file, config)?;
         if diag.has_error() {
             file.write_all(&code.as_bytes()[len..])?;
             diag.print();
@@ -106,11 +140,12 @@ fn process_slint_file(
     source: String,
     path: std::path::PathBuf,
     mut file: impl Write,
+    config: &fmt::FormatConfig,
 ) -> std::io::Result<()> {
     let mut diag = BuildDiagnostics::default();
     let syntax_node = i_slint_compiler::parser::parse(source.clone(), Some(&path), None, &mut diag);
     let len = syntax_node.node.text_range().end().into();
-    visit_node(syntax_node, &mut file)?;
+    visit_node(syntax_node, &mut file, config)?;
     if diag.has_error() {
         file.write_all(&source.as_bytes()[len..])?;
         diag.print();
@@ -122,16 +157,19 @@ fn process_file(
     source: String,
     path: std::path::PathBuf,
     mut file: impl Write,
+    config: &fmt::FormatConfig,
 ) -> std::io::Result<()> {
     match path.extension() {
-        Some(ext) if ext == "rs" => process_rust_file(source, file),
-        Some(ext) if ext == "md" => process_markdown_file(source, file),
+        Some(ext) if ext == "rs" => process_rust_file(source, file, config),
+        Some(ext) if ext == "md" => process_markdown_file(source, file, config),
         // Formatting .60 files because of backwards compatibility (project was recently renamed)
-        Some(ext) if ext == "slint" || ext == ".60" => process_slint_file(source, path, file),
+        Some(ext) if ext == "slint" || ext == ".60" => {
+            process_slint_file(source, path, file, config)
+        }
         _ => {
             // This allows usage like `cat x.slint | slint-fmt /dev/stdin`
             if path.as_path() == Path::new("/dev/stdin") {
-                return process_slint_file(source, path, file);
+                return process_slint_file(source, path, file, config);
             }
             // With other file types, we just output them in their original form.
             return file.write_all(source.as_bytes());
@@ -139,10 +177,14 @@ fn process_file(
     }
 }
 
-fn visit_node(node: SyntaxNode, file: &mut impl Write) -> std::io::Result<()> {
+fn visit_node(
+    node: SyntaxNode,
+    file: &mut impl Write,
+    config: &fmt::FormatConfig,
+) -> std::io::Result<()> {
     if let Some(doc) = syntax_nodes::Document::new(node) {
         let mut writer = writer::FileWriter { file };
-        fmt::format_document(doc, &mut writer)
+        fmt::format_document_with_config(doc, config.clone(), &mut writer)
     } else {
         Err(std::io::Error::new(std::io::ErrorKind::Other, "Not a Document"))
     }
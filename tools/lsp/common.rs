@@ -7,7 +7,7 @@
     object_tree::Element,
     parser::{syntax_nodes, SyntaxKind},
 };
-use lsp_types::Url;
+use lsp_types::{Range, Url};
 
 use std::{collections::HashMap, path::PathBuf};
 
@@ -55,12 +55,35 @@ pub struct Position {
     pub offset: u32,
 }
 
+/// A device the component is previewed on in the preview matrix
+#[derive(Clone, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PreviewDevice {
+    pub name: String,
+    /// The width of the screen, in physical pixels
+    pub width: u32,
+    /// The height of the screen, in physical pixels
+    pub height: u32,
+    /// The scale factor of the screen, used to compute its size in logical pixels. The cells of
+    /// the matrix are rendered with the scale factor of the preview window.
+    #[serde(default = "default_scale_factor")]
+    pub scale_factor: f32,
+}
+
+fn default_scale_factor() -> f32 {
+    1.0
+}
+
 #[derive(Default, Clone, PartialEq, Debug, serde::Deserialize, serde::Serialize)]
 pub struct PreviewConfig {
     pub hide_ui: Option<bool>,
     pub style: String,
     pub include_paths: Vec<PathBuf>,
     pub library_paths: HashMap<String, PathBuf>,
+    /// The devices of the preview matrix
+    pub devices: Vec<PreviewDevice>,
+    /// The styles of the preview matrix
+    pub matrix_styles: Vec<String>,
 }
 
 /// The Component to preview
@@ -105,6 +128,8 @@ pub enum PreviewToLspMessage {
     ShowDocument { file: Url, selection: lsp_types::Range },
     PreviewTypeChanged { is_external: bool },
     RequestState { unused: bool }, // send all documents!
+    SetBinding { url: VersionedUrl, element_range: Range, property: String, expression: String },
+    RemoveBinding { url: VersionedUrl, element_range: Range, property: String },
 }
 
 /// Information on the Element types available
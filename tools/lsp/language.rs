@@ -3,13 +3,21 @@
 
 // cSpell: ignore descr rfind unindented
 
+mod call_hierarchy;
 mod completion;
 mod component_catalog;
+mod folding_range;
+mod formatting;
 mod goto;
-mod properties;
+mod inlay_hints;
+pub(crate) mod properties;
+mod refactoring;
+mod references;
 mod semantic_tokens;
+mod signature_help;
 #[cfg(test)]
 mod test;
+mod workspace_symbols;
 
 use crate::common::{LspToPreviewMessage, PreviewComponent, PreviewConfig, Result, VersionedUrl};
 use crate::language::properties::find_element_indent;
@@ -28,17 +36,22 @@
 };
 use i_slint_compiler::{typeloader::TypeLoader, typeregister::TypeRegister};
 use lsp_types::request::{
+    CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
     CodeActionRequest, CodeLensRequest, ColorPresentationRequest, Completion, DocumentColor,
-    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, GotoDefinition, HoverRequest,
-    PrepareRenameRequest, Rename, SemanticTokensFullRequest,
+    DocumentHighlightRequest, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest,
+    Formatting, GotoDefinition, HoverRequest, InlayHintRequest, OnTypeFormatting,
+    PrepareRenameRequest, RangeFormatting, References, Rename, SemanticTokensFullRequest,
+    SignatureHelpRequest, WorkspaceSymbolRequest,
 };
 use lsp_types::{
-    ClientCapabilities, CodeActionOrCommand, CodeActionProviderCapability, CodeLens,
-    CodeLensOptions, Color, ColorInformation, ColorPresentation, Command, CompletionOptions,
-    DocumentSymbol, DocumentSymbolResponse, Hover, InitializeParams, InitializeResult, OneOf,
-    Position, PrepareRenameResponse, PublishDiagnosticsParams, RenameOptions,
-    SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions, ServerCapabilities,
-    ServerInfo, TextDocumentSyncCapability, TextEdit, Url, WorkDoneProgressOptions, WorkspaceEdit,
+    CallHierarchyServerCapability, ClientCapabilities, CodeActionOrCommand,
+    CodeActionProviderCapability, CodeLens, CodeLensOptions, Color, ColorInformation,
+    ColorPresentation, Command, CompletionOptions, DocumentOnTypeFormattingOptions, DocumentSymbol,
+    DocumentSymbolResponse, FoldingRangeProviderCapability, Hover, InitializeParams,
+    InitializeResult, OneOf, Position, PrepareRenameResponse, PublishDiagnosticsParams,
+    RenameOptions, SemanticTokensFullOptions, SemanticTokensLegend, SemanticTokensOptions,
+    ServerCapabilities, ServerInfo, SignatureHelpOptions, TextDocumentSyncCapability, TextEdit,
+    Url, WorkDoneProgressOptions, WorkspaceEdit, WorkspaceSymbolResponse,
 };
 use std::cell::RefCell;
 use std::collections::HashMap;
@@ -118,13 +131,14 @@ pub fn request_state(ctx: &std::rc::Rc<Context>) {
 pub struct DocumentCache {
     pub(crate) documents: TypeLoader,
     preview_config: PreviewConfig,
+    format_settings: formatting::FormatSettings,
 }
 
 impl DocumentCache {
     pub fn new(config: CompilerConfiguration) -> Self {
         let documents =
             TypeLoader::new(TypeRegister::builtin(), config, &mut BuildDiagnostics::default());
-        Self { documents, preview_config: Default::default() }
+        Self { documents, preview_config: Default::default(), format_settings: Default::default() }
     }
 
     pub fn document_version(&self, target_uri: &lsp_types::Url) -> SourceFileVersion {
@@ -188,7 +202,20 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 all_commit_characters: None,
                 completion_item: None,
             }),
+            signature_help_provider: Some(SignatureHelpOptions {
+                trigger_characters: Some(vec!["(".to_owned(), ",".to_owned()]),
+                retrigger_characters: None,
+                work_done_progress_options: WorkDoneProgressOptions::default(),
+            }),
+            inlay_hint_provider: Some(OneOf::Left(true)),
             definition_provider: Some(OneOf::Left(true)),
+            references_provider: Some(OneOf::Left(true)),
+            document_formatting_provider: Some(OneOf::Left(true)),
+            document_range_formatting_provider: Some(OneOf::Left(true)),
+            document_on_type_formatting_provider: Some(DocumentOnTypeFormattingOptions {
+                first_trigger_character: "}".into(),
+                more_trigger_character: Some(vec![";".into()]),
+            }),
             text_document_sync: Some(TextDocumentSyncCapability::Kind(
                 lsp_types::TextDocumentSyncKind::FULL,
             )),
@@ -198,6 +225,9 @@ pub fn server_initialize_result(client_cap: &ClientCapabilities) -> InitializeRe
                 ..Default::default()
             }),
             document_symbol_provider: Some(OneOf::Left(true)),
+            workspace_symbol_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
+            call_hierarchy_provider: Some(CallHierarchyServerCapability::Simple(true)),
             color_provider: Some(true.into()),
             code_lens_provider: Some(CodeLensOptions { resolve_provider: Some(true) }),
             semantic_tokens_provider: Some(
@@ -249,6 +279,83 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
         .and_then(|token| goto::goto_definition(document_cache, token.0));
         Ok(result)
     });
+    rh.register::<References, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position.text_document.uri,
+            &params.text_document_position.position,
+        )
+        .and_then(|token| {
+            references::find_references(document_cache, token.0, params.context.include_declaration)
+        });
+        Ok(result)
+    });
+    rh.register::<WorkspaceSymbolRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(Some(WorkspaceSymbolResponse::Nested(workspace_symbols::workspace_symbols(
+            document_cache,
+            &params.query,
+        ))))
+    });
+    rh.register::<FoldingRangeRequest, _>(|params, ctx| async move {
+        let document_cache = &ctx.document_cache.borrow();
+        Ok(folding_range::folding_ranges(document_cache, &params.text_document.uri))
+    });
+    rh.register::<CallHierarchyPrepare, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position_params.text_document.uri,
+            &params.text_document_position_params.position,
+        )
+        .and_then(|(token, _)| call_hierarchy::prepare_call_hierarchy(document_cache, token));
+        Ok(result)
+    });
+    rh.register::<CallHierarchyIncomingCalls, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(call_hierarchy::incoming_calls(document_cache, &params.item))
+    });
+    rh.register::<CallHierarchyOutgoingCalls, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        Ok(call_hierarchy::outgoing_calls(document_cache, &params.item))
+    });
+    rh.register::<SignatureHelpRequest, _>(|params, ctx| async move {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let result = token_descr(
+            document_cache,
+            &params.text_document_position_params.text_document.uri,
+            &params.text_document_position_params.position,
+        )
+        .and_then(|(token, offset)| signature_help::signature_help(document_cache, token, offset));
+        Ok(result)
+    });
+    rh.register::<InlayHintRequest, _>(|params, ctx| async move {
+        let document_cache = &ctx.document_cache.borrow();
+        Ok(inlay_hints::inlay_hints(document_cache, &params.text_document.uri, &params.range))
+    });
+    rh.register::<Formatting, _>(|params, ctx| async move {
+        let document_cache = &ctx.document_cache.borrow();
+        Ok(formatting::format_document(document_cache, &params.text_document.uri, &params.options))
+    });
+    rh.register::<RangeFormatting, _>(|params, ctx| async move {
+        let document_cache = &ctx.document_cache.borrow();
+        Ok(formatting::format_range(
+            document_cache,
+            &params.text_document.uri,
+            params.range,
+            &params.options,
+        ))
+    });
+    rh.register::<OnTypeFormatting, _>(|params, ctx| async move {
+        let document_cache = &ctx.document_cache.borrow();
+        Ok(formatting::format_on_type(
+            document_cache,
+            &params.text_document_position.text_document.uri,
+            params.text_document_position.position,
+            &params.options,
+        ))
+    });
     rh.register::<Completion, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
 
@@ -288,11 +395,19 @@ pub fn register_request_handlers(rh: &mut RequestHandler) {
     rh.register::<CodeActionRequest, _>(|params, ctx| async move {
         let document_cache = &mut ctx.document_cache.borrow_mut();
 
-        let result = token_descr(document_cache, &params.text_document.uri, &params.range.start)
-            .and_then(|(token, _)| {
-                get_code_actions(document_cache, token, &ctx.init_param.capabilities)
-            });
-        Ok(result)
+        let mut result =
+            token_descr(document_cache, &params.text_document.uri, &params.range.start)
+                .and_then(|(token, _)| {
+                    get_code_actions(document_cache, token, &ctx.init_param.capabilities)
+                })
+                .unwrap_or_default();
+        result.extend(refactoring::refactoring_actions(
+            document_cache,
+            &params.text_document.uri,
+            &params.range,
+            &ctx.init_param.capabilities,
+        ));
+        Ok((!result.is_empty()).then_some(result))
     });
     rh.register::<ExecuteCommand, _>(|params, ctx| async move {
         if params.command.as_str() == SHOW_PREVIEW_COMMAND {
@@ -657,6 +772,33 @@ pub async fn remove_binding_command(
     Ok(serde_json::to_value(()).expect("Failed to serialize ()!"))
 }
 
+/// Change a binding on behalf of the property editor of the live preview.
+///
+/// The binding is removed when `expression` is `None`.
+#[cfg(feature = "preview-engine")]
+pub async fn update_binding_from_preview(
+    ctx: &Rc<Context>,
+    url: VersionedUrl,
+    element_range: lsp_types::Range,
+    property_name: String,
+    expression: Option<String>,
+) -> Result<()> {
+    let text_document =
+        lsp_types::OptionalVersionedTextDocumentIdentifier { uri: url.url, version: url.version };
+    let mut params = vec![
+        serde_json::to_value(text_document)?,
+        serde_json::to_value(element_range)?,
+        serde_json::Value::from(property_name),
+    ];
+    if let Some(expression) = expression {
+        params.extend([serde_json::Value::from(expression), serde_json::Value::from(false)]);
+        set_binding_command(&params, ctx).await?;
+    } else {
+        remove_binding_command(&params, ctx).await?;
+    }
+    Ok(())
+}
+
 pub(crate) async fn reload_document_impl(
     ctx: Option<&Rc<Context>>,
     mut content: String,
@@ -1137,11 +1279,7 @@ fn get_document_symbols(
                 range: map_node(&component_node)?,
                 selection_range,
                 name: c.id.clone(),
-                kind: if c.is_global() {
-                    lsp_types::SymbolKind::OBJECT
-                } else {
-                    lsp_types::SymbolKind::CLASS
-                },
+                kind: workspace_symbols::component_symbol_kind(c),
                 children: gen_children(&c.root_element, &ds),
                 ..ds.clone()
             })
@@ -1210,7 +1348,10 @@ fn get_code_lenses(
         let mut r = vec![];
 
         // Handle preview lens
-        r.extend(inner_components.iter().filter(|c| !c.is_global()).filter_map(|c| {
+        r.extend(inner_components.iter().filter_map(|c| {
+            if c.is_global() || c.is_interface {
+                return None;
+            }
             Some(CodeLens {
                 range: map_node(c.root_element.borrow().node.first()?)?,
                 command: Some(create_show_preview_command(true, &text_document.uri, c.id.as_str())),
@@ -1290,6 +1431,168 @@ fn recurse(
     None
 }
 
+/// A pattern of a `.gitignore` file. Only the patterns matching a file name are supported: the
+/// patterns with a `/` in the middle and the negated patterns are ignored.
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Clone)]
+struct IgnorePattern {
+    glob: String,
+    /// The pattern started with a `/`, so it only applies to the directory of the `.gitignore`
+    anchored: bool,
+    /// The pattern ended with a `/`, so it only matches directories
+    directories_only: bool,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl IgnorePattern {
+    fn parse_gitignore(content: &str) -> impl Iterator<Item = Self> + '_ {
+        content.lines().map(str::trim).filter_map(|line| {
+            if line.is_empty() || line.starts_with('#') || line.starts_with('!') {
+                return None;
+            }
+            let (line, directories_only) =
+                line.strip_suffix('/').map_or((line, false), |l| (l, true));
+            let (glob, anchored) = line.strip_prefix('/').map_or((line, false), |l| (l, true));
+            (!glob.is_empty() && !glob.contains('/')).then(|| Self {
+                glob: glob.into(),
+                anchored,
+                directories_only,
+            })
+        })
+    }
+
+    fn matches(&self, name: &str, is_dir: bool) -> bool {
+        fn glob_match(glob: &[u8], name: &[u8]) -> bool {
+            match (glob.first(), name.first()) {
+                (None, None) => true,
+                (Some(b'*'), _) => {
+                    glob_match(&glob[1..], name)
+                        || (!name.is_empty() && glob_match(glob, &name[1..]))
+                }
+                (Some(b'?'), Some(_)) => glob_match(&glob[1..], &name[1..]),
+                (Some(g), Some(n)) if g == n => glob_match(&glob[1..], &name[1..]),
+                _ => false,
+            }
+        }
+        (is_dir || !self.directories_only) && glob_match(self.glob.as_bytes(), name.as_bytes())
+    }
+}
+
+/// Collect the .slint files in `dir` and its sub-directories, skipping the hidden files, the
+/// symbolic links, and the files ignored by the `.gitignore` files.
+#[cfg(not(target_arch = "wasm32"))]
+fn collect_slint_files(
+    dir: &std::path::Path,
+    inherited: &[IgnorePattern],
+    files: &mut Vec<PathBuf>,
+) {
+    let mut patterns = inherited.to_vec();
+    if let Ok(gitignore) = std::fs::read_to_string(dir.join(".gitignore")) {
+        patterns.extend(IgnorePattern::parse_gitignore(&gitignore));
+    }
+    let Ok(entries) = std::fs::read_dir(dir) else { return };
+    for entry in entries.flatten() {
+        // Symbolic links are not followed, they could make a cycle
+        let Ok(file_type) = entry.file_type() else { continue };
+        let name = entry.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with('.')
+            || file_type.is_symlink()
+            || patterns.iter().any(|p| p.matches(&name, file_type.is_dir()))
+        {
+            continue;
+        }
+        let path = entry.path();
+        if file_type.is_dir() {
+            let inherited = patterns.iter().filter(|p| !p.anchored).cloned().collect::<Vec<_>>();
+            collect_slint_files(&path, &inherited, files);
+        } else if path.extension().map_or(false, |e| e == "slint") {
+            files.push(clean_path(&path));
+        }
+    }
+}
+
+/// Load the .slint files of the workspace folders that are not loaded yet, so that the requests
+/// that search the whole project find them.
+///
+/// This is done once at startup. The client then reports the changes of the files, see
+/// [`register_file_watcher`] and [`workspace_files_changed`].
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn load_workspace_files(ctx: &Context) {
+    #[allow(deprecated)]
+    let roots = match &ctx.init_param.workspace_folders {
+        Some(folders) => folders.iter().map(|f| f.uri.clone()).collect::<Vec<_>>(),
+        None => ctx.init_param.root_uri.iter().cloned().collect(),
+    };
+    let mut files = Vec::new();
+    for root in roots.iter().filter_map(uri_to_file) {
+        collect_slint_files(&root, &[], &mut files);
+    }
+    let document_cache = &mut ctx.document_cache.borrow_mut();
+    for path in files {
+        if document_cache.documents.get_document(&path).is_some() {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        let mut diag = BuildDiagnostics::default();
+        document_cache.documents.load_file(&path, None, &path, content, false, &mut diag).await;
+    }
+}
+
+/// Ask the client to report the changes of the .slint files with `workspace/didChangeWatchedFiles`
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn register_file_watcher(ctx: &Context) -> Result<()> {
+    let supported = ctx
+        .init_param
+        .capabilities
+        .workspace
+        .as_ref()
+        .and_then(|w| w.did_change_watched_files.as_ref()?.dynamic_registration)
+        .unwrap_or(false);
+    if !supported {
+        return Ok(());
+    }
+    let options = lsp_types::DidChangeWatchedFilesRegistrationOptions {
+        watchers: vec![lsp_types::FileSystemWatcher {
+            glob_pattern: lsp_types::GlobPattern::String("**/*.slint".into()),
+            kind: None,
+        }],
+    };
+    ctx.server_notifier
+        .send_request::<lsp_types::request::RegisterCapability>(lsp_types::RegistrationParams {
+            registrations: vec![lsp_types::Registration {
+                id: "slint-files".into(),
+                method: "workspace/didChangeWatchedFiles".into(),
+                register_options: Some(serde_json::to_value(options)?),
+            }],
+        })?
+        .await?;
+    Ok(())
+}
+
+/// Update the files that changed on disk. The documents that are open in the editor are
+/// skipped, the editor sends their content.
+#[cfg(not(target_arch = "wasm32"))]
+pub async fn workspace_files_changed(
+    ctx: &Rc<Context>,
+    params: lsp_types::DidChangeWatchedFilesParams,
+) -> Result<()> {
+    for change in params.changes {
+        let document_cache = &mut ctx.document_cache.borrow_mut();
+        let Some(path) = uri_to_file(&change.uri) else { continue };
+        if document_cache.document_version(&change.uri).is_some() {
+            continue;
+        }
+        if change.typ == lsp_types::FileChangeType::DELETED {
+            document_cache.documents.drop_document(&path);
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        reload_document(ctx, content, change.uri, None, document_cache).await?;
+    }
+    Ok(())
+}
+
 pub async fn load_configuration(ctx: &Context) -> Result<()> {
     if !ctx
         .init_param
@@ -1316,6 +1619,8 @@ pub async fn load_configuration(ctx: &Context) -> Result<()> {
 
     let document_cache = &mut ctx.document_cache.borrow_mut();
     let mut hide_ui = None;
+    let mut devices = Vec::new();
+    let mut matrix_styles = Vec::new();
     for v in r {
         if let Some(o) = v.as_object() {
             if let Some(ip) = o.get("includePaths").and_then(|v| v.as_array()) {
@@ -1340,6 +1645,15 @@ pub async fn load_configuration(ctx: &Context) -> Result<()> {
                 }
             }
             hide_ui = o.get("preview").and_then(|v| v.as_object()?.get("hide_ui")?.as_bool());
+            if let Some(d) = o.get("preview").and_then(|v| v.as_object()?.get("devices")) {
+                devices = serde_json::from_value(d.clone()).unwrap_or_default();
+            }
+            if let Some(s) = o.get("preview").and_then(|v| v.as_object()?.get("matrixStyles")) {
+                matrix_styles = serde_json::from_value(s.clone()).unwrap_or_default();
+            }
+            if let Some(format) = o.get("format").and_then(|v| v.as_object()) {
+                document_cache.format_settings = formatting::FormatSettings::from_json(format);
+            }
         }
     }
 
@@ -1353,6 +1667,8 @@ pub async fn load_configuration(ctx: &Context) -> Result<()> {
         style: cc.style.clone().unwrap_or_default(),
         include_paths: cc.include_paths.clone(),
         library_paths: cc.library_paths.clone(),
+        devices,
+        matrix_styles,
     };
     document_cache.preview_config = config.clone();
     ctx.server_notifier.send_preview_message(LspToPreviewMessage::SetConfiguration { config });
@@ -1929,4 +2245,51 @@ fn test_code_actions() {
             })])
         );
     }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn test_gitignore_patterns() {
+        let patterns = IgnorePattern::parse_gitignore(
+            "# comment\n/target\nnode_modules/\n*.bak\n!keep.slint\nsrc/generated\n",
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(patterns.len(), 3);
+        let ignored = |name, is_dir| patterns.iter().any(|p| p.matches(name, is_dir));
+        assert!(ignored("target", true));
+        assert!(ignored("node_modules", true));
+        assert!(!ignored("node_modules", false));
+        assert!(ignored("ui.slint.bak", false));
+        assert!(!ignored("ui.slint", false));
+        assert!(!ignored("keep.slint", false));
+        assert!(patterns[0].anchored && !patterns[1].anchored);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_collect_slint_files() {
+        let dir = std::env::temp_dir().join(format!("slint-lsp-workspace-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(dir.join("ui/widgets")).unwrap();
+        std::fs::create_dir_all(dir.join("build")).unwrap();
+        std::fs::write(dir.join(".gitignore"), "/build\n").unwrap();
+        std::fs::write(dir.join("ui/main.slint"), "").unwrap();
+        std::fs::write(dir.join("ui/widgets/button.slint"), "").unwrap();
+        std::fs::write(dir.join("ui/widgets/README.md"), "").unwrap();
+        std::fs::write(dir.join("build/generated.slint"), "").unwrap();
+        // A cycle must not make the walk recurse forever
+        std::os::unix::fs::symlink(&dir, dir.join("ui/widgets/loop")).unwrap();
+
+        let mut files = Vec::new();
+        collect_slint_files(&dir, &[], &mut files);
+        files.sort();
+        assert_eq!(
+            files,
+            vec![
+                clean_path(&dir.join("ui/main.slint")),
+                clean_path(&dir.join("ui/widgets/button.slint"))
+            ]
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
 }
@@ -0,0 +1,222 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Call hierarchy of the functions and callbacks.
+//!
+//! The callers are the functions, callback handlers and bindings that call the function or callback.
+
+use super::references::{definition_of, find_references};
+use super::{token_descr, DocumentCache};
+use crate::util::{map_node, map_node_and_url, map_token};
+
+use i_slint_compiler::parser::{
+    identifier_text, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken,
+};
+use lsp_types::{
+    CallHierarchyIncomingCall, CallHierarchyItem, CallHierarchyOutgoingCall, SymbolKind,
+};
+
+pub fn prepare_call_hierarchy(
+    document_cache: &mut DocumentCache,
+    token: SyntaxToken,
+) -> Option<Vec<CallHierarchyItem>> {
+    let declaration = callable_declaration(document_cache, &token)?;
+    Some(vec![call_hierarchy_item(&declaration)?])
+}
+
+pub fn incoming_calls(
+    document_cache: &mut DocumentCache,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyIncomingCall>> {
+    let (name, _) = token_descr(document_cache, &item.uri, &item.selection_range.start)?;
+    let mut calls: Vec<CallHierarchyIncomingCall> = Vec::new();
+    for location in find_references(document_cache, name, false)? {
+        let Some((token, _)) = token_descr(document_cache, &location.uri, &location.range.start)
+        else {
+            continue;
+        };
+        // Only the uses in expressions
+        if token.parent().kind() != SyntaxKind::QualifiedName {
+            continue;
+        }
+        let Some(from) = std::iter::successors(Some(token.parent()), |n| n.parent())
+            .find(|n| {
+                matches!(
+                    n.kind(),
+                    SyntaxKind::Function
+                        | SyntaxKind::CallbackConnection
+                        | SyntaxKind::Binding
+                        | SyntaxKind::PropertyDeclaration
+                )
+            })
+            .and_then(|n| call_hierarchy_item(&n))
+        else {
+            continue;
+        };
+        if let Some(call) = calls.iter_mut().find(|c| same_item(&c.from, &from)) {
+            call.from_ranges.push(location.range);
+        } else {
+            calls.push(CallHierarchyIncomingCall { from, from_ranges: vec![location.range] });
+        }
+    }
+    Some(calls)
+}
+
+pub fn outgoing_calls(
+    document_cache: &mut DocumentCache,
+    item: &CallHierarchyItem,
+) -> Option<Vec<CallHierarchyOutgoingCall>> {
+    let (name, _) = token_descr(document_cache, &item.uri, &item.selection_range.start)?;
+    let caller = std::iter::successors(Some(name.parent()), |n| n.parent())
+        .find(|n| map_node(n) == Some(item.range))?;
+
+    let mut calls: Vec<CallHierarchyOutgoingCall> = Vec::new();
+    for node in caller.descendants() {
+        let node = SyntaxNode { node, source_file: caller.source_file.clone() };
+        let Some(call) = syntax_nodes::FunctionCallExpression::new(node) else { continue };
+        let Some(callee) =
+            call.Expression().next().and_then(|e| e.QualifiedName()).and_then(|qn| {
+                qn.children_with_tokens()
+                    .filter_map(|t| t.into_token())
+                    .filter(|t| t.kind() == SyntaxKind::Identifier)
+                    .last()
+            })
+        else {
+            continue;
+        };
+        let Some(to) = callable_declaration(document_cache, &callee)
+            .and_then(|declaration| call_hierarchy_item(&declaration))
+        else {
+            continue;
+        };
+        let Some(range) = map_token(&callee) else { continue };
+        if let Some(call) = calls.iter_mut().find(|c| same_item(&c.to, &to)) {
+            call.from_ranges.push(range);
+        } else {
+            calls.push(CallHierarchyOutgoingCall { to, from_ranges: vec![range] });
+        }
+    }
+    Some(calls)
+}
+
+fn same_item(a: &CallHierarchyItem, b: &CallHierarchyItem) -> bool {
+    a.uri == b.uri && a.range == b.range
+}
+
+/// The Function or CallbackDeclaration node of the function or callback the token refers to
+fn callable_declaration(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+) -> Option<SyntaxNode> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let (uri, position) = definition_of(document_cache, token)?;
+    let (token, _) = token_descr(document_cache, &uri, &position)?;
+    std::iter::successors(Some(token.parent()), |n| n.parent())
+        .find(|n| matches!(n.kind(), SyntaxKind::Function | SyntaxKind::CallbackDeclaration))
+}
+
+fn call_hierarchy_item(node: &SyntaxNode) -> Option<CallHierarchyItem> {
+    let (name, selection_range, kind) = match node.kind() {
+        SyntaxKind::Function
+        | SyntaxKind::CallbackDeclaration
+        | SyntaxKind::PropertyDeclaration => {
+            let identifier = node.child_node(SyntaxKind::DeclaredIdentifier)?;
+            let kind = match node.kind() {
+                SyntaxKind::Function => SymbolKind::FUNCTION,
+                SyntaxKind::CallbackDeclaration => SymbolKind::EVENT,
+                _ => SymbolKind::PROPERTY,
+            };
+            (identifier_text(&identifier)?, map_node(&identifier)?, kind)
+        }
+        SyntaxKind::CallbackConnection | SyntaxKind::Binding => {
+            let identifier = node.child_token(SyntaxKind::Identifier)?;
+            let kind = if node.kind() == SyntaxKind::Binding {
+                SymbolKind::PROPERTY
+            } else {
+                SymbolKind::EVENT
+            };
+            // Qualify with the id of the element, if any
+            let id = node
+                .parent()
+                .and_then(|e| e.parent())
+                .filter(|s| s.kind() == SyntaxKind::SubElement)
+                .and_then(|s| s.child_text(SyntaxKind::Identifier));
+            let name = match id {
+                Some(id) => format!("{id}.{}", identifier_text(node)?),
+                None => identifier_text(node)?,
+            };
+            (name, map_token(&identifier)?, kind)
+        }
+        _ => return None,
+    };
+    let (uri, range) = map_node_and_url(node)?;
+    let detail = std::iter::successors(node.parent(), |n| n.parent())
+        .find_map(syntax_nodes::Component::new)
+        .and_then(|c| identifier_text(&c.DeclaredIdentifier()));
+    Some(CallHierarchyItem {
+        name,
+        kind,
+        tags: None,
+        detail,
+        uri,
+        range,
+        selection_range,
+        data: None,
+    })
+}
+
+#[test]
+fn test_call_hierarchy() {
+    let source = r#"
+export component Test {
+    callback clicked();
+    function helper(x: int) -> int { return x * 2; }
+    function compute() -> int { return helper(1) + helper(2); }
+    property <int> value: compute();
+    ta := TouchArea {
+        clicked => { root.clicked(); debug(compute()); }
+    }
+}"#;
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc
+        .documents
+        .get_document(&crate::language::uri_to_file(&uri).unwrap())
+        .unwrap()
+        .node
+        .clone()
+        .unwrap();
+
+    // Prepare from a call
+    let offset = source.find("compute()); }").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset).unwrap();
+    let items = prepare_call_hierarchy(&mut dc, token).unwrap();
+    assert_eq!(items.len(), 1);
+    let compute = items[0].clone();
+    assert_eq!(compute.name, "compute");
+    assert_eq!(compute.kind, SymbolKind::FUNCTION);
+    assert_eq!(compute.detail.as_deref(), Some("Test"));
+    assert_eq!(compute.selection_range.start.line, 4);
+
+    let incoming = incoming_calls(&mut dc, &compute).unwrap();
+    let incoming =
+        incoming.iter().map(|c| (c.from.name.as_str(), c.from_ranges.len())).collect::<Vec<_>>();
+    assert_eq!(incoming, vec![("value", 1), ("ta.clicked", 1)]);
+
+    let outgoing = outgoing_calls(&mut dc, &compute).unwrap();
+    let outgoing =
+        outgoing.iter().map(|c| (c.to.name.as_str(), c.from_ranges.len())).collect::<Vec<_>>();
+    assert_eq!(outgoing, vec![("helper", 2)]);
+
+    // The callback handler calls the callback and the function
+    let handler = &incoming_calls(&mut dc, &compute).unwrap()[1].from;
+    let outgoing = outgoing_calls(&mut dc, handler).unwrap();
+    let outgoing = outgoing.iter().map(|c| (c.to.name.as_str(), c.to.kind)).collect::<Vec<_>>();
+    assert_eq!(outgoing, vec![("clicked", SymbolKind::EVENT), ("compute", SymbolKind::FUNCTION)]);
+
+    // Not a function
+    let offset = source.find("value:").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset).unwrap();
+    assert!(prepare_call_hierarchy(&mut dc, token).is_none());
+}
@@ -244,7 +244,7 @@ pub(crate) fn completion_at(
                     .into_iter()
                     .filter_map(|(k, t)| {
                         match t {
-                            ElementType::Component(c) if !c.is_global() => (),
+                            ElementType::Component(c) if !c.is_global() && !c.is_interface => (),
                             ElementType::Builtin(b) if !b.is_internal && !b.is_global => (),
                             _ => return None,
                         };
@@ -331,6 +331,7 @@ pub(crate) fn completion_at(
             ("component", "component ${1:Component} {\n    $0\n}"),
             ("struct", "struct ${1:Name} {\n    $0\n}"),
             ("global", "global ${1:Name} {\n    $0\n}"),
+            ("interface", "interface ${1:Name} {\n    $0\n}"),
             ("export", "export { $0 }"),
             ("export component", "export component ${1:ExportedComponent} {\n    $0\n}"),
             ("export struct", "export struct ${1:Name} {\n    $0\n}"),
@@ -446,7 +447,7 @@ fn resolve_element_scope(
                 })
                 .chain(tr.all_elements().into_iter().filter_map(|(k, t)| {
                     match t {
-                        ElementType::Component(c) if !c.is_global() => (),
+                        ElementType::Component(c) if !c.is_global() && !c.is_interface => (),
                         ElementType::Builtin(b) if !b.is_internal && !b.is_global => (),
                         _ => return None,
                     };
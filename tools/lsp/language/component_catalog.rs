@@ -43,7 +43,7 @@ fn std_widgets_info(name: &str, is_global: bool) -> ComponentInformation {
             "LineEdit" | "TextEdit" => "Text Handling",
             "Button" | "CheckBox" | "ComboBox" | "Slider" | "SpinBox" | "Switch" => "Input",
             "ProgressIndicator" | "Spinner" => "Status",
-            "ListView" | "StandardListView" | "StandardTableView" => "Views",
+            "ListView" | "HorizontalListView" | "StandardListView" | "StandardTableView" => "Views",
             _ => "Widgets",
         }
     };
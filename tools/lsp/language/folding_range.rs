@@ -0,0 +1,120 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Folding of the blocks between braces and brackets, of the multi-line comments and of the imports.
+
+use super::DocumentCache;
+use crate::util::{last_non_ws_token, map_position};
+
+use i_slint_compiler::parser::{SyntaxKind, TextSize};
+use lsp_types::{FoldingRange, FoldingRangeKind, Url};
+
+pub fn folding_ranges(document_cache: &DocumentCache, uri: &Url) -> Option<Vec<FoldingRange>> {
+    let path = super::uri_to_file(uri)?;
+    let doc = document_cache.documents.get_document(&path)?.node.as_ref()?;
+    let line = |offset: TextSize| map_position(&doc.source_file, offset).line;
+
+    let mut result = Vec::new();
+    let mut add = |start_line: u32, end_line: u32, kind: FoldingRangeKind| {
+        if end_line > start_line {
+            result.push(FoldingRange {
+                start_line,
+                end_line,
+                kind: Some(kind),
+                ..Default::default()
+            });
+        }
+    };
+
+    for element in doc.descendants_with_tokens() {
+        match element {
+            rowan::NodeOrToken::Node(node) => {
+                let delimiters = node
+                    .children_with_tokens()
+                    .filter_map(|t| t.into_token())
+                    .filter(|t| {
+                        matches!(
+                            t.kind(),
+                            SyntaxKind::LBrace
+                                | SyntaxKind::RBrace
+                                | SyntaxKind::LBracket
+                                | SyntaxKind::RBracket
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                if let (Some(open), Some(close)) = (delimiters.first(), delimiters.last()) {
+                    if matches!(open.kind(), SyntaxKind::LBrace | SyntaxKind::LBracket)
+                        && matches!(close.kind(), SyntaxKind::RBrace | SyntaxKind::RBracket)
+                    {
+                        // The line with the closing brace stays visible
+                        add(
+                            line(open.text_range().start()),
+                            line(close.text_range().start()).saturating_sub(1),
+                            FoldingRangeKind::Region,
+                        );
+                    }
+                }
+            }
+            rowan::NodeOrToken::Token(token) => {
+                if token.kind() == SyntaxKind::Comment && token.text().starts_with("/*") {
+                    add(
+                        line(token.text_range().start()),
+                        line(token.text_range().end()),
+                        FoldingRangeKind::Comment,
+                    );
+                }
+            }
+        }
+    }
+
+    let mut imports = doc.ImportSpecifier();
+    if let Some(first) = imports.next() {
+        let last = imports.last().unwrap_or_else(|| first.clone());
+        if let Some(last_token) = last_non_ws_token(&last) {
+            add(
+                line(first.text_range().start()),
+                line(last_token.text_range().end()),
+                FoldingRangeKind::Imports,
+            );
+        }
+    }
+
+    result.sort_by_key(|r| r.start_line);
+    Some(result)
+}
+
+#[test]
+fn test_folding_ranges() {
+    let source = r#"import { Button } from "std-widgets.slint";
+import { LineEdit } from "std-widgets.slint";
+/* A
+   comment */
+export component Test {
+    states [
+        pressed when true: {
+            background: red;
+        }
+    ]
+    Button { }
+    Rectangle {
+        background: blue;
+    }
+}"#;
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let ranges = folding_ranges(&dc, &uri)
+        .unwrap()
+        .into_iter()
+        .map(|r| (r.start_line, r.end_line, r.kind.unwrap()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        ranges,
+        vec![
+            (0, 1, FoldingRangeKind::Imports),
+            (2, 3, FoldingRangeKind::Comment),
+            (4, 13, FoldingRangeKind::Region),
+            (5, 8, FoldingRangeKind::Region),
+            (6, 7, FoldingRangeKind::Region),
+            (11, 12, FoldingRangeKind::Region),
+        ]
+    );
+}
@@ -0,0 +1,207 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Formatting of documents, ranges and of the line being typed, using slint-fmt.
+
+use super::DocumentCache;
+use crate::util::map_range;
+
+use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxToken, TextRange};
+use lsp_types::{FormattingOptions, Position, Range, TextEdit, Url};
+use slint_fmt::fmt::{BraceStyle, FormatConfig};
+use slint_fmt::writer::TokenWriter;
+
+/// The settings of the `slint.format` configuration section.
+///
+/// The indentation defaults to the options sent by the editor with each request.
+#[derive(Clone, Debug, Default)]
+pub struct FormatSettings {
+    pub indent_width: Option<u32>,
+    pub max_line_length: Option<usize>,
+    pub brace_style: Option<BraceStyle>,
+}
+
+impl FormatSettings {
+    pub fn from_json(o: &serde_json::Map<String, serde_json::Value>) -> Self {
+        Self {
+            indent_width: o.get("indentWidth").and_then(|v| v.as_u64()).map(|v| v as u32),
+            max_line_length: o.get("maxLineLength").and_then(|v| v.as_u64()).map(|v| v as usize),
+            brace_style: match o.get("braceStyle").and_then(|v| v.as_str()) {
+                Some("sameLine") => Some(BraceStyle::SameLine),
+                Some("nextLine") => Some(BraceStyle::NextLine),
+                _ => None,
+            },
+        }
+    }
+
+    fn config(&self, options: &FormattingOptions) -> FormatConfig {
+        let default = FormatConfig::default();
+        FormatConfig {
+            indent_width: self.indent_width.unwrap_or(options.tab_size),
+            use_tabs: !options.insert_spaces,
+            max_line_length: self.max_line_length.or(default.max_line_length),
+            brace_style: self.brace_style.unwrap_or(default.brace_style),
+        }
+    }
+}
+
+/// Collects the changes made by the formatter
+#[derive(Default)]
+struct EditCollector {
+    edits: Vec<(TextRange, String)>,
+}
+
+impl EditCollector {
+    fn add(&mut self, range: TextRange, text: &str) {
+        // The whitespace removed before a token and the one inserted instead become a single edit
+        if let Some((last_range, last_text)) = self.edits.last_mut() {
+            if last_range.end() == range.start() {
+                *last_range = last_range.cover(range);
+                *last_text += text;
+                return;
+            }
+        }
+        self.edits.push((range, text.into()));
+    }
+}
+
+impl TokenWriter for EditCollector {
+    fn no_change(&mut self, _token: SyntaxToken) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    fn with_new_content(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        self.add(token.text_range(), contents);
+        Ok(())
+    }
+
+    fn insert_before(&mut self, token: SyntaxToken, contents: &str) -> std::io::Result<()> {
+        self.add(TextRange::empty(token.text_range().start()), contents);
+        Ok(())
+    }
+}
+
+/// Format the whole document. Returns None if the document has syntax errors, or if it is not a
+/// .slint file: the document of a .rs file only contains its `slint!` macro, so edits computed
+/// from it don't apply to the Rust code.
+pub fn format_document(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let path = super::uri_to_file(uri)?;
+    if path.extension().map_or(true, |ext| ext != "slint") {
+        return None;
+    }
+    let node = document_cache.documents.get_document(&path)?.node.as_ref()?;
+
+    // The formatter must not touch a document that does not parse
+    let mut diag = BuildDiagnostics::default();
+    let node =
+        i_slint_compiler::parser::parse(node.text().to_string(), Some(&path), None, &mut diag);
+    if diag.has_error() {
+        return None;
+    }
+    let source_file = node.source_file.clone();
+    let source = source_file.source()?;
+
+    let mut collector = EditCollector::default();
+    slint_fmt::fmt::format_document_with_config(
+        syntax_nodes::Document::new(node)?,
+        document_cache.format_settings.config(options),
+        &mut collector,
+    )
+    .ok()?;
+
+    Some(
+        collector
+            .edits
+            .into_iter()
+            .filter(|(range, text)| source[*range] != *text)
+            .map(|(range, new_text)| TextEdit { range: map_range(&source_file, range), new_text })
+            .collect(),
+    )
+}
+
+/// Format the part of the document within `range`
+pub fn format_range(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    range: Range,
+    options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let edits = format_document(document_cache, uri, options)?;
+    Some(
+        edits
+            .into_iter()
+            .filter(|e| e.range.start <= range.end && e.range.end >= range.start)
+            .collect(),
+    )
+}
+
+/// Format the line where a trigger character was typed
+pub fn format_on_type(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    position: Position,
+    options: &FormattingOptions,
+) -> Option<Vec<TextEdit>> {
+    let edits = format_document(document_cache, uri, options)?;
+    Some(
+        edits
+            .into_iter()
+            .filter(|e| e.range.start.line == position.line || e.range.end.line == position.line)
+            .collect(),
+    )
+}
+
+#[test]
+fn test_formatting() {
+    let source = "component Foo {\n  property<int>   a;\n  property<int>b;\n}\n";
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let options = FormattingOptions { tab_size: 4, insert_spaces: true, ..Default::default() };
+
+    let apply = |edits: Vec<TextEdit>| {
+        let offset = |p: Position| {
+            source.split_inclusive('\n').take(p.line as usize).map(str::len).sum::<usize>()
+                + p.character as usize
+        };
+        let mut result = source.to_string();
+        for e in edits.iter().rev() {
+            result.replace_range(offset(e.range.start)..offset(e.range.end), &e.new_text);
+        }
+        result
+    };
+
+    let edits = format_document(&dc, &uri, &options).unwrap();
+    assert_eq!(apply(edits), "component Foo {\n    property <int> a;\n    property <int> b;\n}\n");
+
+    let range = Range::new(Position::new(1, 0), Position::new(1, 10));
+    let edits = format_range(&dc, &uri, range, &options).unwrap();
+    assert_eq!(apply(edits), "component Foo {\n    property <int>   a;\n  property<int>b;\n}\n");
+
+    let edits = format_on_type(&dc, &uri, Position::new(2, 16), &options).unwrap();
+    assert_eq!(apply(edits), "component Foo {\n  property<int>   a;\n    property <int> b;\n}\n");
+
+    // Documents with syntax errors are not formatted
+    let (dc, uri, _) = crate::language::test::loaded_document_cache("component Foo {".into());
+    assert!(format_document(&dc, &uri, &options).is_none());
+
+    // Nor the slint! macros of Rust files
+    let mut dc = crate::language::test::empty_document_cache();
+    let path = if cfg!(target_family = "windows") { "c://foo/main.rs" } else { "/foo/main.rs" };
+    let uri = Url::from_file_path(path).unwrap();
+    let rust_source = "slint::slint!{\ncomponent Foo {\n  property<int>   a;\n}\n}\nfn main() {}\n";
+    spin_on::spin_on(super::reload_document_impl(
+        None,
+        rust_source.into(),
+        uri.clone(),
+        Some(42),
+        &mut dc,
+    ));
+    assert!(dc.documents.get_document(&super::uri_to_file(&uri).unwrap()).is_some());
+    assert!(format_document(&dc, &uri, &options).is_none());
+    let range = Range::new(Position::new(0, 0), Position::new(5, 0));
+    assert!(format_range(&dc, &uri, range, &options).is_none());
+}
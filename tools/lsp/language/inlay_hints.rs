@@ -0,0 +1,192 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Inlay hints for the names of the arguments at the call sites, for the type of the
+//! properties declared without a type, whose type is inferred from a two-way binding, and for
+//! the value of the lengths bound with a two-way binding to a constant length.
+
+use super::signature_help::function_signature;
+use super::DocumentCache;
+use crate::util::{map_position, with_lookup_ctx};
+
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::namedreference::NamedReference;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode, TextSize};
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Url};
+
+pub fn inlay_hints(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    range: &lsp_types::Range,
+) -> Option<Vec<InlayHint>> {
+    let path = super::uri_to_file(uri)?;
+    let doc = document_cache.documents.get_document(&path)?.node.as_ref()?;
+    let source_file = doc.source_file.clone();
+
+    let mut result = Vec::new();
+    let mut add_hint = |offset: TextSize, label: String, kind: InlayHintKind| {
+        let position = map_position(&source_file, offset);
+        if range.start <= position && position <= range.end {
+            result.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(label),
+                kind: Some(kind),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: Some(true),
+                data: None,
+            });
+        }
+    };
+
+    for node in doc.descendants() {
+        let node = SyntaxNode { node, source_file: source_file.clone() };
+        if let Some(call) = syntax_nodes::FunctionCallExpression::new(node.clone()) {
+            let Some(signature) = function_signature(document_cache, &call) else {
+                continue;
+            };
+            for (argument, parameter) in call.Expression().skip(1).zip(signature.parameters) {
+                let Some(name) = parameter.name.filter(|n| !n.ends_with("...")) else {
+                    continue;
+                };
+                if argument.text().to_string().trim() == name {
+                    continue;
+                }
+                if let Some(offset) = start_of_text(&argument) {
+                    add_hint(offset, format!("{name}:"), InlayHintKind::PARAMETER);
+                }
+            }
+        } else if let Some(binding) = syntax_nodes::TwoWayBinding::new(node.clone()) {
+            let length = with_lookup_ctx(document_cache, node.clone(), |ctx| {
+                let element = ctx.component_scope.last()?;
+                let name = ctx.property_name?;
+                resolved_length(&NamedReference::new(element, name))
+            });
+            if let Some(length) = length.flatten() {
+                let offset = binding.Expression().text_range().end();
+                add_hint(offset, format!("= {length}"), InlayHintKind::TYPE);
+            }
+        } else if let Some(decl) = syntax_nodes::PropertyDeclaration::new(node) {
+            if decl.Type().is_some() {
+                continue;
+            }
+            let ty =
+                with_lookup_ctx(document_cache, (*decl).clone(), |ctx| ctx.property_type.clone());
+            if let Some(ty) = ty.filter(|ty| !matches!(ty, Type::Invalid | Type::InferredProperty))
+            {
+                let offset = decl.DeclaredIdentifier().text_range().start();
+                add_hint(offset, format!("<{ty}>"), InlayHintKind::TYPE);
+            }
+        }
+    }
+    Some(result)
+}
+
+/// The value of a length property that is bound with two-way bindings to a property whose binding
+/// is a constant, such as `100px`
+fn resolved_length(nr: &NamedReference) -> Option<String> {
+    if !matches!(nr.ty(), Type::LogicalLength | Type::PhysicalLength | Type::Rem) {
+        return None;
+    }
+    let mut nr = nr.clone();
+    // Bound the number of steps, in case of a binding loop
+    for _ in 0..16 {
+        let element = nr.element();
+        let next = {
+            let element = element.borrow();
+            let binding = element.bindings.get(nr.name())?.borrow();
+            if let Some(next) = binding.two_way_bindings.first() {
+                next.clone()
+            } else {
+                return constant_length(&binding.expression);
+            }
+        };
+        nr = next;
+    }
+    None
+}
+
+fn constant_length(expression: &Expression) -> Option<String> {
+    match expression {
+        Expression::NumberLiteral(value, unit) => Some(format!("{value}{unit}")),
+        Expression::Cast { from, .. } => constant_length(from),
+        Expression::CodeBlock(statements) if statements.len() == 1 => {
+            constant_length(&statements[0])
+        }
+        _ => None,
+    }
+}
+
+/// The offset of the first token of the node that is not a whitespace or a comment
+fn start_of_text(node: &SyntaxNode) -> Option<TextSize> {
+    node.descendants_with_tokens()
+        .filter_map(|n| n.into_token())
+        .find(|t| !matches!(t.kind(), SyntaxKind::Whitespace | SyntaxKind::Comment))
+        .map(|t| t.text_range().start())
+}
+
+#[test]
+fn test_inlay_hints() {
+    let source = super::signature_help::TEST_SOURCE;
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let range = lsp_types::Range::new(
+        lsp_types::Position::new(0, 0),
+        lsp_types::Position::new(u32::MAX, u32::MAX),
+    );
+    let hints = inlay_hints(&dc, &uri, &range).unwrap();
+    let hints = hints
+        .iter()
+        .map(|h| {
+            let InlayHintLabel::String(label) = &h.label else { panic!("unexpected label") };
+            (h.position.line, label.as_str())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        hints,
+        vec![
+            (3, "<int>"),
+            (7, "red:"),
+            (7, "green:"),
+            (7, "blue:"),
+            (8, "first:"),
+            (8, "second:"),
+            (9, "first:"),
+            (9, "second:"),
+        ]
+    );
+
+    // Only the hints in the range
+    let range =
+        lsp_types::Range::new(lsp_types::Position::new(8, 0), lsp_types::Position::new(8, 100));
+    assert_eq!(inlay_hints(&dc, &uri, &range).unwrap().len(), 2);
+}
+
+#[test]
+fn test_two_way_binding_length_hints() {
+    let source = r#"
+export component Test {
+    property <length> size: 12px;
+    property <length> alias <=> size;
+    property <length> other;
+    Rectangle {
+        width <=> alias;
+        height <=> other;
+    }
+}"#;
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let range = lsp_types::Range::new(
+        lsp_types::Position::new(0, 0),
+        lsp_types::Position::new(u32::MAX, u32::MAX),
+    );
+    let hints = inlay_hints(&dc, &uri, &range).unwrap();
+    let hints = hints
+        .iter()
+        .map(|h| {
+            let InlayHintLabel::String(label) = &h.label else { panic!("unexpected label") };
+            (h.position.line, label.as_str())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(hints, vec![(3, "= 12px"), (6, "= 12px")]);
+}
@@ -20,7 +20,7 @@ pub(crate) struct DefinitionInformation {
     property_definition_range: lsp_types::Range,
     selection_range: lsp_types::Range,
     expression_range: lsp_types::Range,
-    expression_value: String,
+    pub(crate) expression_value: String,
 }
 
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
@@ -31,11 +31,11 @@ pub(crate) struct DeclarationInformation {
 
 #[derive(serde::Deserialize, serde::Serialize, Clone, Debug, PartialEq)]
 pub(crate) struct PropertyInformation {
-    name: String,
-    type_name: String,
+    pub(crate) name: String,
+    pub(crate) type_name: String,
     declared_at: Option<DeclarationInformation>,
-    defined_at: Option<DefinitionInformation>, // Range in the elements source file!
-    group: String,
+    pub(crate) defined_at: Option<DefinitionInformation>, // Range in the elements source file!
+    pub(crate) group: String,
 }
 
 #[derive(serde::Deserialize, serde::Serialize, Debug)]
@@ -261,7 +261,7 @@ fn insert_property_definitions(element: &Element, properties: &mut Vec<PropertyI
     }
 }
 
-fn get_properties(element: &ElementRc) -> Vec<PropertyInformation> {
+pub(crate) fn get_properties(element: &ElementRc) -> Vec<PropertyInformation> {
     let mut result = Vec::new();
     add_element_properties(&element.borrow(), "", true, &mut result);
 
@@ -0,0 +1,1076 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Refactorings offered as code actions: extract elements into a new component, inline a
+//! component usage, make a property `in-out`, move a component to its own file, and declare
+//! a property for an unresolved reference.
+
+use super::DocumentCache;
+use crate::util::{map_position, map_range, with_lookup_ctx};
+
+use i_slint_compiler::expression_tree::Expression;
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::{LookupObject, LookupResult};
+use i_slint_compiler::object_tree::Document;
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, Language, SyntaxKind, SyntaxNode,
+    SyntaxToken, TextRange, TextSize,
+};
+use i_slint_compiler::pathutils::clean_path;
+use lsp_types::{
+    ClientCapabilities, CodeActionKind, CodeActionOrCommand, CreateFile, DocumentChangeOperation,
+    DocumentChanges, OneOf, OptionalVersionedTextDocumentIdentifier, ResourceOp,
+    ResourceOperationKind, TextDocumentEdit, TextEdit, Url, WorkspaceEdit,
+};
+use std::collections::HashSet;
+
+pub fn refactoring_actions(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    range: &lsp_types::Range,
+    client_capabilities: &ClientCapabilities,
+) -> Vec<CodeActionOrCommand> {
+    let Some(doc) =
+        super::uri_to_file(uri).and_then(|path| document_cache.documents.get_document(&path))
+    else {
+        return Vec::new();
+    };
+    let Some(root) = doc.node.as_ref() else { return Vec::new() };
+    let offset = |p: &lsp_types::Position| {
+        TextSize::from(root.source_file.offset(p.line as usize + 1, p.character as usize + 1) as u32)
+    };
+    let start = offset(&range.start);
+    let selection = TextRange::new(start, offset(&range.end).max(start));
+    let Some(token) = super::token_at_offset(root, start.into()) else { return Vec::new() };
+
+    let mut result = Vec::new();
+    result.extend(extract_component(document_cache, uri, doc, &token, selection));
+    result.extend(inline_component(uri, doc, &token));
+    result.extend(make_property_in_out(uri, &token));
+    result.extend(move_component_to_file(document_cache, uri, doc, &token, client_capabilities));
+    result.extend(declare_missing_property(document_cache, uri, &token));
+    result
+}
+
+fn code_action(title: String, kind: CodeActionKind, edit: WorkspaceEdit) -> CodeActionOrCommand {
+    CodeActionOrCommand::CodeAction(lsp_types::CodeAction {
+        title,
+        kind: Some(kind),
+        edit: Some(edit),
+        ..Default::default()
+    })
+}
+
+fn single_file_edit(uri: &Url, edits: Vec<TextEdit>) -> WorkspaceEdit {
+    WorkspaceEdit {
+        changes: Some(std::iter::once((uri.clone(), edits)).collect()),
+        ..Default::default()
+    }
+}
+
+/// A property, callback or function declared outside of the extracted elements, and that
+/// becomes a property or a callback of the new component
+struct Promoted {
+    name: String,
+    /// The text of the original reference, e.g. `root.text`
+    source: String,
+    ty: Type,
+    /// The reference is assigned to, so the property needs to be forwarded with a two-way binding
+    two_way: bool,
+}
+
+fn extract_component(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    doc: &Document,
+    token: &SyntaxToken,
+    selection: TextRange,
+) -> Option<CodeActionOrCommand> {
+    let root = doc.node.as_ref()?;
+    let selected = selected_sub_elements(root, token, selection)?;
+    let range =
+        TextRange::new(selected.first()?.text_range().start(), selected.last()?.text_range().end());
+    let text = root.text().to_string();
+
+    let mut promoted: Vec<Promoted> = Vec::new();
+    let mut replacements = Vec::new();
+    for node in selected.iter().flat_map(|s| s.descendants()) {
+        let node = SyntaxNode { node, source_file: root.source_file.clone() };
+        if node.kind() != SyntaxKind::QualifiedName
+            || node.parent().map(|p| p.kind()) != Some(SyntaxKind::Expression)
+        {
+            continue;
+        }
+        let Some((prefix, expression)) = resolve_prefix(document_cache, &node) else { continue };
+        let element = match &expression {
+            Expression::PropertyReference(nr)
+            | Expression::CallbackReference(nr, _)
+            | Expression::FunctionReference(nr, _) => Some(nr.element()),
+            Expression::RepeaterModelReference { element }
+            | Expression::RepeaterIndexReference { element } => element.upgrade(),
+            _ => None,
+        };
+        let Some(element) = element else { continue };
+        let is_global =
+            element.borrow().enclosing_component.upgrade().map_or(false, |c| c.is_global());
+        let is_inside = element.borrow().node.first().map_or(false, |n| {
+            n.source_file.path() == root.source_file.path() && range.contains_range(n.text_range())
+        });
+        if is_global || is_inside {
+            continue;
+        }
+
+        let reference_range =
+            TextRange::new(prefix.first()?.text_range().start(), prefix.last()?.text_range().end());
+        let source = text[reference_range].to_string();
+        let two_way = is_assigned(&node);
+        let name = if let Some(p) = promoted.iter_mut().find(|p| p.source == source) {
+            p.two_way |= two_way;
+            p.name.clone()
+        } else {
+            let name = promoted_name(&prefix, &promoted)?;
+            promoted.push(Promoted { name: name.clone(), source, ty: expression.ty(), two_way });
+            name
+        };
+        replacements.push((reference_range, format!("root.{name}")));
+    }
+
+    let component_name = std::iter::once("NewComponent".to_string())
+        .chain((2..).map(|i| format!("NewComponent{i}")))
+        .find(|name| doc.local_registry.lookup_element(name).is_err())?;
+
+    let base_indent = indentation_at(&text, range.start());
+    let body = text_with_replacements(&text, range, replacements)
+        .split('\n')
+        .map(|line| line.strip_prefix(base_indent.as_str()).unwrap_or(line))
+        .map(|line| if line.trim().is_empty() { String::new() } else { format!("    {line}") })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut component = format!("component {component_name} {{\n");
+    let mut bindings = Vec::new();
+    for p in &promoted {
+        let (declaration, binding) = match &p.ty {
+            Type::Callback { args, return_type } => {
+                callback_forwarding(p, args, return_type.as_deref())
+            }
+            Type::Function { args, return_type } => {
+                callback_forwarding(p, args, Some(&**return_type))
+            }
+            ty if p.two_way => (
+                format!("in-out property <{ty}> {};", p.name),
+                format!("{} <=> {};", p.name, p.source),
+            ),
+            ty => (format!("in property <{ty}> {};", p.name), format!("{}: {};", p.name, p.source)),
+        };
+        component += &format!("    {declaration}\n");
+        bindings.push(format!("{base_indent}    {binding}"));
+    }
+    if !promoted.is_empty() {
+        component.push('\n');
+    }
+    component += &format!("{body}\n}}\n\n");
+
+    let usage = if bindings.is_empty() {
+        format!("{component_name} {{ }}")
+    } else {
+        format!("{component_name} {{\n{}\n{base_indent}}}", bindings.join("\n"))
+    };
+
+    // The new component is declared before the component it is extracted from
+    let item = std::iter::successors(Some(selected.first()?.clone()), |n| n.parent())
+        .find(|n| n.parent().map_or(false, |p| p.kind() == SyntaxKind::Document))?;
+    let insert_position = map_position(&root.source_file, item.text_range().start());
+    let edits = vec![
+        TextEdit::new(lsp_types::Range::new(insert_position, insert_position), component),
+        TextEdit::new(map_range(&root.source_file, range), usage),
+    ];
+    Some(code_action(
+        "Extract into a new component".into(),
+        CodeActionKind::REFACTOR_EXTRACT,
+        single_file_edit(uri, edits),
+    ))
+}
+
+/// A name for the promoted reference that doesn't clash with the other promoted references or
+/// with the builtin properties of the new component: `root.label` becomes `label`, but
+/// `parent.width` becomes `parent-width`
+fn promoted_name(prefix: &[SyntaxToken], promoted: &[Promoted]) -> Option<String> {
+    let is_free = |name: &String| {
+        !promoted.iter().any(|p| p.name == *name)
+            && !i_slint_compiler::typeregister::reserved_property(name).is_valid()
+    };
+    let last = normalize_identifier(prefix.last()?.text());
+    let qualified =
+        prefix.iter().map(|t| normalize_identifier(t.text())).collect::<Vec<_>>().join("-");
+    [last, qualified.clone()]
+        .into_iter()
+        .chain((2..).map(|n| format!("{qualified}-{n}")))
+        .find(is_free)
+}
+
+/// The callback declaration in the new component, and the handler that forwards it
+fn callback_forwarding(
+    promoted: &Promoted,
+    args: &[Type],
+    return_type: Option<&Type>,
+) -> (String, String) {
+    let types = args.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ");
+    let declaration = match return_type.filter(|t| **t != Type::Void) {
+        Some(return_type) => format!("callback {}({types}) -> {return_type};", promoted.name),
+        None => format!("callback {}({types});", promoted.name),
+    };
+    let names = (0..args.len()).map(|i| format!("arg-{i}")).collect::<Vec<_>>().join(", ");
+    let handler = if args.is_empty() {
+        format!("{} => {{ {}() }}", promoted.name, promoted.source)
+    } else {
+        format!("{}({names}) => {{ {}({names}) }}", promoted.name, promoted.source)
+    };
+    (declaration, handler)
+}
+
+/// The sub-elements covered by the selection, or the sub-element under the cursor if the
+/// selection is empty
+fn selected_sub_elements(
+    root: &syntax_nodes::Document,
+    token: &SyntaxToken,
+    selection: TextRange,
+) -> Option<Vec<SyntaxNode>> {
+    if selection.is_empty() {
+        let node = token.parent();
+        let sub_element = match node.kind() {
+            SyntaxKind::SubElement => node,
+            SyntaxKind::QualifiedName => {
+                node.parent().filter(|e| e.kind() == SyntaxKind::Element)?.parent()?
+            }
+            _ => return None,
+        };
+        return (sub_element.kind() == SyntaxKind::SubElement).then(|| vec![sub_element]);
+    }
+
+    let covering = match root.covering_element(selection) {
+        rowan::NodeOrToken::Node(node) => node,
+        rowan::NodeOrToken::Token(token) => token.parent()?,
+    };
+    let covering = SyntaxNode { node: covering, source_file: root.source_file.clone() };
+    for element in std::iter::successors(Some(covering), |n| n.parent()) {
+        if element.kind() != SyntaxKind::Element || selection.contains_range(element.text_range()) {
+            continue;
+        }
+        let children = element
+            .children()
+            .filter(|c| c.text_range().intersect(selection).map_or(false, |r| !r.is_empty()))
+            .collect::<Vec<_>>();
+        if children.is_empty() {
+            continue;
+        }
+        return children.iter().all(|c| c.kind() == SyntaxKind::SubElement).then_some(children);
+    }
+    None
+}
+
+/// Resolves the shortest prefix of the qualified name that refers to a property, a callback,
+/// a function or the data of a repeater
+fn resolve_prefix(
+    document_cache: &DocumentCache,
+    qualified_name: &SyntaxNode,
+) -> Option<(Vec<SyntaxToken>, Expression)> {
+    let identifiers = identifiers(qualified_name);
+    let (count, expression) = with_lookup_ctx(document_cache, qualified_name.clone(), |ctx| {
+        let mut it = identifiers.iter().map(|t| normalize_identifier(t.text()));
+        let global = i_slint_compiler::lookup::global_lookup();
+        let mut result = global.lookup(ctx, &it.next()?)?;
+        let mut count = 1;
+        loop {
+            if let LookupResult::Expression { expression, .. } = &result {
+                if matches!(
+                    expression,
+                    Expression::PropertyReference(_)
+                        | Expression::CallbackReference(..)
+                        | Expression::FunctionReference(..)
+                        | Expression::RepeaterModelReference { .. }
+                        | Expression::RepeaterIndexReference { .. }
+                ) {
+                    return Some((count, expression.clone()));
+                }
+            }
+            result = result.lookup(ctx, &it.next()?)?;
+            count += 1;
+        }
+    })??;
+    Some((identifiers[..count].to_vec(), expression))
+}
+
+/// Returns true if the qualified name is on the left side of an assignment or in a two-way binding
+fn is_assigned(qualified_name: &SyntaxNode) -> bool {
+    let Some(expression) = qualified_name.parent() else { return false };
+    expression.parent().map_or(false, |p| match p.kind() {
+        SyntaxKind::SelfAssignment => p
+            .child_node(SyntaxKind::Expression)
+            .map_or(false, |lhs| lhs.text_range() == expression.text_range()),
+        SyntaxKind::TwoWayBinding => true,
+        _ => false,
+    })
+}
+
+fn inline_component(uri: &Url, doc: &Document, token: &SyntaxToken) -> Option<CodeActionOrCommand> {
+    let qualified_name = token.parent();
+    if token.kind() != SyntaxKind::Identifier || qualified_name.kind() != SyntaxKind::QualifiedName
+    {
+        return None;
+    }
+    let usage = syntax_nodes::Element::new(qualified_name.parent()?)?;
+    let sub_element = syntax_nodes::SubElement::new(usage.parent()?)?;
+    let name = identifiers(&qualified_name).iter().map(|t| t.text()).collect::<Vec<_>>().join(".");
+    let ElementType::Component(component) = doc.local_registry.lookup_element(&name).ok()? else {
+        return None;
+    };
+    if component.is_global() {
+        return None;
+    }
+    let root = doc.node.as_ref()?;
+    let component_root = component.root_element.borrow().node.first()?.clone();
+    // Only the components of the same file, for which no import is needed
+    if component_root.source_file.path() != root.source_file.path() {
+        return None;
+    }
+    let component_node = syntax_nodes::Component::new(component_root.parent()?)?;
+    let text = root.text().to_string();
+
+    // The bindings of the usage replace the ones of the component
+    let overridden = usage
+        .children()
+        .filter(|c| {
+            matches!(
+                c.kind(),
+                SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection
+            )
+        })
+        .filter_map(|c| identifier_text(&c))
+        .collect::<HashSet<_>>();
+
+    let existing_id = sub_element.child_text(SyntaxKind::Identifier);
+    let id = existing_id.clone().unwrap_or_else(|| separate_words(&component.id, '-'));
+    let mut needs_id = existing_id.is_some();
+
+    let mut replacements = Vec::new();
+    for child in component_root.children() {
+        if matches!(
+            child.kind(),
+            SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection
+        ) && identifier_text(&child).map_or(false, |n| overridden.contains(&n))
+        {
+            replacements.push((with_leading_whitespace(&child), String::new()));
+        }
+    }
+    for node in component_root.descendants() {
+        let node = SyntaxNode { node, source_file: root.source_file.clone() };
+        if node.kind() == SyntaxKind::QualifiedName
+            && node.parent().map(|p| p.kind()) == Some(SyntaxKind::Expression)
+        {
+            if let Some(first) = identifiers(&node).first().filter(|t| t.text() == "root") {
+                replacements.push((first.text_range(), id.clone()));
+                needs_id = true;
+            }
+        }
+    }
+
+    let is_child = |n: &SyntaxNode| {
+        matches!(
+            n.kind(),
+            SyntaxKind::SubElement | SyntaxKind::RepeatedElement | SyntaxKind::ConditionalElement
+        )
+    };
+    let usage_indent = indentation_at(&text, sub_element.text_range().start());
+    let mut usage_replacements = Vec::new();
+    if let Some(placeholder) =
+        component_root.descendants().find(|n| n.kind() == SyntaxKind::ChildrenPlaceholder)
+    {
+        let children = usage.children().filter(is_child).collect::<Vec<_>>();
+        let children_text = children
+            .iter()
+            .map(|c| c.text().to_string())
+            .collect::<Vec<_>>()
+            .join(&format!("\n{usage_indent}    "));
+        replacements.push((placeholder.text_range(), children_text));
+        usage_replacements =
+            children.iter().map(|c| (with_leading_whitespace(c), String::new())).collect();
+    }
+
+    let body = reindent(
+        text_with_replacements(&text, inner_range(&component_root)?, replacements).trim_end(),
+        &indentation_at(&text, component_node.text_range().start()),
+        &usage_indent,
+    );
+    let rest = text_with_replacements(&text, inner_range(&usage)?, usage_replacements);
+    let base = component_root
+        .QualifiedName()
+        .map_or_else(|| "Empty".to_string(), |q| q.text().to_string().trim().to_string());
+    let id_prefix = if needs_id { format!("{id} := ") } else { String::new() };
+    let inlined = format!("{id_prefix}{base} {{{body}{}\n{usage_indent}}}", rest.trim_end());
+
+    let edits =
+        vec![TextEdit::new(map_range(&root.source_file, sub_element.text_range()), inlined)];
+    Some(code_action(
+        format!("Inline `{name}`"),
+        CodeActionKind::REFACTOR_INLINE,
+        single_file_edit(uri, edits),
+    ))
+}
+
+fn make_property_in_out(uri: &Url, token: &SyntaxToken) -> Option<CodeActionOrCommand> {
+    let declaration = std::iter::successors(Some(token.parent()), |n| n.parent())
+        .find_map(syntax_nodes::PropertyDeclaration::new)?;
+    let identifier = declaration.DeclaredIdentifier();
+    // Only on the declaration itself, not in the binding
+    if token.text_range().start() > identifier.text_range().end() {
+        return None;
+    }
+    let keywords = declaration
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .collect::<Vec<_>>();
+    let property_keyword = keywords.iter().find(|t| t.text() == "property")?;
+    let edit = match keywords.first().filter(|t| t.text() != "property") {
+        Some(qualifier) if matches!(qualifier.text(), "in-out" | "in_out") => return None,
+        Some(qualifier) => {
+            TextEdit::new(map_range(&token.source_file, qualifier.text_range()), "in-out".into())
+        }
+        None => {
+            let position = map_position(&token.source_file, property_keyword.text_range().start());
+            TextEdit::new(lsp_types::Range::new(position, position), "in-out ".into())
+        }
+    };
+    Some(code_action(
+        format!("Make `{}` in-out", identifier_text(&identifier)?),
+        CodeActionKind::REFACTOR_REWRITE,
+        single_file_edit(uri, vec![edit]),
+    ))
+}
+
+fn supports_file_creation(client_capabilities: &ClientCapabilities) -> bool {
+    client_capabilities.workspace.as_ref().and_then(|w| w.workspace_edit.as_ref()).map_or(
+        false,
+        |e| {
+            e.document_changes == Some(true)
+                && e.resource_operations
+                    .as_ref()
+                    .map_or(false, |ops| ops.contains(&ResourceOperationKind::Create))
+        },
+    )
+}
+
+fn move_component_to_file(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    doc: &Document,
+    token: &SyntaxToken,
+    client_capabilities: &ClientCapabilities,
+) -> Option<CodeActionOrCommand> {
+    if token.kind() != SyntaxKind::Identifier || !supports_file_creation(client_capabilities) {
+        return None;
+    }
+    let component = syntax_nodes::Component::new(
+        syntax_nodes::DeclaredIdentifier::new(token.parent())?.parent()?,
+    )?;
+    let name = identifier_text(&component.DeclaredIdentifier())?;
+    if doc.inner_components.len() + doc.inner_types.len() < 2 {
+        return None;
+    }
+    let root = doc.node.as_ref()?;
+    let path = super::uri_to_file(uri)?;
+
+    let used = component
+        .descendants_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .map(|t| normalize_identifier(t.text()))
+        .collect::<HashSet<_>>();
+    // The other items of this file would need to be imported from it, which would be recursive
+    let local_items = doc
+        .inner_components
+        .iter()
+        .map(|c| c.id.clone())
+        .chain(doc.inner_types.iter().filter_map(|ty| match ty {
+            Type::Struct { name, .. } => name.clone(),
+            Type::Enumeration(e) => Some(e.name.clone()),
+            _ => None,
+        }))
+        .map(|n| normalize_identifier(&n))
+        .filter(|n| *n != name);
+    if local_items.into_iter().any(|n| used.contains(&n)) {
+        return None;
+    }
+
+    let file_name = format!("{}.slint", separate_words(&name, '_'));
+    let new_path = path.with_file_name(&file_name);
+    if new_path == path
+        || new_path.exists()
+        || document_cache.documents.get_document(&new_path).is_some()
+    {
+        return None;
+    }
+    let new_uri = Url::from_file_path(&new_path).ok()?;
+
+    let mut content = String::new();
+    for import in root.ImportSpecifier() {
+        let Some(list) = import.ImportIdentifierList() else { continue };
+        let names = list
+            .ImportIdentifier()
+            .filter(|i| {
+                i.InternalName()
+                    .map_or_else(|| identifier_text(&i.ExternalName()), |n| identifier_text(&n))
+                    .map_or(false, |n| used.contains(&n))
+            })
+            .map(|i| i.text().to_string().trim().to_string())
+            .collect::<Vec<_>>();
+        if let (false, Some(file)) =
+            (names.is_empty(), import.child_text(SyntaxKind::StringLiteral))
+        {
+            content += &format!("import {{ {} }} from {file};\n", names.join(", "));
+        }
+    }
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    content += &format!("export {}\n", component.text());
+
+    let mut operations = vec![
+        DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
+            uri: new_uri.clone(),
+            options: None,
+            annotation_id: None,
+        })),
+        text_document_edit(&new_uri, vec![TextEdit::new(Default::default(), content)]),
+    ];
+
+    // Remove the component, with its `export` and the whitespace after it
+    let item = component.parent().filter(|p| p.kind() == SyntaxKind::ExportsList);
+    let item = item.unwrap_or_else(|| (*component).clone());
+    let mut removed = item.text_range();
+    if let Some(ws) = item
+        .node
+        .next_sibling_or_token()
+        .and_then(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Whitespace)
+    {
+        removed = removed.cover(ws.text_range());
+    }
+    let mut edits = vec![TextEdit::new(map_range(&root.source_file, removed), String::new())];
+    let used_in_file = root
+        .descendants_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| !removed.contains_range(t.text_range()))
+        .any(|t| t.kind() == SyntaxKind::Identifier && normalize_identifier(t.text()) == name);
+    if used_in_file {
+        let import = format!("import {{ {name} }} from \"{file_name}\";");
+        edits.push(match root.ImportSpecifier().last() {
+            Some(last) => {
+                let position = map_position(&root.source_file, last.text_range().end());
+                TextEdit::new(lsp_types::Range::new(position, position), format!("\n{import}"))
+            }
+            None => TextEdit::new(Default::default(), format!("{import}\n\n")),
+        });
+    }
+    operations.push(text_document_edit(uri, edits));
+
+    // Update the files that import the component
+    for (other_path, other_doc) in document_cache.documents.all_file_documents() {
+        if other_path.starts_with("builtin:") || *other_path == path {
+            continue;
+        }
+        let (Some(other_root), Ok(other_uri)) =
+            (other_doc.node.as_ref(), Url::from_file_path(other_path))
+        else {
+            continue;
+        };
+        let mut edits = Vec::new();
+        for import in other_root.ImportSpecifier() {
+            let Some(literal) = import.child_text(SyntaxKind::StringLiteral) else { continue };
+            let literal = literal.trim_matches('"');
+            let imported = other_path.parent().map(|dir| clean_path(&dir.join(literal)));
+            let Some(list) =
+                import.ImportIdentifierList().filter(|_| imported == Some(path.clone()))
+            else {
+                continue;
+            };
+            let identifiers = list.ImportIdentifier().collect::<Vec<_>>();
+            let Some(identifier) = identifiers
+                .iter()
+                .find(|i| identifier_text(&i.ExternalName()).as_ref() == Some(&name))
+            else {
+                continue;
+            };
+            let new_literal = match literal.rfind('/') {
+                Some(i) => format!("{}{file_name}", &literal[..=i]),
+                None => file_name.clone(),
+            };
+            let new_import = format!(
+                "import {{ {} }} from \"{new_literal}\";",
+                identifier.text().to_string().trim()
+            );
+            if identifiers.len() == 1 {
+                edits.push(TextEdit::new(
+                    map_range(&other_root.source_file, import.text_range()),
+                    new_import,
+                ));
+            } else {
+                edits.push(TextEdit::new(
+                    map_range(&other_root.source_file, with_separating_comma(identifier)),
+                    String::new(),
+                ));
+                let position = map_position(&other_root.source_file, import.text_range().end());
+                edits.push(TextEdit::new(
+                    lsp_types::Range::new(position, position),
+                    format!("\n{new_import}"),
+                ));
+            }
+        }
+        if !edits.is_empty() {
+            operations.push(text_document_edit(&other_uri, edits));
+        }
+    }
+
+    Some(code_action(
+        format!("Move `{name}` to \"{file_name}\""),
+        CodeActionKind::REFACTOR,
+        WorkspaceEdit {
+            document_changes: Some(DocumentChanges::Operations(operations)),
+            ..Default::default()
+        },
+    ))
+}
+
+fn text_document_edit(uri: &Url, edits: Vec<TextEdit>) -> DocumentChangeOperation {
+    DocumentChangeOperation::Edit(TextDocumentEdit {
+        text_document: OptionalVersionedTextDocumentIdentifier { uri: uri.clone(), version: None },
+        edits: edits.into_iter().map(OneOf::Left).collect(),
+    })
+}
+
+fn declare_missing_property(
+    document_cache: &DocumentCache,
+    uri: &Url,
+    token: &SyntaxToken,
+) -> Option<CodeActionOrCommand> {
+    let qualified_name = token.parent();
+    if token.kind() != SyntaxKind::Identifier || qualified_name.kind() != SyntaxKind::QualifiedName
+    {
+        return None;
+    }
+    let expression = qualified_name.parent().filter(|e| e.kind() == SyntaxKind::Expression)?;
+    // A call would refer to a callback or a function
+    if expression.parent().map_or(false, |p| p.kind() == SyntaxKind::FunctionCallExpression) {
+        return None;
+    }
+    let identifiers = identifiers(&qualified_name);
+    let (in_root, name) = match identifiers.as_slice() {
+        [name] => (true, name),
+        [first, name] if matches!(first.text(), "root" | "self") => (first.text() == "root", name),
+        _ => return None,
+    };
+
+    let (resolved, property_type) =
+        with_lookup_ctx(document_cache, qualified_name.clone(), |ctx| {
+            let global = i_slint_compiler::lookup::global_lookup();
+            let mut result = global.lookup(ctx, &normalize_identifier(identifiers[0].text()));
+            if identifiers.len() > 1 {
+                result = result.and_then(|r| r.lookup(ctx, &normalize_identifier(name.text())));
+            }
+            (result.is_some(), ctx.property_type.clone())
+        })?;
+    if resolved {
+        return None;
+    }
+    // The type of the binding, if the reference is the whole binding expression
+    let ty = Some(property_type)
+        .filter(|ty| ty.is_property_type())
+        .filter(|_| expression.parent().map(|p| p.kind()) == Some(SyntaxKind::BindingExpression))
+        .unwrap_or(Type::Int32);
+
+    let element = if in_root {
+        std::iter::successors(Some(qualified_name.clone()), |n| n.parent())
+            .find_map(syntax_nodes::Component::new)?
+            .Element()
+    } else {
+        std::iter::successors(Some(qualified_name.clone()), |n| n.parent())
+            .find_map(syntax_nodes::Element::new)?
+    };
+    let brace = element.child_token(SyntaxKind::LBrace)?;
+    let text = std::iter::successors(Some(element.node.clone()), |n| n.parent())
+        .last()?
+        .text()
+        .to_string();
+    let indent = indentation_at(&text, element.text_range().start());
+    let position = map_position(&element.source_file, brace.text_range().end());
+    let edit = TextEdit::new(
+        lsp_types::Range::new(position, position),
+        format!("\n{indent}    property <{ty}> {};", name.text()),
+    );
+    Some(code_action(
+        format!("Declare property `{}`", name.text()),
+        CodeActionKind::QUICKFIX,
+        single_file_edit(uri, vec![edit]),
+    ))
+}
+
+fn identifiers(node: &SyntaxNode) -> Vec<SyntaxToken> {
+    node.children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .collect()
+}
+
+/// The range between the braces of the element
+fn inner_range(element: &SyntaxNode) -> Option<TextRange> {
+    Some(TextRange::new(
+        element.child_token(SyntaxKind::LBrace)?.text_range().end(),
+        element.child_token(SyntaxKind::RBrace)?.text_range().start(),
+    ))
+}
+
+/// The range of the node, extended to the whitespace before it
+fn with_leading_whitespace(node: &SyntaxNode) -> TextRange {
+    match node.node.prev_sibling_or_token().and_then(|t| t.into_token()) {
+        Some(ws) if ws.kind() == SyntaxKind::Whitespace => node.text_range().cover(ws.text_range()),
+        _ => node.text_range(),
+    }
+}
+
+/// The range of the import identifier, extended to the comma that separates it from the others
+fn with_separating_comma(identifier: &syntax_nodes::ImportIdentifier) -> TextRange {
+    let is_comma = |t: &rowan::SyntaxToken<Language>| t.kind() == SyntaxKind::Comma;
+    let is_ws = |t: &rowan::SyntaxToken<Language>| t.kind() == SyntaxKind::Whitespace;
+    let mut range = identifier.text_range();
+    let next = std::iter::successors(identifier.node.next_sibling_or_token(), |t| {
+        t.next_sibling_or_token()
+    })
+    .map_while(|t| t.into_token())
+    .take_while(|t| is_comma(t) || is_ws(t))
+    .collect::<Vec<_>>();
+    if next.iter().any(is_comma) {
+        for t in next {
+            range = range.cover(t.text_range());
+        }
+    } else {
+        for t in std::iter::successors(identifier.node.prev_sibling_or_token(), |t| {
+            t.prev_sibling_or_token()
+        })
+        .map_while(|t| t.into_token())
+        .take_while(|t| is_comma(t) || is_ws(t))
+        {
+            range = range.cover(t.text_range());
+        }
+    }
+    range
+}
+
+/// The indentation of the line that contains the offset
+fn indentation_at(text: &str, offset: TextSize) -> String {
+    let offset = usize::from(offset);
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    text[line_start..offset].chars().take_while(|c| *c == ' ' || *c == '\t').collect()
+}
+
+fn reindent(text: &str, from: &str, to: &str) -> String {
+    text.split('\n')
+        .enumerate()
+        .map(|(i, line)| match line.strip_prefix(from) {
+            Some(rest) if i > 0 && !line.trim().is_empty() => format!("{to}{rest}"),
+            _ => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// The text in the range, with the replacements that are within the range applied
+fn text_with_replacements(
+    text: &str,
+    range: TextRange,
+    mut replacements: Vec<(TextRange, String)>,
+) -> String {
+    replacements.retain(|(r, _)| range.contains_range(*r));
+    replacements.sort_by_key(|(r, _)| r.start());
+    let mut result = String::new();
+    let mut position = range.start();
+    for (r, replacement) in replacements {
+        if r.start() < position {
+            // Overlaps with the previous replacement
+            continue;
+        }
+        result += &text[TextRange::new(position, r.start())];
+        result += &replacement;
+        position = r.end();
+    }
+    result += &text[TextRange::new(position, range.end())];
+    result
+}
+
+/// `MyButton` -> `my-button` or `my_button`
+fn separate_words(name: &str, separator: char) -> String {
+    let mut result = String::new();
+    let mut previous_is_lower = false;
+    for c in name.chars() {
+        if c == '-' || c == '_' {
+            result.push(separator);
+            previous_is_lower = false;
+            continue;
+        }
+        if c.is_uppercase() && previous_is_lower {
+            result.push(separator);
+        }
+        previous_is_lower = c.is_lowercase() || c.is_ascii_digit();
+        result.extend(c.to_lowercase());
+    }
+    result
+}
+
+#[cfg(test)]
+fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
+    let offset = |p: &lsp_types::Position| {
+        source.split_inclusive('\n').take(p.line as usize).map(str::len).sum::<usize>()
+            + p.character as usize
+    };
+    let mut edits = edits.to_vec();
+    edits.sort_by_key(|e| std::cmp::Reverse(offset(&e.range.start)));
+    let mut result = source.to_string();
+    for edit in edits {
+        result.replace_range(offset(&edit.range.start)..offset(&edit.range.end), &edit.new_text);
+    }
+    result
+}
+
+#[cfg(test)]
+/// Returns the titles of the actions, and the text after applying the action with the title
+fn run(
+    source: &str,
+    range: (usize, usize),
+    title: &str,
+    capabilities: &ClientCapabilities,
+) -> (Vec<String>, Option<String>) {
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let position = |offset: usize| {
+        let line = source[..offset].matches('\n').count();
+        let column = offset - source[..offset].rfind('\n').map_or(0, |i| i + 1);
+        lsp_types::Position::new(line as u32, column as u32)
+    };
+    let range = lsp_types::Range::new(position(range.0), position(range.1));
+    let actions = refactoring_actions(&dc, &uri, &range, capabilities);
+    let mut titles = Vec::new();
+    let mut result = None;
+    for action in actions {
+        let CodeActionOrCommand::CodeAction(action) = action else { continue };
+        if action.title == title {
+            let changes = action.edit.as_ref().and_then(|e| e.changes.as_ref());
+            result = changes.map(|c| apply_edits(source, &c[&uri]));
+        }
+        titles.push(action.title);
+    }
+    (titles, result)
+}
+
+#[test]
+fn test_extract_component() {
+    let source = r#"export component Test {
+    in-out property <int> counter;
+    in property <string> label;
+    VerticalLayout {
+        Text { text: root.label; }
+        TouchArea {
+            clicked => { root.counter += 1; }
+        }
+    }
+}"#;
+    let start = source.find("Text {").unwrap();
+    let end = source.find("        }\n    }\n}").unwrap() + 9;
+    let (titles, result) =
+        run(source, (start, end), "Extract into a new component", &Default::default());
+    assert_eq!(titles, vec!["Extract into a new component"]);
+    assert_eq!(
+        result.unwrap(),
+        r#"component NewComponent {
+    in property <string> label;
+    in-out property <int> counter;
+
+    Text { text: root.label; }
+    TouchArea {
+        clicked => { root.counter += 1; }
+    }
+}
+
+export component Test {
+    in-out property <int> counter;
+    in property <string> label;
+    VerticalLayout {
+        NewComponent {
+            label: root.label;
+            counter <=> root.counter;
+        }
+    }
+}"#
+    );
+
+    // A selection that is not only made of elements
+    let start = source.find("text:").unwrap();
+    let (titles, _) = run(source, (start, start + 10), "", &Default::default());
+    assert!(titles.is_empty());
+
+    // The builtin properties of the new component are not re-declared
+    let source = r#"export component Test {
+    Rectangle {
+        Text { width: parent.width; }
+    }
+}"#;
+    let offset = source.find("Text").unwrap();
+    let (_, result) =
+        run(source, (offset, offset), "Extract into a new component", &Default::default());
+    let result = result.unwrap();
+    assert!(result.contains("in property <length> parent-width;"));
+    assert!(result.contains("parent-width: parent.width;"));
+    assert!(result.contains("Text { width: root.parent-width; }"));
+}
+
+#[test]
+fn test_inline_component() {
+    let source = r#"component Button inherits Rectangle {
+    in property <string> label;
+    background: blue;
+    Text { text: root.label; }
+}
+
+component Label {
+    Text { }
+}
+
+export component Test {
+    Button {
+        label: "Ok";
+        background: red;
+    }
+    Label { }
+}"#;
+    let offset = source.find("Button {\n        label").unwrap();
+    let (titles, result) = run(source, (offset, offset), "Inline `Button`", &Default::default());
+    assert!(titles.contains(&"Inline `Button`".to_string()));
+    assert_eq!(
+        result.unwrap(),
+        r#"component Button inherits Rectangle {
+    in property <string> label;
+    background: blue;
+    Text { text: root.label; }
+}
+
+component Label {
+    Text { }
+}
+
+export component Test {
+    button := Rectangle {
+        in property <string> label;
+        Text { text: button.label; }
+        label: "Ok";
+        background: red;
+    }
+    Label { }
+}"#
+    );
+
+    // A component without a base is inlined as an `Empty`
+    let offset = source.find("Label { }").unwrap();
+    let (_, result) = run(source, (offset, offset), "Inline `Label`", &Default::default());
+    assert!(result.unwrap().contains("    Empty {\n        Text { }\n    }\n}"));
+}
+
+#[test]
+fn test_property_actions() {
+    let source = r#"export component Test {
+    in property <int> value;
+    property <string> name;
+    Text { text: caption; }
+}"#;
+    let offset = source.find("value").unwrap();
+    let (_, result) = run(source, (offset, offset), "Make `value` in-out", &Default::default());
+    assert!(result.unwrap().contains("in-out property <int> value;"));
+    let offset = source.find("name").unwrap();
+    let (_, result) = run(source, (offset, offset), "Make `name` in-out", &Default::default());
+    assert!(result.unwrap().contains("in-out property <string> name;"));
+
+    let offset = source.find("caption").unwrap();
+    let (titles, result) =
+        run(source, (offset, offset), "Declare property `caption`", &Default::default());
+    assert_eq!(titles, vec!["Declare property `caption`"]);
+    assert!(result
+        .unwrap()
+        .starts_with("export component Test {\n    property <string> caption;\n    in property"));
+}
+
+#[test]
+fn test_move_component_to_file() {
+    let source = r#"import { Button } from "std-widgets.slint";
+component MyButton inherits Button { }
+export component Test {
+    MyButton { }
+}"#;
+    let offset = source.find("MyButton").unwrap();
+    let (titles, _) = run(source, (offset, offset), "", &Default::default());
+    assert!(titles.is_empty());
+
+    let capabilities = ClientCapabilities {
+        workspace: Some(lsp_types::WorkspaceClientCapabilities {
+            workspace_edit: Some(lsp_types::WorkspaceEditClientCapabilities {
+                document_changes: Some(true),
+                resource_operations: Some(vec![ResourceOperationKind::Create]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+        ..Default::default()
+    };
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let position = lsp_types::Position::new(1, 12);
+    let actions =
+        refactoring_actions(&dc, &uri, &lsp_types::Range::new(position, position), &capabilities);
+    let Some(CodeActionOrCommand::CodeAction(action)) = actions
+        .iter()
+        .find(|a| matches!(a, CodeActionOrCommand::CodeAction(a) if a.title.starts_with("Move")))
+    else {
+        panic!("no move action in {actions:?}");
+    };
+    assert_eq!(action.title, "Move `MyButton` to \"my_button.slint\"");
+    let Some(DocumentChanges::Operations(operations)) =
+        action.edit.as_ref().and_then(|e| e.document_changes.as_ref())
+    else {
+        panic!("no document changes");
+    };
+    assert_eq!(operations.len(), 3);
+    let edits = |i: usize| match &operations[i] {
+        DocumentChangeOperation::Edit(edit) => edit
+            .edits
+            .iter()
+            .map(|e| match e {
+                OneOf::Left(e) => e.clone(),
+                OneOf::Right(e) => e.text_edit.clone(),
+            })
+            .collect::<Vec<_>>(),
+        _ => panic!("not an edit"),
+    };
+    assert_eq!(
+        apply_edits("", &edits(1)),
+        "import { Button } from \"std-widgets.slint\";\n\nexport component MyButton inherits Button { }\n"
+    );
+    assert_eq!(
+        apply_edits(source, &edits(2)),
+        r#"import { Button } from "std-widgets.slint";
+import { MyButton } from "my_button.slint";
+export component Test {
+    MyButton { }
+}"#
+    );
+}
+
+#[test]
+fn test_separate_words() {
+    assert_eq!(separate_words("MyButton", '_'), "my_button");
+    assert_eq!(separate_words("Foo-Bar2Baz", '-'), "foo-bar2-baz");
+}
@@ -0,0 +1,185 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Find all the references of a property, callback, function, component, global, struct,
+//! enum value or element id.
+//!
+//! The identifiers with the same name in all the loaded documents are resolved with
+//! goto-definition, and the ones that resolve to the same declaration are references.
+
+use super::{goto, DocumentCache};
+use crate::util::map_token;
+
+use i_slint_compiler::parser::{normalize_identifier, syntax_nodes, SyntaxKind, SyntaxToken};
+use lsp_types::{GotoDefinitionResponse, Location, Position, Url};
+
+pub fn find_references(
+    document_cache: &mut DocumentCache,
+    token: SyntaxToken,
+    include_declaration: bool,
+) -> Option<Vec<Location>> {
+    if token.kind() != SyntaxKind::Identifier {
+        return None;
+    }
+    let definition = definition_of(document_cache, &token)?;
+    let name = normalize_identifier(token.text());
+
+    let candidates = document_cache
+        .documents
+        .all_file_documents()
+        .filter(|(path, _)| !path.starts_with("builtin:"))
+        .filter_map(|(_, doc)| doc.node.as_ref())
+        .flat_map(|node| {
+            node.descendants_with_tokens()
+                .filter_map(|t| t.into_token())
+                .filter(|t| {
+                    t.kind() == SyntaxKind::Identifier && normalize_identifier(t.text()) == name
+                })
+                .map(|t| SyntaxToken { token: t, source_file: node.source_file.clone() })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    let mut result = candidates
+        .into_iter()
+        .filter(|t| include_declaration || declaration_location(t).is_none())
+        .filter(|t| definition_of(document_cache, t).as_ref() == Some(&definition))
+        .filter_map(|t| {
+            Some(Location {
+                uri: Url::from_file_path(t.source_file.path()).ok()?,
+                range: map_token(&t)?,
+            })
+        })
+        .collect::<Vec<_>>();
+    result.sort_by(|a, b| {
+        (a.uri.as_str(), a.range.start.line, a.range.start.character).cmp(&(
+            b.uri.as_str(),
+            b.range.start.line,
+            b.range.start.character,
+        ))
+    });
+    result.dedup();
+    Some(result)
+}
+
+/// Returns the location where the item referred to by this token is declared
+pub(super) fn definition_of(
+    document_cache: &mut DocumentCache,
+    token: &SyntaxToken,
+) -> Option<(Url, Position)> {
+    if let Some(location) = declaration_location(token) {
+        return Some(location);
+    }
+    match goto::goto_definition(document_cache, token.clone())? {
+        GotoDefinitionResponse::Link(links) => {
+            let link = links.first()?;
+            Some((link.target_uri.clone(), link.target_range.start))
+        }
+        GotoDefinitionResponse::Scalar(location) => Some((location.uri, location.range.start)),
+        GotoDefinitionResponse::Array(locations) => {
+            let location = locations.first()?;
+            Some((location.uri.clone(), location.range.start))
+        }
+    }
+}
+
+/// If the token is the name in a declaration, return the location that goto-definition reports
+/// for the references to that declaration
+fn declaration_location(token: &SyntaxToken) -> Option<(Url, Position)> {
+    let parent = token.parent();
+    let declaration = match parent.kind() {
+        SyntaxKind::DeclaredIdentifier => {
+            let declaration = parent.parent()?;
+            match declaration.kind() {
+                // Goto-definition of a component goes to its root element
+                SyntaxKind::Component => declaration.child_node(SyntaxKind::Element)?,
+                SyntaxKind::PropertyDeclaration
+                | SyntaxKind::CallbackDeclaration
+                | SyntaxKind::Function
+                | SyntaxKind::StructDeclaration
+                | SyntaxKind::EnumDeclaration => declaration,
+                _ => return None,
+            }
+        }
+        // Goto-definition of an enum value goes to the enum declaration
+        SyntaxKind::EnumValue => syntax_nodes::EnumValue::new(parent)?.parent()?,
+        // The id of an element
+        SyntaxKind::SubElement => parent.child_node(SyntaxKind::Element)?,
+        _ => return None,
+    };
+    let (uri, range) = crate::util::map_node_and_url(&declaration)?;
+    Some((uri, range.start))
+}
+
+#[test]
+fn test_find_references() {
+    let source = r#"
+enum Mode { light, dark }
+component Abc {
+    in-out property <string> hello;
+    in property <Mode> mode: Mode.light;
+}
+export component Test {
+    property <string> other <=> abc.hello;
+    abc := Abc {
+        hello: "foo";
+        mode: Mode.dark;
+    }
+    Text {
+        text: abc.hello;
+    }
+}"#;
+
+    let (mut dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let doc = dc
+        .documents
+        .get_document(&crate::language::uri_to_file(&uri).unwrap())
+        .unwrap()
+        .node
+        .clone()
+        .unwrap();
+
+    let lines = |refs: Vec<Location>| {
+        refs.iter()
+            .map(|l| {
+                assert_eq!(l.uri, uri);
+                l.range.start.line
+            })
+            .collect::<Vec<_>>()
+    };
+
+    // The property, from its declaration, through a two-way binding, a binding and an expression
+    let offset = source.find("hello;").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset).unwrap();
+    assert_eq!(token.text(), "hello");
+    assert_eq!(lines(find_references(&mut dc, token.clone(), true).unwrap()), vec![3, 7, 9, 13]);
+    assert_eq!(lines(find_references(&mut dc, token, false).unwrap()), vec![7, 9, 13]);
+
+    // The same, from a reference
+    let offset = source.find("abc.hello;\n    }").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset + 4).unwrap();
+    assert_eq!(token.text(), "hello");
+    assert_eq!(lines(find_references(&mut dc, token, true).unwrap()), vec![3, 7, 9, 13]);
+
+    // The component
+    let offset = source.find("abc := Abc").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset + 8).unwrap();
+    assert_eq!(token.text(), "Abc");
+    assert_eq!(lines(find_references(&mut dc, token, true).unwrap()), vec![2, 8]);
+
+    // The element id
+    let token = crate::language::token_at_offset(&doc, offset).unwrap();
+    assert_eq!(token.text(), "abc");
+    assert_eq!(lines(find_references(&mut dc, token, true).unwrap()), vec![7, 8, 13]);
+
+    // An enum value
+    let offset = source.find("Mode.dark").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset + 5).unwrap();
+    assert_eq!(token.text(), "dark");
+    assert_eq!(lines(find_references(&mut dc, token, true).unwrap()), vec![1, 10]);
+
+    // Builtin properties have no declaration
+    let offset = source.find("text: abc").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset).unwrap();
+    assert!(find_references(&mut dc, token, true).is_none());
+}
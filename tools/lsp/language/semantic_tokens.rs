@@ -45,6 +45,7 @@ pub fn get_semantic_tokens(
             SyntaxKind::ColorLiteral => Some((self::NUMBER, 0)),
             SyntaxKind::Identifier => match token.parent().kind() {
                 SyntaxKind::Component => Some((self::KEYWORD, 0)),
+                SyntaxKind::ImplementsSpecifier => Some((self::KEYWORD, 0)),
                 // the id of the element
                 SyntaxKind::SubElement => Some((self::VARIABLE, 1 << self::DEFINITION)),
                 SyntaxKind::RepeatedElement => Some((self::KEYWORD, 0)),
@@ -60,6 +61,7 @@ pub fn get_semantic_tokens(
                     SyntaxKind::Type => Some((self::TYPE, 0)),
                     // the base type
                     SyntaxKind::Element => Some((self::TYPE, 0)),
+                    SyntaxKind::ImplementsSpecifier => Some((self::TYPE, 0)),
                     // FIXME: we should do actual lookup
                     SyntaxKind::Expression => None,
                     SyntaxKind::StatePropertyChange => Some((self::PROPERTY, 0)),
@@ -0,0 +1,279 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Signature help for the calls to callbacks, functions, builtin functions and `@image-url`.
+
+use super::DocumentCache;
+use crate::util::with_lookup_ctx;
+
+use i_slint_compiler::expression_tree::{BuiltinMacroFunction, Expression};
+use i_slint_compiler::langtype::{ElementType, Type};
+use i_slint_compiler::lookup::{LookupObject, LookupResult};
+use i_slint_compiler::namedreference::NamedReference;
+use i_slint_compiler::parser::{
+    identifier_text, normalize_identifier, syntax_nodes, SyntaxKind, SyntaxNode, SyntaxToken,
+    TextSize,
+};
+use lsp_types::{ParameterInformation, ParameterLabel, SignatureHelp, SignatureInformation};
+
+pub struct Parameter {
+    /// The name, if it is declared or if the builtin function has a well known name for it
+    pub name: Option<String>,
+    pub ty: Option<Type>,
+}
+
+impl Parameter {
+    fn label(&self) -> String {
+        match (&self.name, &self.ty) {
+            (Some(name), Some(ty)) => format!("{name}: {ty}"),
+            (Some(name), None) => name.clone(),
+            (None, Some(ty)) => ty.to_string(),
+            (None, None) => "_".into(),
+        }
+    }
+}
+
+pub struct Signature {
+    pub name: String,
+    pub parameters: Vec<Parameter>,
+    pub return_type: Option<Type>,
+}
+
+impl Signature {
+    fn into_signature_information(self) -> SignatureInformation {
+        let parameters = self.parameters.iter().map(Parameter::label).collect::<Vec<_>>();
+        let mut label = format!("{}({})", self.name, parameters.join(", "));
+        if let Some(return_type) = self.return_type.filter(|t| *t != Type::Void) {
+            label += &format!(" -> {return_type}");
+        }
+        SignatureInformation {
+            label,
+            documentation: None,
+            parameters: Some(
+                parameters
+                    .into_iter()
+                    .map(|p| ParameterInformation {
+                        label: ParameterLabel::Simple(p),
+                        documentation: None,
+                    })
+                    .collect(),
+            ),
+            active_parameter: None,
+        }
+    }
+}
+
+pub fn signature_help(
+    document_cache: &DocumentCache,
+    token: SyntaxToken,
+    offset: u32,
+) -> Option<SignatureHelp> {
+    let offset = TextSize::from(offset);
+    let mut node = token.parent();
+    loop {
+        if within_parentheses(&node, offset) {
+            match node.kind() {
+                SyntaxKind::AtImageUrl => {
+                    let signature = Signature {
+                        name: "@image-url".into(),
+                        parameters: vec![Parameter {
+                            name: Some("path".into()),
+                            ty: Some(Type::String),
+                        }],
+                        return_type: None,
+                    };
+                    return Some(SignatureHelp {
+                        signatures: vec![signature.into_signature_information()],
+                        active_signature: Some(0),
+                        active_parameter: Some(0),
+                    });
+                }
+                SyntaxKind::FunctionCallExpression => {
+                    let active_parameter = node
+                        .children_with_tokens()
+                        .filter(|n| {
+                            n.kind() == SyntaxKind::Comma && n.text_range().start() < offset
+                        })
+                        .count();
+                    let signature = function_signature(
+                        document_cache,
+                        &syntax_nodes::FunctionCallExpression::new(node)?,
+                    )?;
+                    return Some(SignatureHelp {
+                        signatures: vec![signature.into_signature_information()],
+                        active_signature: Some(0),
+                        active_parameter: Some(active_parameter as u32),
+                    });
+                }
+                _ => (),
+            }
+        }
+        node = node.parent()?;
+    }
+}
+
+/// Returns true if the offset is between the parentheses of the node
+fn within_parentheses(node: &SyntaxNode, offset: TextSize) -> bool {
+    node.child_token(SyntaxKind::LParent).map_or(false, |t| t.text_range().end() <= offset)
+        && node.child_token(SyntaxKind::RParent).map_or(true, |t| offset <= t.text_range().start())
+}
+
+/// Returns the signature of the function that is called
+pub fn function_signature(
+    document_cache: &DocumentCache,
+    call: &syntax_nodes::FunctionCallExpression,
+) -> Option<Signature> {
+    let callee = call.Expression().next()?.QualifiedName()?;
+    let identifiers = callee
+        .children_with_tokens()
+        .filter_map(|t| t.into_token())
+        .filter(|t| t.kind() == SyntaxKind::Identifier)
+        .collect::<Vec<_>>();
+
+    let lookup_result = with_lookup_ctx(document_cache, (*callee).clone(), |ctx| {
+        let mut it = identifiers.iter().map(|t| normalize_identifier(t.text()));
+        let global = i_slint_compiler::lookup::global_lookup();
+        let mut result = global.lookup(ctx, &it.next()?)?;
+        for name in it {
+            result = result.lookup(ctx, &name)?;
+        }
+        Some(result)
+    })??;
+    let LookupResult::Expression { expression, .. } = lookup_result else {
+        return None;
+    };
+
+    let (parameters, return_type) = match expression {
+        Expression::BuiltinMacroReference(mac, _) => (macro_parameters(&mac), None),
+        Expression::MemberFunction { member, .. } => match *member {
+            Expression::BuiltinMacroReference(mac, _) => (macro_parameters(&mac), None),
+            // The first argument is the object on which the function is called
+            member => function_parameters(member.ty(), 1)?,
+        },
+        Expression::CallbackReference(nr, _) | Expression::FunctionReference(nr, _) => {
+            declared_parameters(&nr)?
+        }
+        expression @ Expression::BuiltinFunctionReference(..) => {
+            function_parameters(expression.ty(), 0)?
+        }
+        _ => return None,
+    };
+
+    let name = identifiers.iter().map(|t| t.text().to_string()).collect::<Vec<_>>().join(".");
+    Some(Signature { name, parameters, return_type })
+}
+
+fn function_parameters(ty: Type, skip: usize) -> Option<(Vec<Parameter>, Option<Type>)> {
+    let (args, return_type) = match ty {
+        Type::Function { args, return_type } => (args, Some(*return_type)),
+        Type::Callback { args, return_type } => (args, return_type.map(|t| *t)),
+        _ => return None,
+    };
+    let parameters =
+        args.into_iter().skip(skip).map(|ty| Parameter { name: None, ty: Some(ty) }).collect();
+    Some((parameters, return_type))
+}
+
+/// The parameters of a callback or a function, with the names of the arguments of functions
+/// declared in a .slint file
+fn declared_parameters(nr: &NamedReference) -> Option<(Vec<Parameter>, Option<Type>)> {
+    let (mut parameters, return_type) = function_parameters(nr.ty(), 0)?;
+
+    let mut element = nr.element();
+    let declaration = loop {
+        if let Some(decl) = element.borrow().property_declarations.get(nr.name()) {
+            break decl.node.clone();
+        }
+        let base = element.borrow().base_type.clone();
+        match base {
+            ElementType::Component(c) => element = c.root_element.clone(),
+            _ => break None,
+        }
+    };
+    if let Some(function) = declaration.and_then(syntax_nodes::Function::new) {
+        for (parameter, argument) in parameters.iter_mut().zip(function.ArgumentDeclaration()) {
+            parameter.name = identifier_text(&argument.DeclaredIdentifier());
+        }
+    }
+    Some((parameters, return_type))
+}
+
+fn macro_parameters(mac: &BuiltinMacroFunction) -> Vec<Parameter> {
+    use BuiltinMacroFunction as M;
+    let names: &[&str] = match mac {
+        M::Min | M::Max | M::Debug => &["values..."],
+        M::Clamp => &["value", "min", "max"],
+        M::Mod => &["lhs", "rhs"],
+        M::CubicBezier => &["x1", "y1", "x2", "y2"],
+        M::Spring => &["stiffness", "damping", "mass"],
+        M::Rgb => &["red", "green", "blue", "alpha"],
+        M::Assert => &["condition", "message"],
+        M::AssertEq => &["left", "right", "message"],
+        M::Keyframes => &["progress", "values", "stops"],
+        M::ArraySum => &[],
+        M::ArrayContains | M::ArrayIndexOf => &["value"],
+        M::ArraySlice => &["start", "end"],
+        M::ArrayJoin => &["separator"],
+    };
+    names.iter().map(|name| Parameter { name: Some(name.to_string()), ty: None }).collect()
+}
+
+#[cfg(test)]
+pub(super) const TEST_SOURCE: &str = r#"
+export component Test {
+    in-out property <int> value;
+    property alias <=> value;
+    function add(first: int, second: int) -> int { return first + second; }
+    callback clicked(string, int);
+    Rectangle {
+        background: rgb(1, 2, 3);
+        x: add(1, value) * 1px;
+        TouchArea { clicked => { root.clicked("a", add(2, 3)); } }
+    }
+    Image { source: @image-url("foo.png"); }
+}"#;
+
+#[test]
+fn test_signature_help() {
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(TEST_SOURCE.into());
+    let doc = dc
+        .documents
+        .get_document(&crate::language::uri_to_file(&uri).unwrap())
+        .unwrap()
+        .node
+        .clone()
+        .unwrap();
+
+    let help = |offset: usize| {
+        let offset = offset as u32;
+        let token = crate::language::token_at_offset(&doc, offset).unwrap();
+        let help = signature_help(&dc, token, offset).unwrap();
+        (help.signatures[0].label.clone(), help.active_parameter.unwrap())
+    };
+
+    assert_eq!(
+        help(TEST_SOURCE.find("add(1, value)").unwrap() + 7),
+        ("add(first: int, second: int) -> int".to_string(), 1)
+    );
+    assert_eq!(
+        help(TEST_SOURCE.find("rgb(").unwrap() + 4),
+        ("rgb(red, green, blue, alpha)".to_string(), 0)
+    );
+    assert_eq!(
+        help(TEST_SOURCE.find("\"a\"").unwrap()),
+        ("root.clicked(string, int)".to_string(), 0)
+    );
+    assert_eq!(
+        help(TEST_SOURCE.find("add(2, 3)").unwrap() + 4),
+        ("add(first: int, second: int) -> int".to_string(), 0)
+    );
+    assert_eq!(
+        help(TEST_SOURCE.find("\"foo.png\"").unwrap() + 1),
+        ("@image-url(path: string)".to_string(), 0)
+    );
+
+    // Not in a call
+    let offset = TEST_SOURCE.find("background").unwrap() as u32;
+    let token = crate::language::token_at_offset(&doc, offset).unwrap();
+    assert!(signature_help(&dc, token, offset).is_none());
+}
@@ -0,0 +1,115 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Search of the components, globals, interfaces, structs and enums by name in all the loaded documents.
+
+use super::DocumentCache;
+use crate::util::map_node;
+
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::object_tree::Component;
+use i_slint_compiler::parser::{syntax_nodes, SyntaxKind, SyntaxNode};
+use lsp_types::{Location, OneOf, SymbolKind, Url, WorkspaceSymbol};
+
+pub fn component_symbol_kind(component: &Component) -> SymbolKind {
+    if component.is_global() {
+        SymbolKind::OBJECT
+    } else if component.is_interface {
+        SymbolKind::INTERFACE
+    } else {
+        SymbolKind::CLASS
+    }
+}
+
+pub fn workspace_symbols(document_cache: &DocumentCache, query: &str) -> Vec<WorkspaceSymbol> {
+    let mut result = Vec::new();
+    for (path, doc) in document_cache.documents.all_file_documents() {
+        if path.starts_with("builtin:") {
+            continue;
+        }
+        let Ok(uri) = Url::from_file_path(path) else { continue };
+        let mut add = |name: &str, kind: SymbolKind, name_node: Option<SyntaxNode>| {
+            if name.is_empty() || !matches_query(name, query) {
+                return;
+            }
+            let Some(range) = name_node.as_ref().and_then(map_node) else { return };
+            result.push(WorkspaceSymbol {
+                name: name.into(),
+                kind,
+                tags: None,
+                container_name: None,
+                location: OneOf::Left(Location { uri: uri.clone(), range }),
+                data: None,
+            });
+        };
+
+        for c in &doc.inner_components {
+            let name_node = c
+                .root_element
+                .borrow()
+                .node
+                .first()
+                .and_then(|n| syntax_nodes::Component::new(n.parent()?))
+                .map(|c| c.DeclaredIdentifier().into());
+            add(&c.id, component_symbol_kind(c), name_node);
+        }
+        for ty in &doc.inner_types {
+            match ty {
+                Type::Struct { name: Some(name), node: Some(node), .. } => add(
+                    name,
+                    SymbolKind::STRUCT,
+                    node.parent().and_then(|n| n.child_node(SyntaxKind::DeclaredIdentifier)),
+                ),
+                Type::Enumeration(e) => add(
+                    &e.name,
+                    SymbolKind::ENUM,
+                    e.node.as_ref().map(|n| n.DeclaredIdentifier().into()),
+                ),
+                _ => (),
+            }
+        }
+    }
+    result.sort_by(|a, b| a.name.cmp(&b.name));
+    result
+}
+
+/// Case insensitive fuzzy match: all the characters of the query are in the name, in the same order
+fn matches_query(name: &str, query: &str) -> bool {
+    let normalize = |c: char| if c == '_' { '-' } else { c.to_ascii_lowercase() };
+    let mut name = name.chars().map(normalize);
+    query.chars().filter(|c| !c.is_whitespace()).map(normalize).all(|q| name.any(|c| c == q))
+}
+
+#[test]
+fn test_workspace_symbols() {
+    let source = r#"
+struct Point { x: int, y: int }
+enum Direction { up, down }
+global Palette { in-out property <color> background; }
+component PushButton { }
+export component Test {
+    PushButton { }
+}"#;
+    let (dc, uri, _) = crate::language::test::loaded_document_cache(source.into());
+    let symbols = |query: &str| {
+        workspace_symbols(&dc, query)
+            .into_iter()
+            .filter(|s| matches!(&s.location, OneOf::Left(l) if l.uri == uri))
+            .map(|s| (s.name, s.kind))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        symbols(""),
+        vec![
+            ("Direction".to_string(), SymbolKind::ENUM),
+            ("Palette".to_string(), SymbolKind::OBJECT),
+            ("Point".to_string(), SymbolKind::STRUCT),
+            ("PushButton".to_string(), SymbolKind::CLASS),
+            ("Test".to_string(), SymbolKind::CLASS),
+        ]
+    );
+    assert_eq!(symbols("pbtn"), vec![("PushButton".to_string(), SymbolKind::CLASS)]);
+    assert_eq!(symbols("oin"), vec![("Point".to_string(), SymbolKind::STRUCT)]);
+    assert!(symbols("xyz").is_empty());
+}
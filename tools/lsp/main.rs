@@ -18,9 +18,13 @@
 
 use i_slint_compiler::CompilerConfiguration;
 use lsp_types::notification::{
-    DidChangeConfiguration, DidChangeTextDocument, DidOpenTextDocument, Notification,
+    DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidOpenTextDocument,
+    Notification,
+};
+use lsp_types::{
+    DidChangeTextDocumentParams, DidChangeWatchedFilesParams, DidOpenTextDocumentParams,
+    InitializeParams, Url,
 };
-use lsp_types::{DidChangeTextDocumentParams, DidOpenTextDocumentParams, InitializeParams, Url};
 
 use clap::Parser;
 use lsp_server::{Connection, ErrorCode, IoThreads, Message, RequestId, Response};
@@ -58,6 +62,11 @@ pub struct Cli {
     /// Hide the preview toolbar
     #[arg(long, action)]
     no_toolbar: bool,
+
+    /// Warn about private properties, callbacks, functions, element ids, imports
+    /// and components that are never used
+    #[arg(long, action)]
+    warn_unused: bool,
 }
 
 enum OutgoingRequest {
@@ -76,6 +85,8 @@ pub struct ServerNotifier {
     sender: crossbeam_channel::Sender<Message>,
     queue: OutgoingRequestQueue,
     use_external_preview: std::cell::Cell<bool>,
+    #[cfg(feature = "preview-engine")]
+    preview_to_lsp_sender: crossbeam_channel::Sender<common::PreviewToLspMessage>,
 }
 impl ServerNotifier {
     pub fn send_notification(&self, method: String, params: impl serde::Serialize) -> Result<()> {
@@ -123,6 +134,12 @@ pub fn send_preview_message(&self, message: LspToPreviewMessage) {
             preview::lsp_to_preview_message(message, self);
         }
     }
+
+    /// Queue a message from the built-in preview, to be handled in the LSP thread
+    #[cfg(feature = "preview-engine")]
+    pub fn send_message_to_lsp(&self, message: common::PreviewToLspMessage) {
+        let _ = self.preview_to_lsp_sender.send(message);
+    }
 }
 
 impl RequestHandler {
@@ -215,10 +232,16 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
     register_request_handlers(&mut rh);
 
     let request_queue = OutgoingRequestQueue::default();
+    #[cfg(feature = "preview-engine")]
+    let (preview_to_lsp_sender, preview_to_lsp_receiver) = crossbeam_channel::unbounded();
+    #[cfg(not(feature = "preview-engine"))]
+    let preview_to_lsp_receiver = crossbeam_channel::never::<common::PreviewToLspMessage>();
     let server_notifier = ServerNotifier {
         sender: connection.sender.clone(),
         queue: request_queue.clone(),
         use_external_preview: Default::default(),
+        #[cfg(feature = "preview-engine")]
+        preview_to_lsp_sender,
     };
 
     let mut compiler_config =
@@ -227,6 +250,7 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
     compiler_config.style =
         Some(if cli_args.style.is_empty() { "native".into() } else { cli_args.style });
     compiler_config.include_paths = cli_args.include_paths;
+    compiler_config.warn_unused = cli_args.warn_unused;
     let server_notifier_ = server_notifier.clone();
     compiler_config.open_import_fallback = Some(Rc::new(move |path| {
         let server_notifier = server_notifier_.clone();
@@ -256,13 +280,19 @@ fn main_loop(connection: Connection, init_param: InitializeParams, cli_args: Cli
     });
 
     let mut futures = Vec::<Pin<Box<dyn Future<Output = Result<()>>>>>::new();
-    let mut first_future = Box::pin(load_configuration(&ctx));
+    let mut first_future = Box::pin(async {
+        load_configuration(&ctx).await?;
+        // The include paths of the configuration are needed to load the files
+        load_workspace_files(&ctx).await;
+        register_file_watcher(&ctx).await
+    });
 
     // We are waiting in this loop for two kind of futures:
     //  - The compiler future should always be ready immediately because we do not set a callback to load files
     //  - the future from `send_request` are blocked waiting for a response from the client.
     //    Responses are sent on the `connection.receiver` which will wake the loop, so there
     //    is no need to do anything in the Waker.
+    // Messages from the built-in preview arrive on `preview_to_lsp_receiver` and also wake the loop.
     struct DummyWaker;
     impl std::task::Wake for DummyWaker {
         fn wake(self: Arc<Self>) {}
@@ -273,31 +303,44 @@ fn wake(self: Arc<Self>) {}
         Poll::Pending => futures.push(first_future),
     };
 
-    for msg in &connection.receiver {
-        match msg {
-            Message::Request(req) => {
-                // ignore errors when shutdown
-                if connection.handle_shutdown(&req).unwrap_or(false) {
-                    return Ok(());
-                }
-                futures.push(Box::pin(rh.handle_request(req, &ctx)));
-            }
-            Message::Response(resp) => {
-                if let Some(q) = request_queue.lock().unwrap().get_mut(&resp.id) {
-                    match q {
-                        OutgoingRequest::Done(_) => {
-                            return Err("Response to unknown request".into())
+    loop {
+        crossbeam_channel::select! {
+            recv(connection.receiver) -> msg => {
+                let Ok(msg) = msg else { return Ok(()) };
+                match msg {
+                    Message::Request(req) => {
+                        // ignore errors when shutdown
+                        if connection.handle_shutdown(&req).unwrap_or(false) {
+                            return Ok(());
                         }
-                        OutgoingRequest::Start => { /* nothing to do */ }
-                        OutgoingRequest::Pending(x) => x.wake_by_ref(),
-                    };
-                    *q = OutgoingRequest::Done(resp)
-                } else {
-                    return Err("Response to unknown request".into());
+                        futures.push(Box::pin(rh.handle_request(req, &ctx)));
+                    }
+                    Message::Response(resp) => {
+                        if let Some(q) = request_queue.lock().unwrap().get_mut(&resp.id) {
+                            match q {
+                                OutgoingRequest::Done(_) => {
+                                    return Err("Response to unknown request".into())
+                                }
+                                OutgoingRequest::Start => { /* nothing to do */ }
+                                OutgoingRequest::Pending(x) => x.wake_by_ref(),
+                            };
+                            *q = OutgoingRequest::Done(resp)
+                        } else {
+                            return Err("Response to unknown request".into());
+                        }
+                    }
+                    Message::Notification(notification) => {
+                        futures.push(Box::pin(handle_notification(notification, &ctx)))
+                    }
                 }
             }
-            Message::Notification(notification) => {
-                futures.push(Box::pin(handle_notification(notification, &ctx)))
+            recv(preview_to_lsp_receiver) -> msg => {
+                #[cfg(feature = "preview-engine")]
+                if let Ok(msg) = msg {
+                    futures.push(Box::pin(handle_preview_to_lsp_message(msg, &ctx)));
+                }
+                #[cfg(not(feature = "preview-engine"))]
+                let _ = msg;
             }
         }
 
@@ -316,7 +359,6 @@ fn wake(self: Arc<Self>) {}
         });
         result?;
     }
-    Ok(())
 }
 
 async fn handle_notification(req: lsp_server::Notification, ctx: &Rc<Context>) -> Result<()> {
@@ -346,6 +388,10 @@ async fn handle_notification(req: lsp_server::Notification, ctx: &Rc<Context>) -
         DidChangeConfiguration::METHOD => {
             load_configuration(ctx).await?;
         }
+        DidChangeWatchedFiles::METHOD => {
+            let params: DidChangeWatchedFilesParams = serde_json::from_value(req.params)?;
+            workspace_files_changed(ctx, params).await?;
+        }
 
         #[cfg(any(feature = "preview-builtin", feature = "preview-external"))]
         "slint/showPreview" => {
@@ -357,36 +403,68 @@ async fn handle_notification(req: lsp_server::Notification, ctx: &Rc<Context>) -
 
         #[cfg(all(feature = "preview-external", feature = "preview-engine"))]
         "slint/preview_to_lsp" => {
-            use common::PreviewToLspMessage as M;
-            let params: M = serde_json::from_value(req.params)?;
-            match params {
-                M::Status { message, health } => {
-                    crate::preview::send_status_notification(
-                        &ctx.server_notifier,
-                        &message,
-                        health,
-                    );
-                }
-                M::Diagnostics { uri, diagnostics } => {
-                    crate::preview::notify_lsp_diagnostics(&ctx.server_notifier, uri, diagnostics);
-                }
-                M::ShowDocument { file, selection } => {
-                    send_show_document_to_editor(ctx.server_notifier.clone(), file, selection)
-                        .await;
-                }
-                M::PreviewTypeChanged { is_external } => {
-                    ctx.server_notifier.use_external_preview.set(is_external);
-                }
-                M::RequestState { .. } => {
-                    crate::language::request_state(ctx);
-                }
-            }
+            let params: common::PreviewToLspMessage = serde_json::from_value(req.params)?;
+            handle_preview_to_lsp_message(params, ctx).await?;
         }
         _ => (),
     }
     Ok(())
 }
 
+#[cfg(feature = "preview-engine")]
+async fn handle_preview_to_lsp_message(
+    message: common::PreviewToLspMessage,
+    ctx: &Rc<Context>,
+) -> Result<()> {
+    use common::PreviewToLspMessage as M;
+    match message {
+        M::Status { message, health } => {
+            crate::preview::send_status_notification(&ctx.server_notifier, &message, health);
+        }
+        M::Diagnostics { uri, diagnostics } => {
+            crate::preview::notify_lsp_diagnostics(&ctx.server_notifier, uri, diagnostics);
+        }
+        M::ShowDocument { file, selection } => {
+            send_show_document_to_editor(ctx.server_notifier.clone(), file, selection).await;
+        }
+        M::PreviewTypeChanged { is_external } => {
+            ctx.server_notifier.use_external_preview.set(is_external);
+        }
+        M::RequestState { .. } => {
+            #[cfg(feature = "preview-external")]
+            crate::language::request_state(ctx);
+        }
+        M::SetBinding { url, element_range, property, expression } => {
+            update_binding_from_preview(ctx, url, element_range, property, Some(expression)).await;
+        }
+        M::RemoveBinding { url, element_range, property } => {
+            update_binding_from_preview(ctx, url, element_range, property, None).await;
+        }
+    }
+    Ok(())
+}
+
+/// Errors are reported to the user instead of being propagated, as they must not stop the server
+#[cfg(feature = "preview-engine")]
+async fn update_binding_from_preview(
+    ctx: &Rc<Context>,
+    url: common::VersionedUrl,
+    element_range: lsp_types::Range,
+    property_name: String,
+    expression: Option<String>,
+) {
+    if let Err(e) =
+        language::update_binding_from_preview(ctx, url, element_range, property_name, expression)
+            .await
+    {
+        crate::preview::send_status_notification(
+            &ctx.server_notifier,
+            &format!("Failed to update the binding: {e}"),
+            lsp_ext::Health::Error,
+        );
+    }
+}
+
 #[cfg(feature = "preview-engine")]
 pub async fn send_show_document_to_editor(
     sender: ServerNotifier,
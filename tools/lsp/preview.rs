@@ -2,7 +2,8 @@
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
 use crate::common::{
-    ComponentInformation, LspToPreviewMessage, PreviewComponent, PreviewConfig, VersionedUrl,
+    ComponentInformation, LspToPreviewMessage, PreviewComponent, PreviewConfig,
+    PreviewToLspMessage, VersionedUrl,
 };
 use crate::lsp_ext::Health;
 use i_slint_compiler::object_tree::{ElementRc, ElementWeak};
@@ -20,8 +21,10 @@
 #[cfg(target_arch = "wasm32")]
 use crate::wasm_prelude::*;
 
+mod data;
 mod debug;
 mod element_selection;
+mod matrix;
 mod ui;
 #[cfg(all(target_arch = "wasm32", feature = "preview-external"))]
 mod wasm;
@@ -63,6 +66,10 @@ struct PreviewState {
     ui: Option<ui::PreviewUi>,
     handle: Rc<RefCell<Option<slint_interpreter::ComponentInstance>>>,
     selected_element: Option<ElementWeak>,
+    /// Where the selected element starts, to select it again after reloading
+    selected_element_location: Option<(PathBuf, u32)>,
+    /// The data applied from the data editor, by preview data key
+    preview_data: HashMap<String, serde_json::Value>,
 }
 thread_local! {static PREVIEW_STATE: std::cell::RefCell<PreviewState> = Default::default();}
 
@@ -95,11 +102,160 @@ fn can_drop_component(component_name: slint::SharedString, x: f32, y: f32) -> bo
 }
 
 // triggered from the UI, running in UI thread
-fn drop_component(component_name: slint::SharedString, x: f32, y: f32) {
-    i_slint_core::debug_log!("drop! {} at {x}x{y}", component_name.as_str());
+fn set_binding(property_name: &str, expression: String) {
+    let Some((url, element_range)) = selected_element_range() else {
+        return;
+    };
+    send_message_to_lsp(PreviewToLspMessage::SetBinding {
+        url: VersionedUrl { url, version: None },
+        element_range,
+        property: property_name.to_string(),
+        expression,
+    });
 }
 
-fn change_style() {
+// triggered from the UI, running in UI thread
+fn remove_binding(property_name: &str) {
+    let Some((url, element_range)) = selected_element_range() else {
+        return;
+    };
+    send_message_to_lsp(PreviewToLspMessage::RemoveBinding {
+        url: VersionedUrl { url, version: None },
+        element_range,
+        property: property_name.to_string(),
+    });
+}
+
+/// The component being previewed, and the key to its data in `PreviewState::preview_data`
+fn current_preview_data_key(instance: &ComponentInstance) -> Option<(PreviewComponent, String)> {
+    let mut current = CONTENT_CACHE.get()?.lock().unwrap().current.clone()?;
+    if current.component.is_none() {
+        current.component = Some(instance.definition().name().to_string());
+    }
+    let key = format!("{}#{}", current.url, current.component.as_deref().unwrap_or_default());
+    Some((current, key))
+}
+
+fn fixture_path(current: &PreviewComponent) -> Option<PathBuf> {
+    current.component.as_deref().and_then(|c| data::fixture_path(&current.url, c))
+}
+
+/// Set the data of the previewed component on the instance: From the data editor or from the
+/// fixture file, and the placeholder data for empty models.
+///
+/// Returns the data to show in the data editor, which doesn't include the placeholders, and the errors.
+fn set_preview_data(
+    instance: &ComponentInstance,
+    ui: &ui::PreviewUi,
+) -> (serde_json::Value, Vec<String>) {
+    let Some((current, key)) = current_preview_data_key(instance) else {
+        return (data::current_data(instance), Vec::new());
+    };
+
+    let edited_data = PREVIEW_STATE.with(|ps| ps.borrow().preview_data.get(&key).cloned());
+
+    let mut errors = Vec::new();
+    let data = match (edited_data, fixture_path(&current)) {
+        (Some(data), _) => Some(data),
+        (None, Some(path)) => data::load_fixture(&path).unwrap_or_else(|e| {
+            errors.push(e);
+            None
+        }),
+        (None, None) => None,
+    };
+    if let Some(data) = &data {
+        errors.extend(data::set_properties(instance, data));
+    }
+    let current_data = data::current_data(instance);
+    if ui.get_placeholder_data() {
+        data::fill_empty_models(instance);
+    }
+    (current_data, errors)
+}
+
+/// Set the data of the previewed component and show it in the data editor
+fn apply_preview_data() {
+    let Some(instance) = component_instance() else {
+        return;
+    };
+    let Some(ui) = PREVIEW_STATE.with(|ps| ps.borrow().ui.as_ref().map(|ui| ui.clone_strong()))
+    else {
+        return;
+    };
+
+    let (current_data, errors) = set_preview_data(&instance, &ui);
+
+    let fixture_name = if cfg!(target_arch = "wasm32") {
+        None
+    } else {
+        current_preview_data_key(&instance)
+            .and_then(|(current, _)| fixture_path(&current))
+            .and_then(|p| Some(p.file_name()?.to_string_lossy().to_string()))
+    };
+    ui.set_preview_data_fixture_name(fixture_name.unwrap_or_default().into());
+    ui.set_preview_data(serde_json::to_string_pretty(&current_data).unwrap_or_default().into());
+    ui.set_preview_data_error(errors.join("\n").into());
+}
+
+// triggered from the UI, running in UI thread
+fn apply_preview_data_from_editor(data: slint::SharedString) {
+    let Some(instance) = component_instance() else {
+        return;
+    };
+    let Some((_, key)) = current_preview_data_key(&instance) else {
+        return;
+    };
+    let errors = match serde_json::from_str::<serde_json::Value>(&data) {
+        Ok(data) => {
+            let errors = data::set_properties(&instance, &data);
+            PREVIEW_STATE.with(|ps| ps.borrow_mut().preview_data.insert(key, data));
+            errors
+        }
+        Err(e) => vec![e.to_string()],
+    };
+    PREVIEW_STATE.with(|ps| {
+        if let Some(ui) = &ps.borrow().ui {
+            ui.set_preview_data_error(errors.join("\n").into());
+        }
+    });
+}
+
+// triggered from the UI, running in UI thread
+fn save_preview_data(data: slint::SharedString) {
+    let Some(instance) = component_instance() else {
+        return;
+    };
+    let Some((current, _)) = current_preview_data_key(&instance) else {
+        return;
+    };
+    let Some(path) = fixture_path(&current) else {
+        return;
+    };
+
+    let result = serde_json::from_str::<serde_json::Value>(&data)
+        .map_err(|e| e.to_string())
+        .and_then(|data| serde_json::to_string_pretty(&data).map_err(|e| e.to_string()))
+        .and_then(|data| std::fs::write(&path, data + "\n").map_err(|e| e.to_string()));
+    PREVIEW_STATE.with(|ps| {
+        if let Some(ui) = &ps.borrow().ui {
+            ui.set_preview_data_error(result.err().unwrap_or_default().into());
+        }
+    });
+}
+
+// triggered from the UI, running in UI thread
+fn reset_preview_data() {
+    let Some(instance) = component_instance() else {
+        return;
+    };
+    let Some((_, key)) = current_preview_data_key(&instance) else {
+        return;
+    };
+    PREVIEW_STATE.with(|ps| ps.borrow_mut().preview_data.remove(&key));
+    reload_current_preview();
+}
+
+fn reload_current_preview() {
     let cache = CONTENT_CACHE.get_or_init(Default::default).lock().unwrap();
     let ui_is_visible = cache.ui_is_visible;
     let Some(current) = cache.current.clone() else {
@@ -112,6 +268,15 @@ fn change_style() {
     }
 }
 
+// triggered from the UI, running in UI thread
+fn drop_component(component_name: slint::SharedString, x: f32, y: f32) {
+    i_slint_core::debug_log!("drop! {} at {x}x{y}", component_name.as_str());
+}
+
+fn change_style() {
+    reload_current_preview();
+}
+
 fn start_parsing() {
     set_status_text("Updating Preview...");
     set_diagnostics(&[]);
@@ -236,6 +401,21 @@ async fn reload_preview_impl(
 
     start_parsing();
 
+    let (compiled, diagnostics) = compile(&component, &style, &config).await;
+    notify_diagnostics(&diagnostics);
+
+    let success = compiled.is_some();
+    update_preview_area(compiled.clone());
+    matrix::update_matrix(&component, &style, compiled, &config).await;
+    finish_parsing(success);
+}
+
+/// Compile the component to preview with the given style
+async fn compile(
+    component: &PreviewComponent,
+    style: &str,
+    config: &PreviewConfig,
+) -> (Option<ComponentDefinition>, Vec<slint_interpreter::Diagnostic>) {
     let mut builder = slint_interpreter::ComponentCompiler::default();
 
     #[cfg(target_arch = "wasm32")]
@@ -245,10 +425,10 @@ async fn reload_preview_impl(
     }
 
     if !style.is_empty() {
-        builder.set_style(style.clone());
+        builder.set_style(style.to_string());
     }
-    builder.set_include_paths(config.include_paths);
-    builder.set_library_paths(config.library_paths);
+    builder.set_include_paths(config.include_paths.clone());
+    builder.set_library_paths(config.library_paths.clone());
 
     builder.set_file_loader(|path| {
         let path = path.to_owned();
@@ -270,11 +450,7 @@ async fn reload_preview_impl(
         builder.build_from_path(path).await
     };
 
-    notify_diagnostics(builder.diagnostics());
-
-    let success = compiled.is_some();
-    update_preview_area(compiled);
-    finish_parsing(success);
+    (compiled, builder.diagnostics().clone())
 }
 
 /// This sets up the preview area to show the ComponentInstance
@@ -470,10 +646,15 @@ fn set_selected_element(
         let mut preview_state = preview_state.borrow_mut();
         if let Some((e, _)) = element_position {
             preview_state.selected_element = Some(Rc::downgrade(e));
+            preview_state.selected_element_location = element_selection::element_offset(e);
         } else {
             preview_state.selected_element = None;
+            preview_state.selected_element_location = None;
         }
 
+        if let Some(ui) = &preview_state.ui {
+            ui::ui_set_properties(ui, element_position.map(|(e, _)| e));
+        }
         set_selections(preview_state.ui.as_ref(), element_position, secondary);
     })
 }
@@ -488,6 +669,18 @@ fn selected_element() -> Option<ElementRc> {
     })
 }
 
+fn selected_element_location() -> Option<(PathBuf, u32)> {
+    PREVIEW_STATE.with(|preview_state| preview_state.borrow().selected_element_location.clone())
+}
+
+/// The url and range of the selected element, as expected by the `setBinding` command
+fn selected_element_range() -> Option<(Url, lsp_types::Range)> {
+    let element = selected_element()?;
+    let element = element.borrow();
+    let node = crate::common::filter_ignore_nodes_in_element(&element).next()?;
+    crate::util::map_node_and_url(node)
+}
+
 fn component_instance() -> Option<ComponentInstance> {
     PREVIEW_STATE.with(move |preview_state| {
         preview_state.borrow().handle.borrow().as_ref().map(|ci| ci.clone_strong())
@@ -572,6 +765,11 @@ fn update_preview_area(compiled: Option<ComponentDefinition>) {
                 compiled,
                 Box::new(move |instance| {
                     shared_handle.replace(Some(instance));
+                    // The instance is not laid out yet, so wait before looking at geometries
+                    slint::Timer::single_shot(std::time::Duration::default(), || {
+                        apply_preview_data();
+                        element_selection::reselect_element();
+                    });
                 }),
             );
             reset_selections(ui);
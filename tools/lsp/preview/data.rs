@@ -0,0 +1,359 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The data set on the public properties of the previewed component: fixtures loaded from JSON files
+//! in the same format as `slint-viewer --load-data`, and placeholder rows for empty models.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::object_tree::PropertyVisibility;
+use i_slint_core::model::{Model, ModelRc, VecModel};
+use lsp_types::Url;
+use slint_interpreter::{Brush, Color, ComponentInstance, Value};
+
+/// The number of rows put into empty models
+const PLACEHOLDER_ROWS: usize = 5;
+
+const PLACEHOLDER_COLORS: [u32; 5] = [0xff3b82f6, 0xffef4444, 0xff22c55e, 0xffeab308, 0xffa855f7];
+
+/// The fixture with the data for `component` of the file at `url`: `<component>.preview.json` in the
+/// same directory.
+pub fn fixture_path(url: &Url, component: &str) -> Option<PathBuf> {
+    let path = url.to_file_path().ok()?;
+    Some(path.parent()?.join(format!("{component}.preview.json")))
+}
+
+/// Load the fixture file. A missing file is not an error.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_fixture(path: &std::path::Path) -> Result<Option<serde_json::Value>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|e| format!("{}: {e}", path.to_string_lossy())),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("{}: {e}", path.to_string_lossy())),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn load_fixture(_path: &std::path::Path) -> Result<Option<serde_json::Value>, String> {
+    Ok(None)
+}
+
+/// The public properties that can be set from outside the component
+fn settable_properties(instance: &ComponentInstance) -> Vec<(String, Type)> {
+    let component = instance.definition().root_component();
+    let root_element = component.root_element.borrow();
+    root_element
+        .property_declarations
+        .iter()
+        .filter(|(_, decl)| {
+            decl.expose_in_public_api
+                && decl.property_type.is_property_type()
+                && !matches!(
+                    decl.visibility,
+                    PropertyVisibility::Output | PropertyVisibility::Private
+                )
+        })
+        .map(|(name, decl)| (name.clone(), decl.property_type.clone()))
+        .collect()
+}
+
+/// Set the properties from a JSON object, returning a message for each value that could not be set
+pub fn set_properties(instance: &ComponentInstance, data: &serde_json::Value) -> Vec<String> {
+    let Some(data) = data.as_object() else {
+        return vec!["The data is not a JSON object".into()];
+    };
+
+    let properties = settable_properties(instance).into_iter().collect::<HashMap<_, _>>();
+    let mut errors = Vec::new();
+    for (name, value) in data {
+        let name = name.replace('_', "-");
+        let Some(ty) = properties.get(&name) else {
+            errors.push(format!("Unknown property '{name}'"));
+            continue;
+        };
+        if let Err(e) = instance.set_property(&name, from_json(ty, value)) {
+            errors.push(format!("Cannot set property '{name}': {e}"));
+        }
+    }
+    errors
+}
+
+/// Put placeholder rows into all the models of the component that are empty
+pub fn fill_empty_models(instance: &ComponentInstance) {
+    for (name, ty) in settable_properties(instance) {
+        if !matches!(ty, Type::Array(_)) {
+            continue;
+        }
+        let is_empty = match instance.get_property(&name) {
+            Ok(Value::Model(model)) => model.row_count() == 0,
+            _ => false,
+        };
+        if !is_empty {
+            continue;
+        }
+        if let Some(value) = placeholder(&ty, &name, 0) {
+            // The property could be bound to something that cannot be overwritten
+            let _ = instance.set_property(&name, value);
+        }
+    }
+}
+
+/// The current values of the properties that can be set, as a JSON object
+pub fn current_data(instance: &ComponentInstance) -> serde_json::Value {
+    let mut result = serde_json::Map::new();
+    for (name, _) in settable_properties(instance) {
+        if let Some(value) = instance.get_property(&name).ok().as_ref().and_then(to_json) {
+            result.insert(name, value);
+        }
+    }
+    result.into()
+}
+
+fn from_json(ty: &Type, value: &serde_json::Value) -> Value {
+    match (ty, value) {
+        (Type::Bool, serde_json::Value::Bool(b)) => Value::Bool(*b),
+        (_, serde_json::Value::Number(n)) => Value::Number(n.as_f64().unwrap_or(f64::NAN)),
+        (Type::String, serde_json::Value::String(s)) => Value::String(s.as_str().into()),
+        (Type::Color | Type::Brush, serde_json::Value::String(s)) => {
+            i_slint_compiler::literals::parse_color_literal(s)
+                .map(|c| Value::Brush(Brush::SolidColor(Color::from_argb_encoded(c))))
+                .unwrap_or_else(|| default_value(ty))
+        }
+        (Type::Enumeration(e), serde_json::Value::String(s)) if e.values.contains(s) => {
+            Value::EnumerationValue(e.name.clone(), s.clone())
+        }
+        (Type::Array(inner), serde_json::Value::Array(array)) => Value::Model(ModelRc::new(
+            VecModel::from(array.iter().map(|v| from_json(inner, v)).collect::<Vec<_>>()),
+        )),
+        (Type::Struct { fields, .. }, serde_json::Value::Object(object)) => fields
+            .iter()
+            .map(|(name, ty)| {
+                let value = object
+                    .get(name)
+                    .or_else(|| object.get(&name.replace('-', "_")))
+                    .map_or_else(|| default_value(ty), |v| from_json(ty, v));
+                (name.clone(), value)
+            })
+            .collect::<slint_interpreter::Struct>()
+            .into(),
+        _ => default_value(ty),
+    }
+}
+
+fn to_json(value: &Value) -> Option<serde_json::Value> {
+    match value {
+        Value::Number(x) => Some((*x).into()),
+        Value::String(x) => Some(x.as_str().into()),
+        Value::Bool(x) => Some((*x).into()),
+        Value::Brush(Brush::SolidColor(c)) => Some(
+            format!("#{:02x}{:02x}{:02x}{:02x}", c.red(), c.green(), c.blue(), c.alpha()).into(),
+        ),
+        Value::Model(model) => {
+            model.iter().map(|v| to_json(&v)).collect::<Option<Vec<_>>>().map(Into::into)
+        }
+        Value::Struct(st) => {
+            let mut object = serde_json::Map::new();
+            for (k, v) in st.iter() {
+                object.insert(k.into(), to_json(v)?);
+            }
+            Some(object.into())
+        }
+        Value::EnumerationValue(_, value) => Some(value.as_str().into()),
+        _ => None,
+    }
+}
+
+/// The value of a field that is not in the JSON data
+fn default_value(ty: &Type) -> Value {
+    match ty {
+        Type::Float32
+        | Type::Int32
+        | Type::Duration
+        | Type::Angle
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Percent => Value::Number(0.),
+        Type::String => Value::String(Default::default()),
+        Type::Bool => Value::Bool(false),
+        Type::Color | Type::Brush => Value::Brush(Default::default()),
+        Type::Image => Value::Image(Default::default()),
+        Type::Array(_) => Value::Model(Default::default()),
+        Type::Struct { fields, .. } => fields
+            .iter()
+            .map(|(name, ty)| (name.clone(), default_value(ty)))
+            .collect::<slint_interpreter::Struct>()
+            .into(),
+        Type::Enumeration(e) => Value::EnumerationValue(
+            e.name.clone(),
+            e.values.get(e.default_value).cloned().unwrap_or_default(),
+        ),
+        _ => Value::Void,
+    }
+}
+
+/// Placeholder data for the row `index` of a model: numbers count up and strings are made of
+/// the `name` of the property or field and the row number.
+fn placeholder(ty: &Type, name: &str, index: usize) -> Option<Value> {
+    Some(match ty {
+        Type::Float32
+        | Type::Int32
+        | Type::Duration
+        | Type::Angle
+        | Type::PhysicalLength
+        | Type::LogicalLength
+        | Type::Rem
+        | Type::Percent => Value::Number((index + 1) as f64),
+        Type::String => Value::String(format!("{name} {}", index + 1).into()),
+        Type::Bool => Value::Bool(index % 2 == 0),
+        Type::Color | Type::Brush => Value::Brush(Brush::SolidColor(Color::from_argb_encoded(
+            PLACEHOLDER_COLORS[index % PLACEHOLDER_COLORS.len()],
+        ))),
+        Type::Image => Value::Image(Default::default()),
+        Type::Enumeration(e) if !e.values.is_empty() => {
+            Value::EnumerationValue(e.name.clone(), e.values[index % e.values.len()].clone())
+        }
+        Type::Array(inner) => Value::Model(ModelRc::new(VecModel::from(
+            (0..PLACEHOLDER_ROWS)
+                .map(|row| placeholder(inner, name, row))
+                .collect::<Option<Vec<_>>>()?,
+        ))),
+        Type::Struct { fields, .. } => fields
+            .iter()
+            .map(|(field, ty)| Some((field.clone(), placeholder(ty, field, index)?)))
+            .collect::<Option<slint_interpreter::Struct>>()?
+            .into(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::rc::Rc;
+
+    fn struct_type() -> Type {
+        Type::Struct {
+            fields: BTreeMap::from([
+                ("first-name".to_string(), Type::String),
+                ("age".to_string(), Type::Int32),
+                ("favorite".to_string(), Type::Color),
+            ]),
+            name: None,
+            node: None,
+            rust_attributes: None,
+        }
+    }
+
+    fn enum_type() -> Type {
+        Type::Enumeration(Rc::new(i_slint_compiler::langtype::Enumeration {
+            name: "Mode".into(),
+            values: vec!["light".into(), "dark".into()],
+            default_value: 1,
+            node: None,
+        }))
+    }
+
+    #[test]
+    fn test_from_json() {
+        assert_eq!(from_json(&Type::Bool, &serde_json::json!(true)), Value::Bool(true));
+        assert_eq!(from_json(&Type::LogicalLength, &serde_json::json!(12.5)), Value::Number(12.5));
+        assert_eq!(
+            from_json(&Type::String, &serde_json::json!("hello")),
+            Value::String("hello".into())
+        );
+        assert_eq!(
+            from_json(&Type::Brush, &serde_json::json!("#ff0000")),
+            Value::Brush(Brush::SolidColor(Color::from_rgb_u8(0xff, 0, 0)))
+        );
+        assert_eq!(
+            from_json(&enum_type(), &serde_json::json!("light")),
+            Value::EnumerationValue("Mode".into(), "light".into())
+        );
+        // Invalid values result in the default value
+        assert_eq!(
+            from_json(&enum_type(), &serde_json::json!("blue")),
+            Value::EnumerationValue("Mode".into(), "dark".into())
+        );
+        assert_eq!(from_json(&Type::Bool, &serde_json::json!("yes")), Value::Bool(false));
+        assert_eq!(
+            from_json(&Type::Color, &serde_json::json!("red")),
+            Value::Brush(Brush::default())
+        );
+
+        // Fields can be written with underscores, missing fields get the default value
+        let value = from_json(
+            &Type::Array(Box::new(struct_type())),
+            &serde_json::json!([{ "first_name": "Ann", "age": 42 }]),
+        );
+        let Value::Model(model) = value else { panic!("not a model: {value:?}") };
+        assert_eq!(model.row_count(), 1);
+        let Value::Struct(row) = model.row_data(0).unwrap() else { panic!("not a struct") };
+        assert_eq!(row.get_field("first-name"), Some(&Value::String("Ann".into())));
+        assert_eq!(row.get_field("age"), Some(&Value::Number(42.)));
+        assert_eq!(row.get_field("favorite"), Some(&Value::Brush(Brush::default())));
+    }
+
+    #[test]
+    fn test_to_json() {
+        assert_eq!(to_json(&Value::Number(3.)), Some(serde_json::json!(3.)));
+        assert_eq!(to_json(&Value::Bool(true)), Some(serde_json::json!(true)));
+        assert_eq!(to_json(&Value::String("a".into())), Some(serde_json::json!("a")));
+        assert_eq!(
+            to_json(&Value::Brush(Brush::SolidColor(Color::from_argb_u8(0x80, 0x11, 0x22, 0x33)))),
+            Some(serde_json::json!("#11223380"))
+        );
+        assert_eq!(to_json(&Value::Image(Default::default())), None);
+
+        // Round trip through from_json
+        let data = serde_json::json!([
+            { "first-name": "Ann", "age": 42.0, "favorite": "#ff0000ff" },
+            { "first-name": "Bob", "age": 7.0, "favorite": "#00ff00ff" },
+        ]);
+        let ty = Type::Array(Box::new(struct_type()));
+        assert_eq!(to_json(&from_json(&ty, &data)), Some(data));
+        assert_eq!(
+            to_json(&from_json(&enum_type(), &serde_json::json!("light"))),
+            Some(serde_json::json!("light"))
+        );
+    }
+
+    #[test]
+    fn test_placeholder() {
+        let value = placeholder(&Type::Array(Box::new(struct_type())), "people", 0).unwrap();
+        let Value::Model(model) = value else { panic!("not a model: {value:?}") };
+        assert_eq!(model.row_count(), PLACEHOLDER_ROWS);
+        let Value::Struct(row) = model.row_data(1).unwrap() else { panic!("not a struct") };
+        assert_eq!(row.get_field("first-name"), Some(&Value::String("first-name 2".into())));
+        assert_eq!(row.get_field("age"), Some(&Value::Number(2.)));
+        assert_eq!(
+            row.get_field("favorite"),
+            Some(&Value::Brush(Brush::SolidColor(Color::from_argb_encoded(PLACEHOLDER_COLORS[1]))))
+        );
+
+        assert_eq!(
+            placeholder(&Type::Array(Box::new(Type::String)), "names", 0)
+                .as_ref()
+                .and_then(to_json),
+            Some(serde_json::json!(["names 1", "names 2", "names 3", "names 4", "names 5"]))
+        );
+        assert_eq!(
+            placeholder(&enum_type(), "mode", 3),
+            Some(Value::EnumerationValue("Mode".into(), "dark".into()))
+        );
+        // Models of types without placeholder are left empty
+        assert_eq!(
+            placeholder(
+                &Type::Array(Box::new(Type::Callback { return_type: None, args: vec![] })),
+                "callbacks",
+                0
+            ),
+            None
+        );
+    }
+}
@@ -1,7 +1,10 @@
 // Copyright © SixtyFPS GmbH <info@slint.dev>
 // SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
 
-use std::{path::PathBuf, rc::Rc};
+use std::{
+    path::{Path, PathBuf},
+    rc::Rc,
+};
 
 use i_slint_compiler::{
     diagnostics::{SourceFile, Spanned},
@@ -57,7 +60,11 @@ pub fn unselect_element() {
     super::set_selected_element(None, ComponentPositions::default());
 }
 
-fn select_element(component_instance: &ComponentInstance, selected_element: &ElementRc) {
+/// Mark the element as selected, without moving the editor to it
+fn mark_element_selected(
+    component_instance: &ComponentInstance,
+    selected_element: &ElementRc,
+) -> bool {
     let secondary_positions = if let Some((path, offset)) = element_offset(selected_element) {
         component_instance.component_positions(path, offset)
     } else {
@@ -65,16 +72,54 @@ fn select_element(component_instance: &ComponentInstance, selected_element: &Ele
     };
 
     let Some(position) = secondary_positions.geometries.get(0).cloned() else {
-        return;
+        return false;
     };
 
     super::set_selected_element(Some((&selected_element, position)), secondary_positions);
+    true
+}
+
+fn select_element(component_instance: &ComponentInstance, selected_element: &ElementRc) {
+    if !mark_element_selected(component_instance, selected_element) {
+        return;
+    }
     if let Some(document_position) = lsp_element_position(&selected_element) {
         super::ask_editor_to_show_document(&document_position.0, document_position.1);
     }
 }
 
-fn element_offset(element: &ElementRc) -> Option<(PathBuf, u32)> {
+/// Select the previously selected element in the reloaded preview
+pub fn reselect_element() {
+    let Some((path, offset)) = super::selected_element_location() else {
+        return;
+    };
+    let Some(component_instance) = super::component_instance() else {
+        return;
+    };
+
+    let element = find_element_at(&root_element(&component_instance), &path, offset);
+    if !element.map_or(false, |e| mark_element_selected(&component_instance, &e)) {
+        unselect_element();
+    }
+}
+
+fn find_element_at(element: &ElementRc, path: &Path, offset: u32) -> Option<ElementRc> {
+    if element_offset(element).map_or(false, |(p, o)| p == path && o == offset) {
+        return Some(element.clone());
+    }
+
+    let base_type = element.borrow().base_type.clone();
+    if let i_slint_compiler::langtype::ElementType::Component(c) = base_type {
+        if let Some(e) = find_element_at(&c.root_element, path, offset) {
+            return Some(e);
+        }
+    }
+
+    let children = element.borrow().children.clone();
+    children.iter().find_map(|c| find_element_at(c, path, offset))
+}
+
+pub(super) fn element_offset(element: &ElementRc) -> Option<(PathBuf, u32)> {
     let Some(node) = element.borrow().node.first().cloned() else {
         return None;
     };
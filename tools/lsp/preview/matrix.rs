@@ -0,0 +1,180 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The preview matrix: the previewed component shown side by side in each configured device
+//! size and style.
+
+use std::rc::Rc;
+
+use i_slint_core::component_factory::FactoryContext;
+use i_slint_core::model::VecModel;
+use slint_interpreter::{ComponentDefinition, ComponentHandle};
+
+use crate::common::{PreviewComponent, PreviewConfig, PreviewDevice};
+
+use super::ui::MatrixCell;
+use super::PREVIEW_STATE;
+
+/// The devices shown when none are configured
+fn default_devices() -> Vec<PreviewDevice> {
+    [("Phone", 1080, 2340, 3.), ("Tablet", 1600, 2560, 2.), ("Desktop", 1920, 1080, 1.)]
+        .into_iter()
+        .map(|(name, width, height, scale_factor)| PreviewDevice {
+            name: name.into(),
+            width,
+            height,
+            scale_factor,
+        })
+        .collect()
+}
+
+/// Fill the matrix with one cell per device and style, or empty it when it is not shown.
+/// `compiled` is the component compiled with `current_style` for the main preview: only the
+/// other styles of the matrix are compiled again.
+///
+/// This must be run in the UI thread.
+pub async fn update_matrix(
+    component: &PreviewComponent,
+    current_style: &str,
+    compiled: Option<ComponentDefinition>,
+    config: &PreviewConfig,
+) {
+    let Some(ui) = PREVIEW_STATE.with(|ps| ps.borrow().ui.as_ref().map(|ui| ui.clone_strong()))
+    else {
+        return;
+    };
+
+    if !ui.get_matrix_mode() {
+        ui.set_matrix_cells(Rc::new(VecModel::<MatrixCell>::default()).into());
+        return;
+    }
+
+    let devices =
+        if config.devices.is_empty() { default_devices() } else { config.devices.clone() };
+
+    let mut cells = Vec::new();
+    for style in matrix_styles(current_style, config) {
+        let compiled = if style == current_style {
+            compiled.clone()
+        } else {
+            super::compile(component, &style, config).await.0
+        };
+
+        for device in &devices {
+            let (width, height) = logical_size(device);
+            cells.push(MatrixCell {
+                title: cell_title(device, &style, compiled.is_some()).into(),
+                width,
+                height,
+                preview_area: compiled.clone().map(create_factory).unwrap_or_default(),
+            });
+        }
+    }
+
+    ui.set_matrix_cells(Rc::new(VecModel::from(cells)).into());
+}
+
+/// The styles of the matrix: the configured ones, or the current style
+fn matrix_styles(current_style: &str, config: &PreviewConfig) -> Vec<String> {
+    if config.matrix_styles.is_empty() {
+        vec![current_style.to_string()]
+    } else {
+        config.matrix_styles.clone()
+    }
+}
+
+/// The size of the device screen in logical pixels, which is the size of its cell.
+///
+/// The scale factor of a window applies to all the cells, so they are rendered with the scale
+/// factor of the preview window: the scale factor of the device only gives its logical size.
+fn logical_size(device: &PreviewDevice) -> (f32, f32) {
+    let scale_factor = if device.scale_factor > 0. { device.scale_factor } else { 1. };
+    (device.width as f32 / scale_factor, device.height as f32 / scale_factor)
+}
+
+fn cell_title(device: &PreviewDevice, style: &str, compiled: bool) -> String {
+    let (width, height) = logical_size(device);
+    let style = if style.is_empty() { "default" } else { style };
+    let mut title = format!("{} — {}×{}, {style}", device.name, width.round(), height.round());
+    if !compiled {
+        title.push_str(" (failed to compile)");
+    }
+    title
+}
+
+fn create_factory(compiled: ComponentDefinition) -> slint::ComponentFactory {
+    slint::ComponentFactory::new(move |ctx: FactoryContext| {
+        let instance = compiled.create_embedded(ctx).ok()?;
+
+        // The factory is called while the UI is being updated: Set the data once that is done
+        let weak = instance.as_weak();
+        slint::Timer::single_shot(std::time::Duration::default(), move || {
+            let Some(instance) = weak.upgrade() else {
+                return;
+            };
+            let Some(ui) =
+                PREVIEW_STATE.with(|ps| ps.borrow().ui.as_ref().map(|ui| ui.clone_strong()))
+            else {
+                return;
+            };
+            // Errors are shown in the data editor of the main preview already
+            let _ = super::set_preview_data(&instance, &ui);
+        });
+
+        Some(instance)
+    })
+}
+
+/// Rebuild the matrix after it was toggled in the UI
+pub fn matrix_mode_changed() {
+    let Some(ui) = PREVIEW_STATE.with(|ps| ps.borrow().ui.as_ref().map(|ui| ui.clone_strong()))
+    else {
+        return;
+    };
+    if ui.get_matrix_mode() {
+        super::reload_current_preview();
+    } else {
+        ui.set_matrix_cells(Rc::new(VecModel::<MatrixCell>::default()).into());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(width: u32, height: u32, scale_factor: f32) -> PreviewDevice {
+        PreviewDevice { name: "Phone".into(), width, height, scale_factor }
+    }
+
+    #[test]
+    fn test_logical_size() {
+        assert_eq!(logical_size(&device(1080, 2340, 3.)), (360., 780.));
+        assert_eq!(logical_size(&device(1920, 1080, 1.)), (1920., 1080.));
+        // Invalid scale factors are ignored
+        assert_eq!(logical_size(&device(800, 600, 0.)), (800., 600.));
+        assert_eq!(logical_size(&device(800, 600, -2.)), (800., 600.));
+
+        for d in default_devices() {
+            let (width, height) = logical_size(&d);
+            assert!(width > 0. && height > 0., "{d:?}");
+        }
+    }
+
+    #[test]
+    fn test_cell_title() {
+        assert_eq!(cell_title(&device(1080, 2340, 3.), "fluent", true), "Phone — 360×780, fluent");
+        assert_eq!(
+            cell_title(&device(1000, 500, 1.5), "", false),
+            "Phone — 667×333, default (failed to compile)"
+        );
+    }
+
+    #[test]
+    fn test_matrix_styles() {
+        let mut config = PreviewConfig::default();
+        assert_eq!(matrix_styles("fluent", &config), vec!["fluent".to_string()]);
+        assert_eq!(matrix_styles("", &config), vec![String::new()]);
+        config.matrix_styles = vec!["material".into(), "cupertino".into()];
+        assert_eq!(matrix_styles("fluent", &config), config.matrix_styles);
+    }
+}
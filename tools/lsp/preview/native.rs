@@ -230,3 +230,10 @@ pub fn ask_editor_to_show_document(file: &str, selection: lsp_types::Range) {
     let fut = crate::send_show_document_to_editor(sender, url, selection);
     slint_interpreter::spawn_local(fut).unwrap(); // Fire and forget.
 }
+
+pub fn send_message_to_lsp(message: crate::common::PreviewToLspMessage) {
+    let Some(sender) = SERVER_NOTIFIER.get_or_init(Default::default).lock().unwrap().clone() else {
+        return;
+    };
+    sender.send_message_to_lsp(message);
+}
@@ -3,6 +3,9 @@
 
 use std::{collections::HashMap, iter::once, rc::Rc};
 
+use i_slint_compiler::langtype::Type;
+use i_slint_compiler::object_tree::ElementRc;
+use i_slint_compiler::parser::SyntaxKind;
 use slint::{Model, SharedString, VecModel};
 use slint_interpreter::{DiagnosticLevel, PlatformError};
 
@@ -46,6 +49,26 @@ pub fn create_ui(style: String) -> Result<PreviewUi, PlatformError> {
     ui.on_select_behind(super::element_selection::select_element_behind);
     ui.on_can_drop(super::can_drop_component);
     ui.on_drop(super::drop_component);
+    ui.on_set_binding(|name, expression| super::set_binding(&name, expression.into()));
+    ui.on_set_string_binding(|name, value| super::set_binding(&name, string_literal(&value)));
+    ui.on_set_color_binding(|name, value| super::set_binding(&name, color_literal(value)));
+    ui.on_set_gradient_binding(|name, start, end, angle| {
+        let expression = format!(
+            "@linear-gradient({angle}deg, {} 0%, {} 100%)",
+            color_literal(start),
+            color_literal(end)
+        );
+        super::set_binding(&name, expression)
+    });
+    ui.on_reset_binding(|name| super::remove_binding(&name));
+    ui.on_apply_preview_data(super::apply_preview_data_from_editor);
+    ui.on_save_preview_data(super::save_preview_data);
+    ui.on_reset_preview_data(super::reset_preview_data);
+    ui.on_placeholder_data_changed(super::reload_current_preview);
+    ui.on_matrix_mode_changed(super::matrix::matrix_mode_changed);
+    ui.set_layout_overlay(slint_interpreter::layout_overlay::layout_overlay_factory(
+        super::component_instance,
+    ));
 
     Ok(ui)
 }
@@ -99,3 +122,317 @@ pub fn ui_set_known_components(
     let result = Rc::new(VecModel::from(result));
     ui.set_known_components(result.into());
 }
+
+/// Show the properties of `element` in the property editor, or clear it
+pub fn ui_set_properties(ui: &PreviewUi, element: Option<&ElementRc>) {
+    let Some(element) = element else {
+        ui.set_selected_element(ElementInformation::default());
+        ui.set_properties(Rc::new(VecModel::<PropertyGroup>::default()).into());
+        return;
+    };
+
+    let (id, type_name) = {
+        let e = element.borrow();
+        let node = crate::common::filter_ignore_nodes_in_element(&e).next().cloned();
+        let id = node
+            .as_ref()
+            .and_then(|n| n.parent())
+            .filter(|p| p.kind() == SyntaxKind::SubElement)
+            .and_then(|p| p.child_node(SyntaxKind::DeclaredIdentifier))
+            .map(|n| n.text().to_string().trim().to_string())
+            .unwrap_or_default();
+        let type_name = node
+            .and_then(|n| n.child_node(SyntaxKind::QualifiedName))
+            .map(|n| n.text().to_string().trim().to_string())
+            .unwrap_or_else(|| e.base_type.to_string());
+        (id, type_name)
+    };
+
+    let mut groups: Vec<(String, Vec<PropertyInformation>)> = Vec::new();
+    for property in crate::language::properties::get_properties(element) {
+        let group =
+            if property.group.is_empty() { type_name.clone() } else { property.group.clone() };
+        let info = property_information(element, &property);
+        match groups.iter_mut().find(|(name, _)| *name == group) {
+            Some((_, properties)) => properties.push(info),
+            None => groups.push((group, vec![info])),
+        }
+    }
+
+    let groups = groups
+        .into_iter()
+        .map(|(name, properties)| PropertyGroup {
+            name: name.into(),
+            properties: Rc::new(VecModel::from(properties)).into(),
+        })
+        .collect::<Vec<_>>();
+
+    ui.set_selected_element(ElementInformation { id: id.into(), type_name: type_name.into() });
+    ui.set_properties(Rc::new(VecModel::from(groups)).into());
+}
+
+/// Prepare the data for the editor matching the type of the property.
+///
+/// Bindings the editor cannot represent fall back to editing the expression.
+fn property_information(
+    element: &ElementRc,
+    property: &crate::language::properties::PropertyInformation,
+) -> PropertyInformation {
+    let code = property
+        .defined_at
+        .as_ref()
+        .map(|d| d.expression_value.trim().to_string())
+        .unwrap_or_default();
+
+    let mut info = PropertyInformation {
+        name: property.name.clone().into(),
+        type_name: property.type_name.clone().into(),
+        kind: PropertyValueKind::Expression,
+        is_set: property.defined_at.is_some(),
+        code: code.clone().into(),
+        ..Default::default()
+    };
+
+    let ty = element.borrow().lookup_property(&property.name).property_type;
+    match &ty {
+        Type::Bool => {
+            let value = match code.as_str() {
+                "" | "false" => Some(false),
+                "true" => Some(true),
+                _ => None,
+            };
+            if let Some(value) = value {
+                info.kind = PropertyValueKind::Boolean;
+                info.bool_value = value;
+            }
+        }
+        Type::Int32 | Type::Float32 => {
+            if code.is_empty() || code.parse::<f64>().is_ok() {
+                info.kind = PropertyValueKind::Number;
+                info.number_value = code.into();
+            }
+        }
+        Type::LogicalLength
+        | Type::PhysicalLength
+        | Type::Rem
+        | Type::Angle
+        | Type::Duration
+        | Type::Percent => {
+            let mut units = match &ty {
+                Type::LogicalLength => vec!["px", "cm", "mm", "in", "pt", "phx", "rem"],
+                Type::PhysicalLength => vec!["phx", "px"],
+                Type::Rem => vec!["rem"],
+                Type::Angle => vec!["deg", "grad", "turn", "rad"],
+                Type::Duration => vec!["ms", "s"],
+                _ => vec!["%"],
+            };
+            if matches!(property.name.as_str(), "width" | "height") {
+                units.push("%");
+            }
+            if let Some((number, unit_index)) = split_unit(&code, &units) {
+                info.kind = PropertyValueKind::Quantity;
+                info.number_value = number.into();
+                info.unit_index = unit_index as i32;
+                info.units = Rc::new(VecModel::from(
+                    units.into_iter().map(SharedString::from).collect::<Vec<_>>(),
+                ))
+                .into();
+            }
+        }
+        Type::Color => {
+            if let Some(value) = color_value(&code) {
+                info.kind = PropertyValueKind::Color;
+                info.color_value = value;
+            }
+        }
+        Type::Brush => {
+            if let Some((angle, start, end)) = parse_linear_gradient(&code) {
+                info.kind = PropertyValueKind::Brush;
+                info.is_gradient = true;
+                info.gradient_angle = angle;
+                info.color_value = start;
+                info.gradient_end = end;
+            } else if let Some(value) = color_value(&code) {
+                info.kind = PropertyValueKind::Brush;
+                info.color_value = value;
+            }
+        }
+        Type::Enumeration(enumeration) => {
+            let index = if code.is_empty() {
+                Some(enumeration.default_value)
+            } else {
+                let value = code.rsplit('.').next().unwrap_or_default().trim();
+                enumeration.values.iter().position(|v| v == value)
+            };
+            if let Some(index) = index {
+                info.kind = PropertyValueKind::Enumeration;
+                info.enum_name = enumeration.name.clone().into();
+                info.enum_index = index as i32;
+                info.enum_values = Rc::new(VecModel::from(
+                    enumeration
+                        .values
+                        .iter()
+                        .map(|v| SharedString::from(v.as_str()))
+                        .collect::<Vec<_>>(),
+                ))
+                .into();
+            }
+        }
+        Type::String => {
+            let value = if code.is_empty() {
+                Some(String::new())
+            } else {
+                i_slint_compiler::literals::unescape_string(&code)
+            };
+            if let Some(value) = value {
+                info.kind = PropertyValueKind::String;
+                info.string_value = value.into();
+            }
+        }
+        _ => {}
+    }
+
+    info
+}
+
+/// Split `code` like `12.5px` into the number and the index of its unit in `units`.
+///
+/// An empty `code` results in an empty number with the first unit.
+fn split_unit(code: &str, units: &[&str]) -> Option<(String, usize)> {
+    if code.is_empty() {
+        return Some((String::new(), 0));
+    }
+    let unit_start =
+        code.find(|c: char| !c.is_ascii_digit() && c != '.' && c != '-').unwrap_or(code.len());
+    let (number, unit) = code.split_at(unit_start);
+    let value = number.parse::<f64>().ok()?;
+    let unit_index = if unit.is_empty() && value == 0.0 {
+        // `0` is a valid value for all units
+        0
+    } else {
+        units.iter().position(|u| *u == unit.trim())?
+    };
+    Some((number.to_string(), unit_index))
+}
+
+fn parse_color(code: &str) -> Option<slint::Color> {
+    i_slint_compiler::literals::parse_color_literal(code).map(slint::Color::from_argb_encoded)
+}
+
+/// The color of a binding that is a color literal, or the default color if there is no binding
+fn color_value(code: &str) -> Option<slint::Color> {
+    if code.is_empty() {
+        Some(Default::default())
+    } else {
+        parse_color(code)
+    }
+}
+
+/// Parse a `@linear-gradient` into its angle, start and end color.
+///
+/// Only the gradients the editor writes back unchanged are accepted: an angle in whole degrees
+/// and two literal colors at 0% and 100%.
+fn parse_linear_gradient(code: &str) -> Option<(i32, slint::Color, slint::Color)> {
+    let arguments = code.strip_prefix("@linear-gradient(")?.strip_suffix(')')?;
+    let arguments = arguments.split(',').map(str::trim).collect::<Vec<_>>();
+    let [angle, start, end] = arguments.as_slice() else {
+        return None;
+    };
+    let angle = angle.strip_suffix("deg")?.trim().parse::<i32>().ok()?;
+    let stop = |stop: &str, position: &str| {
+        let mut parts = stop.split_whitespace();
+        let color = parse_color(parts.next()?)?;
+        match (parts.next(), parts.next()) {
+            (None, None) => Some(color),
+            (Some(p), None) if p == position => Some(color),
+            _ => None,
+        }
+    };
+    Some((angle, stop(start, "0%")?, stop(end, "100%")?))
+}
+
+fn color_literal(color: slint::Color) -> String {
+    if color.alpha() == 0xff {
+        format!("#{:02x}{:02x}{:02x}", color.red(), color.green(), color.blue())
+    } else {
+        format!(
+            "#{:02x}{:02x}{:02x}{:02x}",
+            color.red(),
+            color.green(),
+            color.blue(),
+            color.alpha()
+        )
+    }
+}
+
+fn string_literal(value: &str) -> String {
+    let mut result = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '{' => result.push_str("\\{"),
+            '\n' => result.push_str("\\n"),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_unit() {
+        let units = ["px", "cm", "%"];
+        assert_eq!(split_unit("", &units), Some((String::new(), 0)));
+        assert_eq!(split_unit("12.5px", &units), Some(("12.5".into(), 0)));
+        assert_eq!(split_unit("-3cm", &units), Some(("-3".into(), 1)));
+        assert_eq!(split_unit("50 %", &units), Some(("50".into(), 2)));
+        assert_eq!(split_unit("0", &units), Some(("0".into(), 0)));
+        assert_eq!(split_unit("10", &units), None);
+        assert_eq!(split_unit("10deg", &units), None);
+        assert_eq!(split_unit("root.width", &units), None);
+        assert_eq!(split_unit("10px * 2", &units), None);
+    }
+
+    #[test]
+    fn test_parse_linear_gradient() {
+        let red = slint::Color::from_rgb_u8(0xff, 0, 0);
+        let blue = slint::Color::from_rgb_u8(0, 0, 0xff);
+        assert_eq!(
+            parse_linear_gradient("@linear-gradient(90deg, #ff0000 0%, #0000ff 100%)"),
+            Some((90, red, blue))
+        );
+        assert_eq!(
+            parse_linear_gradient("@linear-gradient(45deg, #f00, #00f)"),
+            Some((45, red, blue))
+        );
+        // More stops, stops at other positions, or fractional angles can't be edited without loss
+        assert_eq!(
+            parse_linear_gradient("@linear-gradient(90deg, #f00 0%, #0f0 50%, #00f 100%)"),
+            None
+        );
+        assert_eq!(parse_linear_gradient("@linear-gradient(90deg, #f00 10%, #00f 100%)"), None);
+        assert_eq!(parse_linear_gradient("@linear-gradient(22.5deg, #f00 0%, #00f 100%)"), None);
+        assert_eq!(parse_linear_gradient("@linear-gradient(90deg, red 0%, #00f 100%)"), None);
+        assert_eq!(parse_linear_gradient("@radial-gradient(circle, #f00 0%, #00f 100%)"), None);
+        assert_eq!(parse_linear_gradient("#ff0000"), None);
+    }
+
+    #[test]
+    fn test_string_literal() {
+        assert_eq!(string_literal(""), r#""""#);
+        assert_eq!(string_literal("Hello"), r#""Hello""#);
+        assert_eq!(string_literal(r#"a "b" c\d"#), r#""a \"b\" c\\d""#);
+        assert_eq!(string_literal("{x}\nnext"), r#""\{x}\nnext""#);
+        for value in ["", "Hello", r#"a "b" c\d"#, "{x}\nnext"] {
+            assert_eq!(
+                i_slint_compiler::literals::unescape_string(&string_literal(value)).as_deref(),
+                Some(value)
+            );
+        }
+    }
+}
@@ -217,7 +217,10 @@ fn lookup_expression_context(mut n: SyntaxNode) -> Option<ExpressionContextInfo>
         match n.kind() {
             SyntaxKind::Binding | SyntaxKind::TwoWayBinding | SyntaxKind::CallbackConnection => {
                 let parent = n.parent()?;
-                if parent.kind() == SyntaxKind::PropertyAnimation {
+                if parent.kind() == SyntaxKind::PropertyDeclaration {
+                    // `property <int> foo <=> bar;`: the binding has no name of its own
+                    n = parent;
+                } else if parent.kind() == SyntaxKind::PropertyAnimation {
                     let prop_name = i_slint_compiler::parser::identifier_text(&n)?;
                     let element = syntax_nodes::Element::new(parent.parent()?)?;
                     break (element, prop_name, true);
@@ -250,13 +253,25 @@ fn lookup_expression_context(mut n: SyntaxNode) -> Option<ExpressionContextInfo>
 }
 
 pub fn to_lsp_diag(d: &i_slint_compiler::diagnostics::Diagnostic) -> lsp_types::Diagnostic {
+    let related_information = d
+        .related_locations()
+        .filter_map(|(message, path, line_column)| {
+            Some(lsp_types::DiagnosticRelatedInformation {
+                location: lsp_types::Location::new(
+                    lsp_types::Url::from_file_path(path?).ok()?,
+                    to_range(line_column),
+                ),
+                message: message.to_owned(),
+            })
+        })
+        .collect::<Vec<_>>();
     lsp_types::Diagnostic::new(
         to_range(d.line_column()),
         Some(to_lsp_diag_level(d.level())),
         None,
         None,
         d.message().to_owned(),
-        None,
+        (!related_information.is_empty()).then_some(related_information),
         None,
     )
 }
@@ -262,6 +262,28 @@ pub async fn process_preview_to_lsp_message(
             M::RequestState { .. } => {
                 crate::language::request_state(&self.ctx);
             }
+            M::SetBinding { url, element_range, property, expression } => {
+                crate::language::update_binding_from_preview(
+                    &self.ctx,
+                    url,
+                    element_range,
+                    property,
+                    Some(expression),
+                )
+                .await
+                .map_err(|e| JsValue::from(e.to_string()))?;
+            }
+            M::RemoveBinding { url, element_range, property } => {
+                crate::language::update_binding_from_preview(
+                    &self.ctx,
+                    url,
+                    element_range,
+                    property,
+                    None,
+                )
+                .await
+                .map_err(|e| JsValue::from(e.to_string()))?;
+            }
         }
         Ok(())
     }
@@ -0,0 +1,276 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The `--inspect` mode: shows the element tree of an application that was built with the
+//! `inspector` feature of Slint, and changes the properties of its elements live.
+
+use i_slint_core::model::{ModelRc, VecModel};
+use serde_json::json;
+use slint_interpreter::{ComponentCompiler, ComponentHandle, ComponentInstance, Value};
+use std::cell::RefCell;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::rc::Rc;
+use std::time::Duration;
+
+/// How long to wait for the inspected application to answer
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+struct Connection {
+    reader: BufReader<TcpStream>,
+    writer: TcpStream,
+}
+
+impl Connection {
+    fn open(address: &str, token: &str) -> Result<Self, String> {
+        let connect = || -> std::io::Result<Self> {
+            let writer = TcpStream::connect(address)?;
+            writer.set_read_timeout(Some(TIMEOUT))?;
+            Ok(Self { reader: BufReader::new(writer.try_clone()?), writer })
+        };
+        let mut connection = connect().map_err(|err| err.to_string())?;
+        connection.request(json!({ "request": "authenticate", "token": token }))?;
+        Ok(connection)
+    }
+
+    /// Send a request and wait for its response
+    fn request(&mut self, request: serde_json::Value) -> Result<serde_json::Value, String> {
+        writeln!(self.writer, "{request}").map_err(|err| err.to_string())?;
+        let mut line = String::new();
+        match self.reader.read_line(&mut line) {
+            Ok(0) => return Err("The application was closed".into()),
+            Ok(_) => {}
+            Err(err) => return Err(err.to_string()),
+        }
+        let response: serde_json::Value =
+            serde_json::from_str(&line).map_err(|err| err.to_string())?;
+        match response.get("error").and_then(|e| e.as_str()) {
+            Some(err) => Err(err.into()),
+            None => Ok(response),
+        }
+    }
+}
+
+/// An element of the flattened tree
+struct Element {
+    handle: String,
+    window: usize,
+    depth: usize,
+    label: String,
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+struct State {
+    connection: Connection,
+    elements: Vec<Element>,
+    /// The handle of the selected element
+    selected: Option<String>,
+}
+
+/// Connect to the application at `address` with the token of its session, and show the inspector until its window is closed
+pub fn run(address: &str, token: &str, style: Option<&str>) -> crate::Result<()> {
+    let connection = Connection::open(address, token)
+        .map_err(|err| format!("Cannot connect to the inspector at {address}: {err}"))?;
+
+    let mut compiler = ComponentCompiler::default();
+    if let Some(style) = style {
+        compiler.set_style(style.into());
+    }
+    let definition = spin_on::spin_on(
+        compiler
+            .build_from_source(include_str!("inspector.slint").into(), "inspector.slint".into()),
+    );
+    slint_interpreter::print_diagnostics(compiler.diagnostics());
+    let instance = definition.ok_or("Cannot compile the inspector")?.create()?;
+    instance.set_property("address", Value::String(address.into()))?;
+
+    let state = Rc::new(RefCell::new(State { connection, elements: Vec::new(), selected: None }));
+
+    let callback = |f: fn(&ComponentInstance, &mut State, &[Value])| {
+        let weak = instance.as_weak();
+        let state = state.clone();
+        move |args: &[Value]| {
+            if let Some(instance) = weak.upgrade() {
+                f(&instance, &mut state.borrow_mut(), args);
+            }
+            Value::Void
+        }
+    };
+    instance.set_callback("refresh", callback(|instance, state, _| refresh(instance, state)))?;
+    instance.set_callback(
+        "select",
+        callback(|instance, state, args| {
+            if let [Value::Number(index)] = args {
+                let handle = state.elements.get(*index as usize).map(|e| e.handle.clone());
+                state.selected = handle;
+                show_selection(instance, state);
+            }
+        }),
+    )?;
+    instance.set_callback(
+        "set-property",
+        callback(|instance, state, args| {
+            let [Value::String(name), Value::String(value)] = args else {
+                return;
+            };
+            let Some(handle) = state.selected.clone() else {
+                return;
+            };
+            let request = json!({
+                "request": "set-property",
+                "handle": handle,
+                "name": name.as_str(),
+                "value": value.as_str(),
+            });
+            match state.connection.request(request) {
+                // The geometry of the elements may have changed too
+                Ok(_) => refresh(instance, state),
+                Err(err) => set_status(instance, &format!("Cannot set {name}: {err}")),
+            }
+        }),
+    )?;
+
+    refresh(&instance, &mut state.borrow_mut());
+    instance.run()?;
+    Ok(())
+}
+
+fn set_status(instance: &ComponentInstance, status: &str) {
+    instance.set_property("status", Value::String(status.into())).unwrap();
+}
+
+/// Load the tree again, and the properties of the selected element
+fn refresh(instance: &ComponentInstance, state: &mut State) {
+    let windows = match state.connection.request(json!({ "request": "tree" })) {
+        Ok(response) => response.get("windows").and_then(|w| w.as_array()).cloned(),
+        Err(err) => {
+            set_status(instance, &format!("Cannot load the element tree: {err}"));
+            return;
+        }
+    };
+    let windows = windows.unwrap_or_default();
+
+    state.elements.clear();
+    for (window, root) in windows.iter().enumerate() {
+        flatten(root, window, 0, &mut state.elements);
+    }
+    set_status(
+        instance,
+        &format!("{} elements in {} windows", state.elements.len(), windows.len()),
+    );
+
+    let elements = state.elements.iter().map(element_value).collect::<Vec<_>>();
+    instance
+        .set_property("elements", Value::Model(ModelRc::new(VecModel::from(elements))))
+        .unwrap();
+    show_selection(instance, state);
+}
+
+fn flatten(node: &serde_json::Value, window: usize, depth: usize, result: &mut Vec<Element>) {
+    let text = |key: &str| node.get(key).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+    let number = |key: &str| node.get(key).and_then(|v| v.as_f64()).unwrap_or_default();
+
+    let (id, type_name) = (text("id"), text("type"));
+    let type_name = if type_name.is_empty() { "?".to_string() } else { type_name };
+    result.push(Element {
+        handle: text("handle"),
+        window,
+        depth,
+        label: if id.is_empty() { type_name } else { format!("{id} := {type_name}") },
+        x: number("x"),
+        y: number("y"),
+        width: number("width"),
+        height: number("height"),
+    });
+
+    for child in node.get("children").and_then(|c| c.as_array()).into_iter().flatten() {
+        flatten(child, window, depth + 1, result);
+    }
+}
+
+/// Highlight the selected element, and show its properties
+fn show_selection(instance: &ComponentInstance, state: &mut State) {
+    let index = state
+        .selected
+        .as_ref()
+        .and_then(|handle| state.elements.iter().position(|e| &e.handle == handle));
+    instance
+        .set_property("selected", Value::Number(index.map_or(-1., |index| index as f64)))
+        .unwrap();
+
+    let Some(index) = index else {
+        state.selected = None;
+        set_properties(instance, Vec::new());
+        return;
+    };
+
+    let window = state.elements[index].window;
+    if let Some(root) = state.elements.iter().find(|e| e.window == window && e.depth == 0) {
+        instance.set_property("window-width", Value::Number(root.width)).unwrap();
+        instance.set_property("window-height", Value::Number(root.height)).unwrap();
+    }
+
+    let request = json!({ "request": "element", "handle": state.elements[index].handle });
+    match state.connection.request(request) {
+        Ok(response) => {
+            let properties = response
+                .get("properties")
+                .and_then(|p| p.as_array())
+                .into_iter()
+                .flatten()
+                .map(property_value)
+                .collect();
+            set_properties(instance, properties);
+        }
+        Err(err) => set_status(instance, &format!("Cannot load the properties: {err}")),
+    }
+}
+
+fn set_properties(instance: &ComponentInstance, properties: Vec<Value>) {
+    instance
+        .set_property("properties", Value::Model(ModelRc::new(VecModel::from(properties))))
+        .unwrap();
+}
+
+fn element_value(element: &Element) -> Value {
+    [
+        ("handle", Value::String(element.handle.as_str().into())),
+        ("window", Value::Number(element.window as _)),
+        ("depth", Value::Number(element.depth as _)),
+        ("label", Value::String(element.label.as_str().into())),
+        ("x", Value::Number(element.x)),
+        ("y", Value::Number(element.y)),
+        ("width", Value::Number(element.width)),
+        ("height", Value::Number(element.height)),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), value))
+    .collect::<slint_interpreter::Struct>()
+    .into()
+}
+
+fn property_value(property: &serde_json::Value) -> Value {
+    let text = |key: &str| property.get(key).and_then(|v| v.as_str()).unwrap_or_default();
+    let dependencies = property
+        .get("dependencies")
+        .and_then(|d| d.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|d| d.as_str())
+        .collect::<Vec<_>>()
+        .join(", ");
+    [
+        ("name", Value::String(text("name").into())),
+        ("value", Value::String(text("value").into())),
+        ("editable", Value::Bool(property.get("editable").and_then(|e| e.as_bool()) == Some(true))),
+        ("binding", Value::String(text("binding").into())),
+        ("dependencies", Value::String(dependencies.into())),
+    ]
+    .into_iter()
+    .map(|(name, value)| (name.to_string(), value))
+    .collect::<slint_interpreter::Struct>()
+    .into()
+}
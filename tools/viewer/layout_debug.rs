@@ -0,0 +1,97 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! The `--layout-debug` mode: the loaded component is embedded in a window, under the layout
+//! debugging overlay of the interpreter that is also used by the live preview.
+
+use i_slint_core::component_factory::{ComponentFactory, FactoryContext};
+use slint_interpreter::layout_overlay::layout_overlay_factory;
+use slint_interpreter::{
+    ComponentCompiler, ComponentDefinition, ComponentHandle, ComponentInstance, Value,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The window, with the size constraints of the embedded component
+const WINDOW: &str = r#"
+export component LayoutDebugWindow inherits Window {
+    in property <component-factory> preview-area;
+    in property <component-factory> layout-overlay;
+
+    min-width: preview.min-width;
+    min-height: preview.min-height;
+    preferred-width: preview.preferred-width;
+    preferred-height: preview.preferred-height;
+    max-width: preview.max-width;
+    max-height: preview.max-height;
+
+    preview := ComponentContainer {
+        width: 100%;
+        height: 100%;
+        component-factory: root.preview-area;
+    }
+
+    ComponentContainer {
+        width: 100%;
+        height: 100%;
+        component-factory: root.layout-overlay;
+    }
+}
+"#;
+
+pub struct LayoutDebugWindow {
+    /// The instance of the window
+    pub window: ComponentInstance,
+    /// The instance of the loaded component, once the window created it
+    instance: Rc<RefCell<Option<ComponentInstance>>>,
+}
+
+impl LayoutDebugWindow {
+    pub fn new() -> Self {
+        let mut compiler = ComponentCompiler::default();
+        compiler.compiler_configuration(i_slint_core::InternalToken).enable_component_containers =
+            true;
+        let definition = spin_on::spin_on(compiler.build_from_source(WINDOW.into(), "".into()));
+        slint_interpreter::print_diagnostics(compiler.diagnostics());
+        let window = definition.expect("the layout debug window must compile").create().unwrap();
+
+        let instance = Rc::new(RefCell::new(None::<ComponentInstance>));
+        let i = instance.clone();
+        let overlay = layout_overlay_factory(move || i.borrow().as_ref().map(|i| i.clone_strong()));
+        window.set_property("layout-overlay", Value::ComponentFactory(overlay)).unwrap();
+
+        Self { window, instance }
+    }
+
+    /// Show a new instance of `definition` in the window. `init` is called with that instance
+    /// once the window created it.
+    pub fn set_component(
+        &self,
+        definition: ComponentDefinition,
+        init: impl Fn(&ComponentInstance) + 'static,
+    ) {
+        let instance = self.instance.clone();
+        let init = Rc::new(init);
+        let factory = ComponentFactory::new(move |ctx: FactoryContext| {
+            let created = definition.create_embedded(ctx).ok()?;
+            instance.replace(Some(created.clone_strong()));
+
+            // The factory is called while the window is being laid out: Initialize the instance
+            // once that is done
+            let weak = created.as_weak();
+            let init = init.clone();
+            i_slint_core::timers::Timer::single_shot(Default::default(), move || {
+                if let Some(created) = weak.upgrade() {
+                    init(&created);
+                }
+            });
+            Some(created)
+        });
+        self.window.set_property("preview-area", Value::ComponentFactory(factory)).unwrap();
+    }
+
+    /// The instance of the loaded component, if the window created it
+    pub fn instance(&self) -> Option<ComponentInstance> {
+        self.instance.borrow().as_ref().map(|i| i.clone_strong())
+    }
+}
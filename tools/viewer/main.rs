@@ -16,6 +16,10 @@
 use clap::Parser;
 use itertools::Itertools;
 
+mod inspector;
+mod layout_debug;
+mod test_runner;
+
 type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
 
 #[derive(Clone, clap::Parser)]
@@ -29,8 +33,8 @@ struct Cli {
     library_paths: Vec<String>,
 
     /// The .slint file to load ('-' for stdin)
-    #[arg(name = "path to .slint file", action)]
-    path: std::path::PathBuf,
+    #[arg(name = "path to .slint file", action, required_unless_present = "inspect")]
+    path: Option<std::path::PathBuf>,
 
     /// The style name ('native' or 'fluent')
     #[arg(long, name = "style name", action)]
@@ -44,6 +48,26 @@ struct Cli {
     #[arg(long, action)]
     auto_reload: bool,
 
+    /// Run the tests of the file headless instead of showing it.
+    /// Tests are the public functions whose name starts with `test-` in exported components
+    #[arg(long, action)]
+    test: bool,
+
+    /// Draw the bounds of all the elements and the padding and spacing of the layouts on top of
+    /// the component, and show the layout constraints of the element under the mouse
+    #[arg(long, action)]
+    layout_debug: bool,
+
+    /// Instead of loading a file, show the element tree of a running application built with the
+    /// `inspector` feature, which listens on the address (for example 127.0.0.1:7979)
+    #[arg(long, value_name = "address", action)]
+    inspect: Option<String>,
+
+    /// The token printed by the inspected application when it starts, or the value of its
+    /// SLINT_INSPECTOR_TOKEN environment variable. Defaults to SLINT_INSPECTOR_TOKEN.
+    #[arg(long, value_name = "token", requires = "inspect", action)]
+    inspect_token: Option<String>,
+
     /// Load properties from a json file ('-' for stdin)
     #[arg(long, name = "load data file", action)]
     load_data: Option<std::path::PathBuf>,
@@ -70,7 +94,15 @@ struct Cli {
     translation_dir: Option<std::path::PathBuf>,
 }
 
+impl Cli {
+    /// The path of the .slint file, which clap requires unless `--inspect` is passed
+    fn path(&self) -> &std::path::Path {
+        self.path.as_deref().expect("the path is required without --inspect")
+    }
+}
+
 thread_local! {static CURRENT_INSTANCE: std::cell::RefCell<Option<ComponentInstance>> = Default::default();}
+thread_local! {static LAYOUT_DEBUG_WINDOW: std::cell::RefCell<Option<layout_debug::LayoutDebugWindow>> = Default::default();}
 static EXIT_CODE: std::sync::atomic::AtomicI32 = std::sync::atomic::AtomicI32::new(0);
 
 fn main() -> Result<()> {
@@ -82,10 +114,24 @@ fn main() -> Result<()> {
         std::process::exit(-1);
     }
 
+    if args.test && (args.auto_reload || args.save_data.is_some() || args.load_data.is_some()) {
+        eprintln!("Cannot pass --test together with --auto-reload, --load-data or --save-data");
+        std::process::exit(-1);
+    }
+
     if let Some(backend) = &args.backend {
         std::env::set_var("SLINT_BACKEND", backend);
     }
 
+    if let Some(address) = &args.inspect {
+        let token = args
+            .inspect_token
+            .clone()
+            .or_else(|| std::env::var("SLINT_INSPECTOR_TOKEN").ok())
+            .ok_or("The token of the inspected application is required: use --inspect-token")?;
+        return inspector::run(address, &token, args.style.as_deref());
+    }
+
     #[cfg(feature = "gettext")]
     if let Some(dirname) = args.translation_dir.clone() {
         i_slint_core::translations::gettext_bindtextdomain(
@@ -97,7 +143,11 @@ fn main() -> Result<()> {
     let fswatcher = if args.auto_reload { Some(start_fswatch_thread(args.clone())?) } else { None };
     let mut compiler = init_compiler(&args, fswatcher);
 
-    let c = spin_on::spin_on(compiler.build_from_path(args.path));
+    if args.test {
+        std::process::exit(test_runner::run(compiler, args.path()));
+    }
+
+    let c = spin_on::spin_on(compiler.build_from_path(args.path()));
     slint_interpreter::print_diagnostics(compiler.diagnostics());
 
     let c = match c {
@@ -105,21 +155,33 @@ fn main() -> Result<()> {
         None => std::process::exit(-1),
     };
 
-    let component = c.create().unwrap();
-    init_dialog(&component);
+    let component = if args.layout_debug {
+        let window = layout_debug::LayoutDebugWindow::new();
+        window.set_component(c.clone(), init_layout_debug_instance(&c, &args));
+        let handle = window.window.clone_strong();
+        LAYOUT_DEBUG_WINDOW.with(|w| w.replace(Some(window)));
 
-    if let Some(data_path) = args.load_data {
-        load_data(&c, &component, &data_path)?;
-    }
-    install_callbacks(&component, &args.on);
+        handle.run().unwrap();
 
-    if args.auto_reload {
-        CURRENT_INSTANCE.with(|current| current.replace(Some(component.clone_strong())));
-    }
+        LAYOUT_DEBUG_WINDOW.with(|w| w.borrow().as_ref().and_then(|w| w.instance()))
+    } else {
+        let component = c.create().unwrap();
+        init_dialog(&component);
 
-    component.run().unwrap();
+        if let Some(data_path) = &args.load_data {
+            load_data(&c, &component, data_path)?;
+        }
+        install_callbacks(&component, &args.on);
 
-    if let Some(data_path) = args.save_data {
+        if args.auto_reload {
+            CURRENT_INSTANCE.with(|current| current.replace(Some(component.clone_strong())));
+        }
+
+        component.run().unwrap();
+        Some(component)
+    };
+
+    if let Some((data_path, component)) = args.save_data.zip(component) {
         let mut obj = serde_json::Map::new();
         for (name, _) in c.properties() {
             fn to_json(val: slint_interpreter::Value) -> Option<serde_json::Value> {
@@ -183,11 +245,11 @@ fn init_compiler(
     if let Some(watcher) = fswatcher {
         notify::Watcher::watch(
             &mut *watcher.lock().unwrap(),
-            &args.path,
+            args.path(),
             notify::RecursiveMode::NonRecursive,
         )
         .unwrap_or_else(|err| {
-            eprintln!("Warning: error while watching {}: {:?}", args.path.display(), err)
+            eprintln!("Warning: error while watching {}: {:?}", args.path().display(), err)
         });
         if let Some(data_path) = &args.load_data {
             notify::Watcher::watch(
@@ -233,6 +295,23 @@ fn init_dialog(instance: &ComponentInstance) {
     }
 }
 
+/// The initialization of the instances created by the window of `--layout-debug`
+fn init_layout_debug_instance(
+    c: &ComponentDefinition,
+    args: &Cli,
+) -> impl Fn(&ComponentInstance) + 'static {
+    let (c, data_path, callbacks) = (c.clone(), args.load_data.clone(), args.on.clone());
+    move |instance| {
+        init_dialog(instance);
+        if let Some(data_path) = &data_path {
+            if let Err(err) = load_data(&c, instance, data_path) {
+                eprintln!("Error: cannot load {}: {err}", data_path.display());
+            }
+        }
+        install_callbacks(instance, &callbacks);
+    }
+}
+
 static PENDING_EVENTS: AtomicU32 = AtomicU32::new(0);
 
 fn start_fswatch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatcher>>> {
@@ -262,10 +341,17 @@ fn start_fswatch_thread(args: Cli) -> Result<Arc<Mutex<notify::RecommendedWatche
 
 async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
     let mut compiler = init_compiler(&args, Some(fswatcher));
-    let c = compiler.build_from_path(&args.path).await;
+    let c = compiler.build_from_path(args.path()).await;
     slint_interpreter::print_diagnostics(compiler.diagnostics());
 
-    if let Some(c) = c {
+    if let Some(c) = c.as_ref().filter(|_| args.layout_debug) {
+        LAYOUT_DEBUG_WINDOW.with(|window| {
+            if let Some(window) = window.borrow().as_ref() {
+                window.set_component(c.clone(), init_layout_debug_instance(c, &args));
+            }
+        });
+        eprintln!("Successful reload of {}", args.path().display());
+    } else if let Some(c) = c {
         CURRENT_INSTANCE.with(|current| {
             let mut current = current.borrow_mut();
             if let Some(handle) = current.take() {
@@ -282,7 +368,7 @@ async fn reload(args: Cli, fswatcher: Arc<Mutex<notify::RecommendedWatcher>>) {
             if let Some(data_path) = args.load_data {
                 let _ = load_data(&c, current.as_ref().unwrap(), &data_path);
             }
-            eprintln!("Successful reload of {}", args.path.display());
+            eprintln!("Successful reload of {}", args.path().display());
         });
     }
 
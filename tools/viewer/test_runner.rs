@@ -0,0 +1,155 @@
+// Copyright © SixtyFPS GmbH <info@slint.dev>
+// SPDX-License-Identifier: GPL-3.0-only OR LicenseRef-Slint-Royalty-free-1.1 OR LicenseRef-Slint-commercial
+
+//! Run the tests written in a .slint file.
+//!
+//! A test is a public function with no arguments whose name starts with `test-`, declared
+//! in an exported component. Each test is run on a new instance of its component, using the
+//! headless testing backend. Failing `assert()` and `assert-eq()` are reported with their
+//! source location.
+
+use i_slint_compiler::diagnostics::BuildDiagnostics;
+use i_slint_compiler::parser::{identifier_text, syntax_nodes, SyntaxKind};
+use slint_interpreter::ComponentHandle;
+use std::cell::RefCell;
+use std::path::Path;
+use std::rc::Rc;
+
+/// Name of the component that imports the tested component and makes it the root
+const TEST_ROOT: &str = "_SLINT_Test";
+
+/// The source of a file next to the tested file, whose last exported component inherits the
+/// tested component, so that the interpreter instantiates it
+fn wrapper_source(path: &Path, component: &str) -> String {
+    let file_name = path.file_name().map(|f| f.to_string_lossy()).unwrap_or_default();
+    let file_name = file_name.replace('\\', "\\\\").replace('"', "\\\"");
+    format!(
+        "import {{ {component} }} from \"{file_name}\";\n\
+         export component {TEST_ROOT} inherits {component} {{}}\n"
+    )
+}
+
+/// Run all the tests of the file, and return the exit code
+pub fn run(mut compiler: slint_interpreter::ComponentCompiler, path: &Path) -> i32 {
+    i_slint_backend_testing::init();
+
+    let source = match i_slint_compiler::diagnostics::load_from_path(path) {
+        Ok(source) => source,
+        Err(d) => {
+            slint_interpreter::print_diagnostics(&[d]);
+            return -1;
+        }
+    };
+
+    let tests = discover_tests(&source, path);
+    if tests.is_empty() {
+        eprintln!("No test found in {}", path.display());
+        return 0;
+    }
+
+    // The wrapper imports the tested file: serve the source that was loaded, which is the content
+    // of the `slint!` macro for .rs files
+    let tested_path = std::fs::canonicalize(path).unwrap_or_else(|_| path.into());
+    let tested_source = source.clone();
+    compiler.set_file_loader(move |path| {
+        let result = (std::fs::canonicalize(path).ok().as_ref() == Some(&tested_path))
+            .then(|| Ok(tested_source.clone()));
+        Box::pin(async move { result })
+    });
+
+    let failures = Rc::new(RefCell::new(Vec::<String>::new()));
+    let failures_ = failures.clone();
+    i_slint_core::tests::set_assertion_failure_handler(Some(Box::new(move |message| {
+        failures_.borrow_mut().push(message.into())
+    })));
+
+    let (mut passed, mut failed) = (0, 0);
+    for (component, functions) in &tests {
+        let definition = spin_on::spin_on(compiler.build_from_source(
+            wrapper_source(path, component),
+            path.with_file_name(format!("{TEST_ROOT}.slint")),
+        ));
+        slint_interpreter::print_diagnostics(compiler.diagnostics());
+        let Some(definition) = definition else {
+            failed += functions.len();
+            for function in functions {
+                println!("test {component}::{function} ... FAILED (compilation error)");
+            }
+            continue;
+        };
+
+        for function in functions {
+            failures.borrow_mut().clear();
+            let result = definition
+                .create()
+                .map_err(|e| e.to_string())
+                .and_then(|instance| instance.invoke(function, &[]).map_err(|e| e.to_string()));
+            if let Err(e) = result {
+                failures.borrow_mut().push(e);
+            }
+            let failures = failures.borrow();
+            if failures.is_empty() {
+                passed += 1;
+                println!("test {component}::{function} ... ok");
+            } else {
+                failed += 1;
+                println!("test {component}::{function} ... FAILED");
+                for failure in failures.iter() {
+                    println!("    {}", failure.replace('\n', "\n    "));
+                }
+            }
+        }
+    }
+
+    i_slint_core::tests::set_assertion_failure_handler(None);
+
+    println!(
+        "\ntest result: {}. {passed} passed; {failed} failed",
+        if failed == 0 { "ok" } else { "FAILED" }
+    );
+    if failed == 0 {
+        0
+    } else {
+        1
+    }
+}
+
+/// Returns the name of the exported components that have tests, with the name of their tests
+fn discover_tests(source: &str, path: &Path) -> Vec<(String, Vec<String>)> {
+    let mut diag = BuildDiagnostics::default();
+    let doc: syntax_nodes::Document =
+        i_slint_compiler::parser::parse(source.into(), Some(path), None, &mut diag).into();
+
+    let exported_names = doc
+        .ExportsList()
+        .flat_map(|exports| exports.ExportSpecifier())
+        .filter_map(|specifier| identifier_text(&specifier.ExportIdentifier()))
+        .collect::<Vec<_>>();
+
+    let components = doc.ExportsList().filter_map(|exports| exports.Component()).chain(
+        doc.Component().filter(|component| {
+            identifier_text(&component.DeclaredIdentifier())
+                .map_or(false, |name| exported_names.contains(&name))
+        }),
+    );
+
+    components
+        .filter_map(|component| {
+            let name = identifier_text(&component.DeclaredIdentifier())?;
+            let functions = component
+                .Element()
+                .Function()
+                .filter(|function| {
+                    function.ArgumentDeclaration().next().is_none()
+                        && function.children_with_tokens().any(|token| {
+                            token.kind() == SyntaxKind::Identifier
+                                && token.as_token().map_or(false, |t| t.text() == "public")
+                        })
+                })
+                .filter_map(|function| identifier_text(&function.DeclaredIdentifier()))
+                .filter(|name| name.starts_with("test-"))
+                .collect::<Vec<_>>();
+            (!functions.is_empty()).then_some((name, functions))
+        })
+        .collect()
+}